@@ -0,0 +1,12 @@
+/// A block type. Zero is air; what the other ids mean is up to the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct BlockId(pub u16);
+
+impl BlockId {
+    pub const AIR: BlockId = BlockId(0);
+
+    #[inline]
+    pub fn is_air(self) -> bool {
+        self == Self::AIR
+    }
+}
@@ -0,0 +1,144 @@
+use super::block::BlockId;
+use super::palette::PalettedStorage;
+
+pub const CHUNK_BITS: u32 = 5;
+/// Blocks along each edge of a chunk.
+pub const CHUNK_SIZE: usize = 1 << CHUNK_BITS;
+pub const CHUNK_VOLUME: usize = CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE;
+
+/// A cube of `CHUNK_SIZE³` blocks.
+///
+/// Chunks holding a single block type, which is most of them in typical
+/// terrain (air above ground, stone below), store just that id. Anything
+/// else is palette compressed, and a chunk collapses back to the uniform
+/// form as soon as one block type fills it again.
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    storage: Storage,
+}
+
+#[derive(Debug, Clone)]
+enum Storage {
+    Uniform(BlockId),
+    Paletted(PalettedStorage),
+}
+
+impl Chunk {
+    /// A chunk filled with `block`.
+    pub fn new(block: BlockId) -> Self {
+        Self {
+            storage: Storage::Uniform(block),
+        }
+    }
+
+    /// The position of a block in [`blocks`](Self::blocks) order: x varies
+    /// fastest, then y, then z.
+    #[inline]
+    pub fn index(x: usize, y: usize, z: usize) -> usize {
+        debug_assert!(
+            x < CHUNK_SIZE && y < CHUNK_SIZE && z < CHUNK_SIZE,
+            "local position out of bounds"
+        );
+        x | (y << CHUNK_BITS) | (z << (2 * CHUNK_BITS))
+    }
+
+    /// The inverse of [`index`](Self::index).
+    #[inline]
+    pub fn position(index: usize) -> (usize, usize, usize) {
+        let mask = CHUNK_SIZE - 1;
+        (
+            index & mask,
+            (index >> CHUNK_BITS) & mask,
+            index >> (2 * CHUNK_BITS),
+        )
+    }
+
+    #[inline]
+    pub fn get(&self, x: usize, y: usize, z: usize) -> BlockId {
+        self.get_index(Self::index(x, y, z))
+    }
+
+    #[inline]
+    pub fn get_index(&self, index: usize) -> BlockId {
+        match &self.storage {
+            Storage::Uniform(block) => *block,
+            Storage::Paletted(storage) => storage.get(index),
+        }
+    }
+
+    /// Stores `block` and returns the block that was there.
+    pub fn set(&mut self, x: usize, y: usize, z: usize, block: BlockId) -> BlockId {
+        self.set_index(Self::index(x, y, z), block)
+    }
+
+    pub fn set_index(&mut self, index: usize, block: BlockId) -> BlockId {
+        match &mut self.storage {
+            Storage::Uniform(current) if *current == block => block,
+            Storage::Uniform(current) => {
+                let previous = *current;
+                let mut storage = PalettedStorage::new(CHUNK_VOLUME, previous);
+                storage.set(index, block);
+                self.storage = Storage::Paletted(storage);
+                previous
+            }
+            Storage::Paletted(storage) => {
+                let previous = storage.set(index, block);
+                if storage.uniform_block() == Some(block) {
+                    self.storage = Storage::Uniform(block);
+                }
+                previous
+            }
+        }
+    }
+
+    pub fn fill(&mut self, block: BlockId) {
+        self.storage = Storage::Uniform(block);
+    }
+
+    /// The block filling the whole chunk, if there is only one.
+    pub fn uniform_block(&self) -> Option<BlockId> {
+        match &self.storage {
+            Storage::Uniform(block) => Some(*block),
+            Storage::Paletted(_) => None,
+        }
+    }
+
+    /// Whether the chunk is all air.
+    pub fn is_empty(&self) -> bool {
+        self.uniform_block() == Some(BlockId::AIR)
+    }
+
+    /// The distinct blocks present.
+    pub fn palette(&self) -> Vec<BlockId> {
+        match &self.storage {
+            Storage::Uniform(block) => vec![*block],
+            Storage::Paletted(storage) => storage.palette().collect(),
+        }
+    }
+
+    /// Bits stored per block; zero for uniform chunks.
+    pub fn bits_per_block(&self) -> u32 {
+        match &self.storage {
+            Storage::Uniform(_) => 0,
+            Storage::Paletted(storage) => storage.bits_per_index(),
+        }
+    }
+
+    /// Shrinks the palette after many blocks were removed.
+    pub fn compact(&mut self) {
+        if let Storage::Paletted(storage) = &mut self.storage {
+            storage.compact();
+        }
+    }
+
+    /// Every block in [`index`](Self::index) order.
+    pub fn blocks(&self) -> impl Iterator<Item = BlockId> + '_ {
+        (0..CHUNK_VOLUME).map(move |index| self.get_index(index))
+    }
+}
+
+impl Default for Chunk {
+    fn default() -> Self {
+        Self::new(BlockId::AIR)
+    }
+}
@@ -0,0 +1,233 @@
+use super::block::BlockId;
+use super::svo::{self, SparseVoxelOctree, SvoNode};
+use anyhow::{bail, ensure, Result};
+use hmath::geometry::ray::Ray;
+use hmath::geometry::voxel_traversal::VoxelHit;
+use hmath::vector::Vector3i;
+use std::collections::HashMap;
+
+const MAGIC: [u8; 4] = *b"HDAG";
+const VERSION: u32 = 1;
+const HEADER_LEN: usize = 36;
+const NODE_LEN: usize = 8;
+
+/// A sparse voxel octree with identical subtrees stored once, for large
+/// static scenes.
+///
+/// Nodes use the octree layout, except that any number of parents may point
+/// at the same group of eight children. Lookups and rays therefore work
+/// exactly as on [`SparseVoxelOctree`], and the node array can be uploaded
+/// the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseVoxelDag {
+    origin: Vector3i,
+    depth: u32,
+    nodes: Vec<SvoNode>,
+    octree_nodes: u64,
+}
+
+impl SparseVoxelDag {
+    /// Merges identical subtrees bottom up: once every child group has been
+    /// deduplicated, equal subtrees have equal child nodes, so hashing the
+    /// eight children of a group is enough to find its twin.
+    pub fn from_octree(octree: &SparseVoxelOctree) -> Self {
+        let mut builder = Builder {
+            source: octree.nodes(),
+            nodes: vec![SvoNode::leaf(BlockId::AIR)],
+            groups: HashMap::new(),
+            visited: 1,
+        };
+        let root = builder.intern(0);
+        builder.nodes[0] = root;
+        Self {
+            origin: octree.origin(),
+            depth: octree.depth(),
+            nodes: builder.nodes,
+            octree_nodes: builder.visited,
+        }
+    }
+
+    pub fn origin(&self) -> Vector3i {
+        self.origin
+    }
+
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Blocks along each edge.
+    pub fn size(&self) -> i32 {
+        1 << self.depth
+    }
+
+    /// All nodes, the root first. Child groups always come before the groups
+    /// that point at them.
+    pub fn nodes(&self) -> &[SvoNode] {
+        &self.nodes
+    }
+
+    /// Nodes reachable in the octree this was built from.
+    pub fn octree_node_count(&self) -> u64 {
+        self.octree_nodes
+    }
+
+    /// How many times fewer nodes the DAG needs than the octree.
+    pub fn compression_ratio(&self) -> f64 {
+        self.octree_nodes as f64 / self.nodes.len() as f64
+    }
+
+    /// The block at a position, or air outside the volume.
+    pub fn block(&self, position: Vector3i) -> BlockId {
+        self.lod(position, 0)
+    }
+
+    /// See [`SparseVoxelOctree::lod`].
+    pub fn lod(&self, position: Vector3i, level: u32) -> BlockId {
+        svo::lookup(&self.nodes, self.origin, self.depth, position, level)
+    }
+
+    /// See [`SparseVoxelOctree::raycast`].
+    pub fn raycast(&self, ray: &Ray<f64>, max_distance: f64) -> Option<(VoxelHit<f64>, BlockId)> {
+        self.raycast_lod(ray, max_distance, 0)
+    }
+
+    /// See [`SparseVoxelOctree::raycast_lod`].
+    pub fn raycast_lod(
+        &self,
+        ray: &Ray<f64>,
+        max_distance: f64,
+        level: u32,
+    ) -> Option<(VoxelHit<f64>, BlockId)> {
+        svo::raycast(
+            &self.nodes,
+            self.origin,
+            self.depth,
+            ray,
+            max_distance,
+            level,
+        )
+    }
+
+    /// See [`SparseVoxelOctree::to_gpu_buffer`].
+    pub fn to_gpu_buffer(&self) -> Vec<u32> {
+        svo::gpu_words(&self.nodes)
+    }
+
+    /// The serialized form: a little-endian header (`HDAG`, format version,
+    /// origin, depth, octree node count, node count) followed by eight bytes
+    /// per node, the first child index, block id, child mask and a zero byte.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.nodes.len() * NODE_LEN);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        for coordinate in [self.origin.x, self.origin.y, self.origin.z] {
+            bytes.extend_from_slice(&coordinate.to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.depth.to_le_bytes());
+        bytes.extend_from_slice(&self.octree_nodes.to_le_bytes());
+        bytes.extend_from_slice(&(self.nodes.len() as u32).to_le_bytes());
+        for node in &self.nodes {
+            bytes.extend_from_slice(&node.first_child.to_le_bytes());
+            bytes.extend_from_slice(&node.block.0.to_le_bytes());
+            bytes.extend_from_slice(&[node.child_mask, 0]);
+        }
+        bytes
+    }
+
+    /// Reads [`to_bytes`](Self::to_bytes) output. The node array is checked
+    /// so that every child group is in bounds and precedes its parents,
+    /// which rules out cycles; no other work is done.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        ensure!(bytes.len() >= HEADER_LEN, "truncated header");
+        ensure!(bytes[0..4] == MAGIC, "not a voxel DAG");
+        let u32_at =
+            |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        let i32_at =
+            |offset: usize| i32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+
+        let version = u32_at(4);
+        if version != VERSION {
+            bail!("unsupported version {}", version);
+        }
+        let origin = Vector3i::new(i32_at(8), i32_at(12), i32_at(16));
+        let depth = u32_at(20);
+        ensure!(depth <= 30, "depth {} too large", depth);
+        let octree_nodes = u64::from_le_bytes(bytes[24..32].try_into().unwrap());
+        let count = u32_at(32) as usize;
+        ensure!(
+            count >= 1 && (count - 1).is_multiple_of(8),
+            "bad node count {}",
+            count
+        );
+        ensure!(
+            bytes.len() == HEADER_LEN + count * NODE_LEN,
+            "expected {} node bytes, found {}",
+            count * NODE_LEN,
+            bytes.len() - HEADER_LEN
+        );
+
+        let mut nodes = Vec::with_capacity(count);
+        for (index, record) in bytes[HEADER_LEN..].chunks_exact(NODE_LEN).enumerate() {
+            let node = SvoNode {
+                first_child: u32::from_le_bytes(record[0..4].try_into().unwrap()),
+                block: BlockId(u16::from_le_bytes(record[4..6].try_into().unwrap())),
+                child_mask: record[6],
+            };
+            if !node.is_leaf() {
+                let first = node.first_child as usize;
+                // The root may point anywhere; other nodes only at earlier
+                // groups.
+                let limit = if index == 0 {
+                    count
+                } else {
+                    index - (index - 1) % 8
+                };
+                ensure!(
+                    (first - 1).is_multiple_of(8) && first + 8 <= limit,
+                    "node {} has invalid children {}",
+                    index,
+                    first
+                );
+            }
+            nodes.push(node);
+        }
+
+        Ok(Self {
+            origin,
+            depth,
+            nodes,
+            octree_nodes,
+        })
+    }
+}
+
+struct Builder<'a> {
+    source: &'a [SvoNode],
+    nodes: Vec<SvoNode>,
+    groups: HashMap<[SvoNode; 8], u32>,
+    visited: u64,
+}
+
+impl Builder<'_> {
+    /// The DAG node equivalent to source node `index`, with its children
+    /// already stored.
+    fn intern(&mut self, index: usize) -> SvoNode {
+        let node = self.source[index];
+        if node.is_leaf() {
+            return node;
+        }
+
+        self.visited += 8;
+        let first = node.first_child as usize;
+        let children: [SvoNode; 8] = std::array::from_fn(|child| self.intern(first + child));
+        let nodes = &mut self.nodes;
+        let first_child = *self.groups.entry(children).or_insert_with(|| {
+            nodes.extend_from_slice(&children);
+            (nodes.len() - 8) as u32
+        });
+        SvoNode {
+            first_child,
+            ..node
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use hmath::vector::{Vector3d, Vector3f, Vector3i};
+use std::collections::HashMap;
+
+/// Chunk faces as bits of a transition mask, in the order -x, +x, -y, +y,
+/// -z, +z.
+pub fn face_bit(axis: usize, positive: bool) -> u8 {
+    1 << (axis * 2 + positive as usize)
+}
+
+/// A scalar field sampled on the lattice of one chunk, for the smooth
+/// surface extractors.
+///
+/// The field is negative inside solid matter and positive in empty space,
+/// so the surface is its zero level set and gradients point outward.
+/// Lattice point `p` lies at `origin + p * step` in world space, where
+/// `step = 2^lod`; a chunk of `cells` cells per axis stores the points
+/// `-1..=cells + 1` so that cells straddling its borders can be evaluated
+/// exactly like the neighbour would. Gradients are taken from the density
+/// function by central differences when sampling, both at the lattice points
+/// and where the surface crosses a lattice edge.
+#[derive(Debug, Clone)]
+pub struct DensityChunk {
+    origin: Vector3i,
+    cells: usize,
+    lod: u32,
+    values: Vec<f32>,
+    gradients: Vec<Vector3f>,
+    crossings: HashMap<(Vector3i, usize), Vector3f>,
+    transitions: u8,
+    faces: [FaceSamples; 6],
+}
+
+/// Samples at half the chunk's step on a face bordering a finer chunk,
+/// indexed by the two face axes in `(d + 1, d + 2) % 3` order.
+#[derive(Debug, Clone, Default)]
+struct FaceSamples {
+    values: Vec<f32>,
+    gradients: Vec<Vector3f>,
+}
+
+impl DensityChunk {
+    /// Samples `density` for a chunk whose lattice point zero is at
+    /// `origin`.
+    ///
+    /// `transitions` is a mask of [`face_bit`]s for the faces that border a
+    /// chunk one level of detail finer; those faces are also sampled at the
+    /// finer resolution so that marching cubes can stitch the seam.
+    ///
+    /// Panics if `lod` exceeds 30, past which the step no longer fits an
+    /// `i32` block offset.
+    pub fn sample(
+        origin: Vector3i,
+        cells: usize,
+        lod: u32,
+        transitions: u8,
+        density: impl Fn(Vector3d) -> f32,
+    ) -> Self {
+        assert!(cells > 0, "a chunk needs at least one cell");
+        assert!(
+            transitions == 0 || lod > 0,
+            "level 0 has no finer neighbours"
+        );
+        assert!(lod <= 30, "level of detail {} is too coarse", lod);
+
+        let step = (1 << lod) as f64;
+        let world = |p: Vector3d| origin.cast::<f64>() + p * step;
+        let gradient = |p: Vector3d, h: f64| -> Vector3f {
+            let mut gradient = Vector3f::zero();
+            for axis in 0..3 {
+                let mut offset = Vector3d::zero();
+                offset[axis] = h;
+                let slope = (density(world(p + offset)) - density(world(p - offset))) as f64;
+                gradient[axis] = (slope / (2.0 * h * step)) as f32;
+            }
+            gradient
+        };
+
+        let side = cells + 3;
+        let mut values = Vec::with_capacity(side * side * side);
+        let mut gradients = Vec::with_capacity(side * side * side);
+        for z in -1..=cells as i32 + 1 {
+            for y in -1..=cells as i32 + 1 {
+                for x in -1..=cells as i32 + 1 {
+                    let p = Vector3i::new(x, y, z).cast::<f64>();
+                    values.push(density(world(p)));
+                    gradients.push(gradient(p, 0.5));
+                }
+            }
+        }
+
+        let mut chunk = Self {
+            origin,
+            cells,
+            lod,
+            values,
+            gradients,
+            crossings: HashMap::new(),
+            transitions,
+            faces: Default::default(),
+        };
+        for z in -1..=cells as i32 + 1 {
+            for y in -1..=cells as i32 + 1 {
+                for x in -1..=cells as i32 + 1 {
+                    let p = Vector3i::new(x, y, z);
+                    for axis in 0..3 {
+                        let mut q = p;
+                        q[axis] += 1;
+                        if q[axis] > cells as i32 + 1 {
+                            continue;
+                        }
+                        let (a, b) = (chunk.value(p), chunk.value(q));
+                        if (a < 0.0) != (b < 0.0) {
+                            let mut point = p.cast::<f64>();
+                            point[axis] += (a / (a - b)) as f64;
+                            chunk.crossings.insert((p, axis), gradient(point, 0.05));
+                        }
+                    }
+                }
+            }
+        }
+
+        let fine = 2 * cells + 1;
+        chunk.faces = std::array::from_fn(|face| {
+            if transitions & (1 << face) == 0 {
+                return FaceSamples::default();
+            }
+            let d = face / 2;
+            let (u, v) = ((d + 1) % 3, (d + 2) % 3);
+            let mut samples = FaceSamples::default();
+            for b in 0..fine {
+                for a in 0..fine {
+                    let mut p = Vector3d::zero();
+                    p[d] = if face % 2 == 1 { cells as f64 } else { 0.0 };
+                    p[u] = a as f64 * 0.5;
+                    p[v] = b as f64 * 0.5;
+                    samples.values.push(density(world(p)));
+                    samples.gradients.push(gradient(p, 0.25));
+                }
+            }
+            samples
+        });
+        chunk
+    }
+
+    /// World position of lattice point zero.
+    pub fn origin(&self) -> Vector3i {
+        self.origin
+    }
+
+    /// Cells along each edge.
+    pub fn cells(&self) -> usize {
+        self.cells
+    }
+
+    pub fn lod(&self) -> u32 {
+        self.lod
+    }
+
+    /// World units between lattice points.
+    pub fn step(&self) -> i32 {
+        1 << self.lod
+    }
+
+    /// The [`face_bit`]s of faces that border a finer chunk.
+    pub fn transitions(&self) -> u8 {
+        self.transitions
+    }
+
+    /// The density at a lattice point in `-1..=cells + 1`.
+    pub fn value(&self, p: Vector3i) -> f32 {
+        self.values[self.index(p)]
+    }
+
+    /// The density gradient at a lattice point in `-1..=cells + 1`.
+    pub fn gradient(&self, p: Vector3i) -> Vector3f {
+        self.gradients[self.index(p)]
+    }
+
+    /// The density gradient where the surface crosses the lattice edge from
+    /// `p` along `axis`, if it does. The crossing is found by linear
+    /// interpolation between the edge's ends.
+    pub fn crossing_gradient(&self, p: Vector3i, axis: usize) -> Option<Vector3f> {
+        self.crossings.get(&(p, axis)).copied()
+    }
+
+    /// The density at a half-step sample on a transition face, indexed along
+    /// the face's `(d + 1, d + 2) % 3` axes.
+    pub fn face_value(&self, face: usize, a: usize, b: usize) -> f32 {
+        self.faces[face].values[b * (2 * self.cells + 1) + a]
+    }
+
+    /// See [`face_value`](Self::face_value).
+    pub fn face_gradient(&self, face: usize, a: usize, b: usize) -> Vector3f {
+        self.faces[face].gradients[b * (2 * self.cells + 1) + a]
+    }
+
+    fn index(&self, p: Vector3i) -> usize {
+        let side = self.cells as i32 + 3;
+        let range = -1..=self.cells as i32 + 1;
+        assert!(
+            range.contains(&p.x) && range.contains(&p.y) && range.contains(&p.z),
+            "lattice point {:?} outside the chunk",
+            p
+        );
+        ((p.x + 1) + (p.y + 1) * side + (p.z + 1) * side * side) as usize
+    }
+}
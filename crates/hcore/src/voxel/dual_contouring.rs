@@ -0,0 +1,130 @@
+use super::density::DensityChunk;
+use super::marching_cubes::corner_offset;
+use super::surface::{SurfaceBuilder, SurfaceMesh, VertexKey};
+use hmath::matrix::Matrix3;
+use hmath::vector::{Vector3d, Vector3f, Vector3i};
+
+/// Pull towards the mean of the edge crossings, keeping the vertex stable in
+/// flat or nearly flat cells where the planes barely constrain it.
+const QEF_BIAS: f64 = 0.01;
+
+/// Extracts the zero isosurface of a chunk with dual contouring.
+///
+/// Every cell the surface passes through gets one vertex, placed where the
+/// tangent planes at its edge crossings meet, so sharp features survive.
+/// Each sign-changing lattice edge then becomes a quad joining the four
+/// cells around it. A chunk emits the quads for edges starting inside it,
+/// using the cells just past its lower faces from the padded samples; the
+/// cell vertices' [`VertexKey`]s let [`SurfaceMesh::stitch`] weld them to the
+/// neighbour's. Transition faces are ignored, so chunks joined this way
+/// must share a level of detail.
+pub fn dual_contouring(chunk: &DensityChunk) -> SurfaceMesh {
+    let mut builder = SurfaceBuilder::default();
+    let n = chunk.cells() as i32;
+    for z in 0..n {
+        for y in 0..n {
+            for x in 0..n {
+                let p = Vector3i::new(x, y, z);
+                for axis in 0..3 {
+                    let mut along = Vector3i::zero();
+                    along[axis] = 1;
+                    let inside = chunk.value(p) < 0.0;
+                    if inside == (chunk.value(p + along) < 0.0) {
+                        continue;
+                    }
+
+                    let mut du = Vector3i::zero();
+                    du[(axis + 1) % 3] = 1;
+                    let mut dv = Vector3i::zero();
+                    dv[(axis + 2) % 3] = 1;
+                    // Counterclockwise around +axis, the outward direction
+                    // when the solid is at the lower end.
+                    let [a, b, c, d] = [p - du - dv, p - dv, p, p - du]
+                        .map(|cell| cell_vertex(&mut builder, chunk, cell));
+                    if inside {
+                        builder.triangle([a, b, c]);
+                        builder.triangle([a, c, d]);
+                    } else {
+                        builder.triangle([a, c, b]);
+                        builder.triangle([a, d, c]);
+                    }
+                }
+            }
+        }
+    }
+    builder.mesh
+}
+
+/// The vertex of a cell the surface crosses.
+fn cell_vertex(builder: &mut SurfaceBuilder, chunk: &DensityChunk, cell: Vector3i) -> u32 {
+    let key = VertexKey {
+        position: chunk.origin() + cell * chunk.step(),
+        kind: VertexKey::CELL,
+        lod: chunk.lod() as u8,
+    };
+    builder.vertex(key, || {
+        let mut planes = Vec::new();
+        for axis in 0..3 {
+            for corner in 0..4 {
+                let mut a = cell;
+                a[(axis + 1) % 3] += corner & 1;
+                a[(axis + 2) % 3] += corner >> 1;
+                let mut b = a;
+                b[axis] += 1;
+                let Some(gradient) = chunk.crossing_gradient(a, axis) else {
+                    continue;
+                };
+                let (va, vb) = (chunk.value(a), chunk.value(b));
+                let mut point = a.cast::<f64>();
+                point[axis] += (va / (va - vb)) as f64;
+                planes.push((point, gradient.normalize().cast::<f64>()));
+            }
+        }
+
+        let position = solve_qef(&planes, cell);
+        let f = position - cell.cast();
+        let mut gradient = Vector3f::zero();
+        for corner in 0..8 {
+            let offset = corner_offset(corner);
+            let weight: f64 = (0..3)
+                .map(|axis| {
+                    if offset[axis] == 1 {
+                        f[axis]
+                    } else {
+                        1.0 - f[axis]
+                    }
+                })
+                .product();
+            gradient += chunk.gradient(cell + offset) * weight as f32;
+        }
+        (
+            (position * chunk.step() as f64).cast(),
+            gradient.normalize(),
+        )
+    })
+}
+
+/// Minimises the summed squared distances to the planes, each given as a
+/// point and unit normal, and clamps the result to the cell.
+fn solve_qef(planes: &[(Vector3d, Vector3d)], cell: Vector3i) -> Vector3d {
+    let mass = planes
+        .iter()
+        .fold(Vector3d::zero(), |sum, (point, _)| sum + *point)
+        / planes.len() as f64;
+    // Solved relative to the mass point: (AᵀA + bias·I) x = Aᵀ(b - A·mass).
+    let mut ata = [QEF_BIAS, 0.0, 0.0, 0.0, QEF_BIAS, 0.0, 0.0, 0.0, QEF_BIAS];
+    let mut atb = Vector3d::zero();
+    for (point, normal) in planes {
+        for row in 0..3 {
+            for column in 0..3 {
+                ata[row * 3 + column] += normal[row] * normal[column];
+            }
+        }
+        atb += *normal * normal.dot(&(*point - mass));
+    }
+    let offset = Matrix3::new(ata)
+        .inverse()
+        .map_or(Vector3d::zero(), |inverse| inverse.transform_vector(&atb));
+    let min: Vector3d = cell.cast();
+    (mass + offset).clamp(&min, &(min + Vector3d::new(1.0, 1.0, 1.0)))
+}
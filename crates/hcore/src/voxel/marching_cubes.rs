@@ -0,0 +1,354 @@
+use super::density::{face_bit, DensityChunk};
+use super::surface::{SurfaceBuilder, SurfaceMesh, VertexKey};
+use hmath::vector::{Vector3d, Vector3i};
+use std::sync::OnceLock;
+
+/// How far, in cells, transition cells reach into a coarse chunk. The
+/// chunk's outermost regular cells are squeezed by this much on faces that
+/// border a finer chunk.
+pub const TRANSITION_WIDTH: f64 = 0.5;
+
+/// A cell edge, as the two sample indices it joins, smaller first.
+type Edge = (u8, u8);
+type Table = Vec<Vec<[Edge; 3]>>;
+
+/// Extracts the zero isosurface of a chunk with marching cubes.
+///
+/// Each vertex lies on a lattice edge and is shared by every triangle
+/// touching that edge; its [`VertexKey`] names the edge in world space, so
+/// neighbouring chunks produce matching vertices along their common faces
+/// and can be joined with [`SurfaceMesh::stitch`]. Faces marked as
+/// transitions are closed against the finer neighbour with Transvoxel-style
+/// transition cells instead.
+pub fn marching_cubes(chunk: &DensityChunk) -> SurfaceMesh {
+    let mut builder = SurfaceBuilder::default();
+    let table = regular_table();
+    let n = chunk.cells() as i32;
+    for z in 0..n {
+        for y in 0..n {
+            for x in 0..n {
+                let cell = Vector3i::new(x, y, z);
+                let config = (0..8).fold(0, |config, corner| {
+                    config | ((chunk.value(cell + corner_offset(corner)) < 0.0) as usize) << corner
+                });
+                for triangle in &table[config] {
+                    let corners = triangle.map(|(a, b)| {
+                        lattice_vertex(
+                            &mut builder,
+                            chunk,
+                            cell + corner_offset(a),
+                            cell + corner_offset(b),
+                        )
+                    });
+                    builder.triangle(corners);
+                }
+            }
+        }
+    }
+
+    for face in 0..6 {
+        if chunk.transitions() & (1 << face) != 0 {
+            transition_face(&mut builder, chunk, face);
+        }
+    }
+    builder.mesh
+}
+
+/// Offset of cube corner `x | y << 1 | z << 2` from the cell's minimum.
+pub(super) fn corner_offset(corner: u8) -> Vector3i {
+    Vector3i::new(
+        (corner & 1) as i32,
+        (corner >> 1 & 1) as i32,
+        (corner >> 2 & 1) as i32,
+    )
+}
+
+/// The vertex on the lattice edge between neighbouring points `p` and `q`.
+fn lattice_vertex(
+    builder: &mut SurfaceBuilder,
+    chunk: &DensityChunk,
+    p: Vector3i,
+    q: Vector3i,
+) -> u32 {
+    let (lower, upper) = if p.x + p.y + p.z < q.x + q.y + q.z {
+        (p, q)
+    } else {
+        (q, p)
+    };
+    let axis = (0..3).find(|&axis| lower[axis] != upper[axis]).unwrap();
+    let key = VertexKey {
+        position: chunk.origin() + lower * chunk.step(),
+        kind: axis as u8,
+        lod: chunk.lod() as u8,
+    };
+    builder.vertex(key, || {
+        let (a, b) = (chunk.value(lower), chunk.value(upper));
+        let t = a / (a - b);
+        let mut position = lower.cast::<f64>();
+        position[axis] += t as f64;
+        let position = compress(chunk, position) * chunk.step() as f64;
+        let normal = chunk
+            .gradient(lower)
+            .lerp(&chunk.gradient(upper), t)
+            .normalize();
+        (position.cast(), normal)
+    })
+}
+
+/// Moves a lattice-space position in the outermost cells of a transition
+/// face towards the interior.
+fn compress(chunk: &DensityChunk, mut position: Vector3d) -> Vector3d {
+    let last = chunk.cells() as f64 - 1.0;
+    let scale = 1.0 - TRANSITION_WIDTH;
+    for axis in 0..3 {
+        if chunk.transitions() & face_bit(axis, false) != 0 && position[axis] < 1.0 {
+            position[axis] = 1.0 - (1.0 - position[axis]) * scale;
+        }
+        if chunk.transitions() & face_bit(axis, true) != 0 && position[axis] > last {
+            position[axis] = last + (position[axis] - last) * scale;
+        }
+    }
+    position
+}
+
+/// Fills the gap between the squeezed regular cells and the finer
+/// neighbour on one face.
+///
+/// A transition cell has the nine half-step samples of the face on its
+/// outer side and the four coarse corners, which carry the same values, on
+/// its inner side. Outer vertices match the neighbour's border vertices and
+/// inner ones are the squeezed vertices of the regular cell behind.
+fn transition_face(builder: &mut SurfaceBuilder, chunk: &DensityChunk, face: usize) {
+    let table = transition_table();
+    let d = face / 2;
+    let positive = face % 2 == 1;
+    let (u, v) = ((d + 1) % 3, (d + 2) % 3);
+    // The cell tables are built with the outward normal along +z, so x and
+    // y must form a right-handed frame with it.
+    let (x_axis, y_axis) = if positive { (u, v) } else { (v, u) };
+    let n = chunk.cells() as i32;
+    let plane = if positive { n } else { 0 };
+
+    for j in 0..n {
+        for i in 0..n {
+            // Half-step face coordinates of outer sample `s`, along u and v.
+            let outer = |s: u8| -> (usize, usize) {
+                let x = 2 * i as usize + (s % 3) as usize;
+                let y = 2 * j as usize + (s / 3) as usize;
+                if positive {
+                    (x, y)
+                } else {
+                    (y, x)
+                }
+            };
+            let inner = |s: u8| -> Vector3i {
+                let mut p = Vector3i::zero();
+                p[d] = plane;
+                p[x_axis] = i + ((s - 9) % 2) as i32;
+                p[y_axis] = j + ((s - 9) / 2) as i32;
+                p
+            };
+
+            let config = (0..9).fold(0, |config, s| {
+                let (a, b) = outer(s);
+                config | ((chunk.face_value(face, a, b) < 0.0) as usize) << s
+            });
+            for triangle in &table[config] {
+                let corners = triangle.map(|(a, b)| {
+                    if b < 9 {
+                        face_vertex(builder, chunk, face, outer(a), outer(b))
+                    } else {
+                        debug_assert!(a >= 9, "no crossing between the sides of a transition cell");
+                        lattice_vertex(builder, chunk, inner(a), inner(b))
+                    }
+                });
+                builder.triangle(corners);
+            }
+        }
+    }
+}
+
+/// The vertex on the half-step edge between face samples `p` and `q`, keyed
+/// like the finer neighbour's vertex on the same edge.
+fn face_vertex(
+    builder: &mut SurfaceBuilder,
+    chunk: &DensityChunk,
+    face: usize,
+    p: (usize, usize),
+    q: (usize, usize),
+) -> u32 {
+    let d = face / 2;
+    let (u, v) = ((d + 1) % 3, (d + 2) % 3);
+    let (lower, upper) = if p.0 + p.1 < q.0 + q.1 {
+        (p, q)
+    } else {
+        (q, p)
+    };
+    let axis = if lower.0 != upper.0 { u } else { v };
+
+    let mut fine = Vector3i::zero();
+    fine[d] = if face % 2 == 1 {
+        2 * chunk.cells() as i32
+    } else {
+        0
+    };
+    fine[u] = lower.0 as i32;
+    fine[v] = lower.1 as i32;
+    let key = VertexKey {
+        position: chunk.origin() + fine * (chunk.step() / 2),
+        kind: axis as u8,
+        lod: chunk.lod() as u8 - 1,
+    };
+    builder.vertex(key, || {
+        let a = chunk.face_value(face, lower.0, lower.1);
+        let b = chunk.face_value(face, upper.0, upper.1);
+        let t = a / (a - b);
+        let mut position = fine.cast::<f64>();
+        position[axis] += t as f64;
+        let position = position * (chunk.step() as f64 * 0.5);
+        let normal = chunk
+            .face_gradient(face, lower.0, lower.1)
+            .lerp(&chunk.face_gradient(face, upper.0, upper.1), t)
+            .normalize();
+        (position.cast::<f32>(), normal)
+    })
+}
+
+/// Triangles for each of the 256 sign configurations of a cube, by corner
+/// `x | y << 1 | z << 2`.
+fn regular_table() -> &'static Table {
+    static TABLE: OnceLock<Table> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let faces: Vec<[u8; 4]> = (0..6)
+            .map(|face| {
+                let d = face / 2;
+                let positive = face % 2 == 1;
+                let (u, v) = ((d + 1) % 3, (d + 2) % 3);
+                let square = if positive {
+                    [(0, 0), (1, 0), (1, 1), (0, 1)]
+                } else {
+                    [(0, 0), (0, 1), (1, 1), (1, 0)]
+                };
+                square.map(|(i, j): (u8, u8)| ((positive as u8) << d) | i << u | j << v)
+            })
+            .collect();
+        let faces: Vec<&[u8]> = faces.iter().map(|face| &face[..]).collect();
+        (0..256)
+            .map(|config: usize| polygonize(&faces, |corner| config & (1 << corner) != 0))
+            .collect()
+    })
+}
+
+/// Triangles for each of the 512 sign configurations of a transition cell's
+/// outer samples.
+///
+/// In the cell's own frame the outer face is `z = 1` with samples
+/// `i + 3 * j` at `(i / 2, j / 2)`, and the inner face is `z = 0` with
+/// samples `9 + i + 2 * j` at `(i, j)`, copies of outer samples `2i + 6j`.
+fn transition_table() -> &'static Table {
+    static TABLE: OnceLock<Table> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let outer = |i: u8, j: u8| i + 3 * j;
+        let inner = |i: u8, j: u8| 9 + i + 2 * j;
+        let mut faces: Vec<Vec<u8>> = Vec::new();
+        for j in 0..2 {
+            for i in 0..2 {
+                faces.push(vec![
+                    outer(i, j),
+                    outer(i + 1, j),
+                    outer(i + 1, j + 1),
+                    outer(i, j + 1),
+                ]);
+            }
+        }
+        faces.push(vec![inner(0, 0), inner(0, 1), inner(1, 1), inner(1, 0)]);
+        faces.push(vec![
+            inner(0, 0),
+            inner(1, 0),
+            outer(2, 0),
+            outer(1, 0),
+            outer(0, 0),
+        ]);
+        faces.push(vec![
+            inner(0, 1),
+            outer(0, 2),
+            outer(1, 2),
+            outer(2, 2),
+            inner(1, 1),
+        ]);
+        faces.push(vec![
+            inner(0, 0),
+            outer(0, 0),
+            outer(0, 1),
+            outer(0, 2),
+            inner(0, 1),
+        ]);
+        faces.push(vec![
+            inner(1, 0),
+            inner(1, 1),
+            outer(2, 2),
+            outer(2, 1),
+            outer(2, 0),
+        ]);
+        let faces: Vec<&[u8]> = faces.iter().map(|face| &face[..]).collect();
+
+        (0..512)
+            .map(|config: usize| {
+                polygonize(&faces, |sample| {
+                    let sample = if sample < 9 {
+                        sample
+                    } else {
+                        outer((sample - 9) % 2 * 2, (sample - 9) / 2 * 2)
+                    };
+                    config & (1 << sample) != 0
+                })
+            })
+            .collect()
+    })
+}
+
+/// Triangulates the surface inside a convex cell from its sign pattern.
+///
+/// `faces` lists the cell's faces as sample indices counterclockwise around
+/// the outward normal. Walking each face, the surface enters the solid at
+/// one sign change and leaves at the next, which yields a segment with the
+/// solid on its right; face-adjacent cells see the same segments reversed,
+/// so their surfaces meet without cracks. The segments chain into closed
+/// loops that are fanned into triangles, counterclockwise seen from outside
+/// the solid.
+fn polygonize(faces: &[&[u8]], inside: impl Fn(u8) -> bool) -> Vec<[Edge; 3]> {
+    let mut segments: Vec<(Edge, Edge)> = Vec::new();
+    for face in faces {
+        let crossings: Vec<(Edge, bool)> = (0..face.len())
+            .filter_map(|i| {
+                let (a, b) = (face[i], face[(i + 1) % face.len()]);
+                (inside(a) != inside(b)).then_some(((a.min(b), a.max(b)), inside(b)))
+            })
+            .collect();
+        for (i, &(edge, entering)) in crossings.iter().enumerate() {
+            if entering {
+                segments.push((edge, crossings[(i + 1) % crossings.len()].0));
+            }
+        }
+    }
+
+    let mut triangles = Vec::new();
+    let mut used = vec![false; segments.len()];
+    while let Some(first) = used.iter().position(|&used| !used) {
+        let mut polygon = Vec::new();
+        let mut current = first;
+        while !used[current] {
+            used[current] = true;
+            polygon.push(segments[current].0);
+            let end = segments[current].1;
+            current = segments
+                .iter()
+                .position(|segment| segment.0 == end)
+                .expect("isosurface loops are closed");
+        }
+        for i in 1..polygon.len() - 1 {
+            triangles.push([polygon[0], polygon[i], polygon[i + 1]]);
+        }
+    }
+    triangles
+}
@@ -0,0 +1,10 @@
+pub mod block;
+pub mod chunk;
+pub mod dag;
+pub mod density;
+pub mod dual_contouring;
+pub mod marching_cubes;
+pub mod palette;
+pub mod surface;
+pub mod svo;
+pub mod world;
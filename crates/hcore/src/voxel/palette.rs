@@ -0,0 +1,208 @@
+use super::block::BlockId;
+
+/// A fixed-length array of small unsigned integers packed into 64-bit words.
+/// Widths are powers of two, so no entry straddles two words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackedArray {
+    bits: u32,
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl PackedArray {
+    /// Zero-filled. Panics unless `bits` is a power of two up to 32.
+    pub fn new(len: usize, bits: u32) -> Self {
+        assert!(bits.is_power_of_two() && bits <= 32, "unsupported width");
+        let per_word = (64 / bits) as usize;
+        Self {
+            bits,
+            len,
+            words: vec![0; len.div_ceil(per_word)],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    #[inline]
+    fn locate(&self, index: usize) -> (usize, u32) {
+        debug_assert!(index < self.len, "index out of bounds");
+        let per_word = (64 / self.bits) as usize;
+        (index / per_word, (index % per_word) as u32 * self.bits)
+    }
+
+    #[inline]
+    fn mask(&self) -> u64 {
+        u64::MAX >> (64 - self.bits)
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> u32 {
+        let (word, shift) = self.locate(index);
+        ((self.words[word] >> shift) & self.mask()) as u32
+    }
+
+    /// Panics in debug builds if `value` does not fit the width.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: u32) {
+        debug_assert!(value as u64 <= self.mask(), "value too wide");
+        let (word, shift) = self.locate(index);
+        let mask = self.mask() << shift;
+        self.words[word] = (self.words[word] & !mask) | ((value as u64) << shift);
+    }
+
+    /// A copy with every entry stored at a new width. Panics if an entry
+    /// does not fit.
+    pub fn with_bits(&self, bits: u32) -> Self {
+        let mut result = Self::new(self.len, bits);
+        for (index, value) in self.iter().enumerate() {
+            assert!(value as u64 <= result.mask(), "value too wide");
+            result.set(index, value);
+        }
+        result
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..self.len).map(move |index| self.get(index))
+    }
+}
+
+/// Blocks stored as a palette of distinct ids plus one packed palette index
+/// per cell, using as few bits per cell as the palette size allows.
+///
+/// Entries keep a reference count so the palette knows which blocks are
+/// still present; slots that drop to zero are reused before the palette
+/// grows, and [`compact`](Self::compact) removes them.
+#[derive(Debug, Clone)]
+pub struct PalettedStorage {
+    palette: Vec<BlockId>,
+    counts: Vec<u32>,
+    indices: PackedArray,
+}
+
+impl PalettedStorage {
+    /// `len` cells, all holding `block`.
+    pub fn new(len: usize, block: BlockId) -> Self {
+        Self {
+            palette: vec![block],
+            counts: vec![len as u32],
+            indices: PackedArray::new(len, 1),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn bits_per_index(&self) -> u32 {
+        self.indices.bits()
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> BlockId {
+        self.palette[self.indices.get(index) as usize]
+    }
+
+    /// Stores `block` at `index` and returns the block that was there.
+    pub fn set(&mut self, index: usize, block: BlockId) -> BlockId {
+        let old_entry = self.indices.get(index) as usize;
+        let previous = self.palette[old_entry];
+        if previous == block {
+            return previous;
+        }
+
+        let entry = self.entry_for(block);
+        self.counts[old_entry] -= 1;
+        self.counts[entry] += 1;
+        self.indices.set(index, entry as u32);
+        previous
+    }
+
+    /// The palette entry holding `block`, adding one if needed.
+    fn entry_for(&mut self, block: BlockId) -> usize {
+        if let Some(entry) = self
+            .palette
+            .iter()
+            .zip(&self.counts)
+            .position(|(&candidate, &count)| candidate == block && count > 0)
+        {
+            return entry;
+        }
+        if let Some(entry) = self.counts.iter().position(|&count| count == 0) {
+            self.palette[entry] = block;
+            return entry;
+        }
+
+        self.palette.push(block);
+        self.counts.push(0);
+        if self.palette.len() > 1 << self.indices.bits() {
+            self.indices = self.indices.with_bits(self.indices.bits() * 2);
+        }
+        self.palette.len() - 1
+    }
+
+    /// The block filling every cell, if there is only one.
+    pub fn uniform_block(&self) -> Option<BlockId> {
+        let len = self.len() as u32;
+        self.counts
+            .iter()
+            .position(|&count| count == len)
+            .map(|entry| self.palette[entry])
+    }
+
+    /// The distinct blocks present, in palette order.
+    pub fn palette(&self) -> impl Iterator<Item = BlockId> + '_ {
+        self.palette
+            .iter()
+            .zip(&self.counts)
+            .filter(|(_, &count)| count > 0)
+            .map(|(&block, _)| block)
+    }
+
+    /// Drops unused palette entries and narrows the indices to the smallest
+    /// width that still fits.
+    pub fn compact(&mut self) {
+        let mut remap = vec![0u32; self.palette.len()];
+        let mut palette = Vec::new();
+        let mut counts = Vec::new();
+        for (entry, (&block, &count)) in self.palette.iter().zip(&self.counts).enumerate() {
+            if count > 0 {
+                remap[entry] = palette.len() as u32;
+                palette.push(block);
+                counts.push(count);
+            }
+        }
+
+        let mut bits = 1;
+        while palette.len() > 1 << bits {
+            bits *= 2;
+        }
+        let mut indices = PackedArray::new(self.len(), bits);
+        for (index, entry) in self.indices.iter().enumerate() {
+            indices.set(index, remap[entry as usize]);
+        }
+
+        self.palette = palette;
+        self.counts = counts;
+        self.indices = indices;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = BlockId> + '_ {
+        self.indices
+            .iter()
+            .map(|entry| self.palette[entry as usize])
+    }
+}
@@ -0,0 +1,99 @@
+use hmath::vector::{Vector3f, Vector3i};
+use std::collections::HashMap;
+
+/// Identifies where a surface vertex came from, so that chunks meshed
+/// separately can be welded back together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VertexKey {
+    /// World position of the lower lattice point of the edge, or of the
+    /// minimum corner of the cell.
+    pub position: Vector3i,
+    /// 0, 1 or 2 for an edge along x, y or z; [`VertexKey::CELL`] for a
+    /// vertex placed inside a cell.
+    pub kind: u8,
+    /// Level of detail of the lattice the edge or cell belongs to.
+    pub lod: u8,
+}
+
+impl VertexKey {
+    pub const CELL: u8 = 3;
+}
+
+/// An indexed triangle mesh of an isosurface.
+///
+/// Positions are relative to the origin the mesh was extracted for and
+/// normals are unit gradients of the density field. Triangles are
+/// counterclockwise seen from outside the solid.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SurfaceMesh {
+    pub positions: Vec<Vector3f>,
+    pub normals: Vec<Vector3f>,
+    pub indices: Vec<u32>,
+    /// One per vertex.
+    pub keys: Vec<VertexKey>,
+}
+
+impl SurfaceMesh {
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// Merges chunk meshes, each given with the origin it was extracted for,
+    /// into one mesh relative to `origin`. Vertices with equal keys are
+    /// welded; the first occurrence wins.
+    pub fn stitch<'a>(
+        origin: Vector3i,
+        parts: impl IntoIterator<Item = (Vector3i, &'a SurfaceMesh)>,
+    ) -> SurfaceMesh {
+        let mut builder = SurfaceBuilder::default();
+        for (part_origin, part) in parts {
+            let offset: Vector3f = (part_origin - origin).cast();
+            let remap: Vec<u32> = (0..part.keys.len())
+                .map(|i| {
+                    builder.vertex(part.keys[i], || {
+                        (part.positions[i] + offset, part.normals[i])
+                    })
+                })
+                .collect();
+            builder
+                .mesh
+                .indices
+                .extend(part.indices.iter().map(|&index| remap[index as usize]));
+        }
+        builder.mesh
+    }
+}
+
+/// Accumulates a [`SurfaceMesh`], creating each keyed vertex once.
+#[derive(Default)]
+pub(super) struct SurfaceBuilder {
+    pub(super) mesh: SurfaceMesh,
+    lookup: HashMap<VertexKey, u32>,
+}
+
+impl SurfaceBuilder {
+    /// The index of the vertex with this key, computing its position and
+    /// normal on first use.
+    pub(super) fn vertex(
+        &mut self,
+        key: VertexKey,
+        make: impl FnOnce() -> (Vector3f, Vector3f),
+    ) -> u32 {
+        let mesh = &mut self.mesh;
+        *self.lookup.entry(key).or_insert_with(|| {
+            let (position, normal) = make();
+            mesh.positions.push(position);
+            mesh.normals.push(normal);
+            mesh.keys.push(key);
+            (mesh.keys.len() - 1) as u32
+        })
+    }
+
+    pub(super) fn triangle(&mut self, corners: [u32; 3]) {
+        self.mesh.indices.extend(corners);
+    }
+}
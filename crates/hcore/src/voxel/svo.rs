@@ -0,0 +1,524 @@
+use super::block::BlockId;
+use super::chunk::{Chunk, CHUNK_BITS, CHUNK_SIZE};
+use super::world::VoxelWorld;
+use hmath::geometry::ray::Ray;
+use hmath::geometry::voxel_traversal::VoxelHit;
+use hmath::vector::{Vector3, Vector3d, Vector3i};
+
+/// One octree node. Leaves cover a cube of a single block type; interior
+/// nodes own eight children stored next to each other, child `i` covering
+/// the octant offset by `(i & 1, (i >> 1) & 1, (i >> 2) & 1)` half sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SvoNode {
+    /// Index of the first child, or zero for leaves. The root is node zero,
+    /// so no child can have that index.
+    pub first_child: u32,
+    /// For leaves, the block filling the cube. For interior nodes, the most
+    /// common block among the non-empty children, used as the coarser level
+    /// of detail.
+    pub block: BlockId,
+    /// Bit `i` is set when child `i` contains anything but air; zero for
+    /// leaves.
+    pub child_mask: u8,
+}
+
+impl SvoNode {
+    pub fn leaf(block: BlockId) -> Self {
+        Self {
+            first_child: 0,
+            block,
+            child_mask: 0,
+        }
+    }
+
+    #[inline]
+    pub fn is_leaf(&self) -> bool {
+        self.first_child == 0
+    }
+
+    /// Whether the node is all air. Interior nodes never are, since eight
+    /// equal leaves are always merged.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.is_leaf() && self.block.is_air()
+    }
+}
+
+#[inline]
+fn child_offset(child: usize) -> Vector3i {
+    Vector3i::new(
+        child as i32 & 1,
+        (child as i32 >> 1) & 1,
+        (child as i32 >> 2) & 1,
+    )
+}
+
+/// A sparse voxel octree over a cube of `2^depth` blocks whose minimum
+/// corner is `origin`.
+///
+/// Uniform regions are single leaves, so empty space and solid interiors
+/// cost one node regardless of size. Nodes live in one flat array that
+/// [`to_gpu_buffer`](Self::to_gpu_buffer) can hand to a shader as is.
+#[derive(Debug, Clone)]
+pub struct SparseVoxelOctree {
+    origin: Vector3i,
+    depth: u32,
+    nodes: Vec<SvoNode>,
+    /// First indices of child groups released by merges, reused before
+    /// the array grows.
+    free: Vec<u32>,
+}
+
+impl SparseVoxelOctree {
+    /// An all-air tree. Panics if `depth` exceeds 30.
+    pub fn new(origin: Vector3i, depth: u32) -> Self {
+        assert!(depth <= 30, "octree too deep");
+        Self {
+            origin,
+            depth,
+            nodes: vec![SvoNode::leaf(BlockId::AIR)],
+            free: Vec::new(),
+        }
+    }
+
+    /// A tree of depth [`CHUNK_BITS`] holding one chunk whose minimum corner
+    /// is at `origin`.
+    pub fn from_chunk(chunk: &Chunk, origin: Vector3i) -> Self {
+        let mut tree = Self::new(origin, CHUNK_BITS);
+        tree.build(0, Vector3i::zero(), CHUNK_SIZE as i32, &|min, size| {
+            if size == 1 {
+                Some(chunk.get(min.x as usize, min.y as usize, min.z as usize))
+            } else {
+                chunk.uniform_block()
+            }
+        });
+        tree
+    }
+
+    /// A tree of the given depth holding the blocks of `world` from
+    /// `origin` on. Regions without stored blocks, and regions covered by
+    /// uniform chunks of one block, become single leaves without visiting
+    /// their blocks, so the cost follows the stored chunks rather than the
+    /// extent.
+    pub fn from_world(world: &VoxelWorld, origin: Vector3i, depth: u32) -> Self {
+        let mut tree = Self::new(origin, depth);
+        // Every chunk holding anything but air, sorted by x so a region only
+        // scans the slab of chunks it spans.
+        let mut stored: Vec<(Vector3i, Option<BlockId>)> = world
+            .chunks()
+            .filter(|(_, chunk)| !chunk.is_empty())
+            .map(|(position, chunk)| (position, chunk.uniform_block()))
+            .collect();
+        stored.sort_unstable_by_key(|(position, _)| (position.x, position.y, position.z));
+
+        tree.build(0, Vector3i::zero(), 1 << depth, &|min, size| {
+            let min = origin + min;
+            if size == 1 {
+                return Some(world.block(min));
+            }
+            let first = VoxelWorld::chunk_position(min);
+            let last =
+                VoxelWorld::chunk_position(min + Vector3i::new(size - 1, size - 1, size - 1));
+            let start = stored.partition_point(|(position, _)| position.x < first.x);
+            let end = stored.partition_point(|(position, _)| position.x <= last.x);
+            let mut inside = stored[start..end].iter().filter(|(position, _)| {
+                (first.y..=last.y).contains(&position.y) && (first.z..=last.z).contains(&position.z)
+            });
+
+            let Some(&(_, block)) = inside.next() else {
+                return Some(BlockId::AIR);
+            };
+            // Missing chunks are air, so a solid region needs every chunk it
+            // overlaps stored and filled with the same block.
+            let block = block?;
+            let span = (last - first).cast::<i64>() + Vector3::new(1, 1, 1);
+            let mut found = 1;
+            for &(_, other) in inside {
+                if other != Some(block) {
+                    return None;
+                }
+                found += 1;
+            }
+            (found == span.x * span.y * span.z).then_some(block)
+        });
+        tree
+    }
+
+    /// Fills node `index` for the cube at `min` (relative to the origin).
+    /// `uniform` reports the block filling a cube when it is known to hold
+    /// only one, and must answer for single blocks.
+    fn build(
+        &mut self,
+        index: usize,
+        min: Vector3i,
+        size: i32,
+        uniform: &dyn Fn(Vector3i, i32) -> Option<BlockId>,
+    ) {
+        if let Some(block) = uniform(min, size) {
+            self.nodes[index] = SvoNode::leaf(block);
+            return;
+        }
+
+        let half = size / 2;
+        let first = self.nodes.len();
+        self.nodes.extend([SvoNode::leaf(BlockId::AIR); 8]);
+        for child in 0..8 {
+            self.build(
+                first + child,
+                min + child_offset(child) * half,
+                half,
+                uniform,
+            );
+        }
+        self.nodes[index].first_child = first as u32;
+        // Everything after `first` belongs to this node, so a merge can
+        // simply drop it.
+        if self.refresh(index) {
+            self.nodes.truncate(first);
+        }
+    }
+
+    pub fn origin(&self) -> Vector3i {
+        self.origin
+    }
+
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Blocks along each edge.
+    pub fn size(&self) -> i32 {
+        1 << self.depth
+    }
+
+    /// All nodes, the root first. Released groups stay in the array until
+    /// [`compact`](Self::compact) but are unreachable from the root.
+    pub fn nodes(&self) -> &[SvoNode] {
+        &self.nodes
+    }
+
+    /// The block at a position, or air outside the tree.
+    pub fn block(&self, position: Vector3i) -> BlockId {
+        self.lod(position, 0)
+    }
+
+    /// The block at a position seen at a coarser level of detail, where
+    /// level `n` stops at cubes of `2^n` blocks and reports their most common
+    /// non-air block. Level zero is full resolution.
+    pub fn lod(&self, position: Vector3i, level: u32) -> BlockId {
+        lookup(&self.nodes, self.origin, self.depth, position, level)
+    }
+
+    /// Stores `block`, splitting and merging nodes along the way to keep the
+    /// tree minimal. Returns the block that was there, or `None` outside the
+    /// tree.
+    pub fn set_block(&mut self, position: Vector3i, block: BlockId) -> Option<BlockId> {
+        let local = local_position(self.origin, self.depth, position)?;
+        let mut path = Vec::with_capacity(self.depth as usize);
+        let mut index = 0;
+        let mut size = self.size();
+        let mut min = Vector3i::zero();
+        while size > 1 {
+            let node = self.nodes[index];
+            if node.is_leaf() {
+                if node.block == block {
+                    return Some(block);
+                }
+                self.split(index);
+            }
+            path.push(index);
+            size /= 2;
+            let child = child_containing(local, min, size);
+            min += child_offset(child) * size;
+            index = self.nodes[index].first_child as usize + child;
+        }
+
+        let previous = self.nodes[index].block;
+        if previous == block {
+            return Some(previous);
+        }
+        self.nodes[index] = SvoNode::leaf(block);
+        for &ancestor in path.iter().rev() {
+            let first = self.nodes[ancestor].first_child;
+            if self.refresh(ancestor) {
+                self.free.push(first);
+            }
+        }
+        Some(previous)
+    }
+
+    /// Turns a leaf into an interior node with eight copies of itself.
+    fn split(&mut self, index: usize) {
+        let leaf = SvoNode::leaf(self.nodes[index].block);
+        let first = match self.free.pop() {
+            Some(first) => first as usize,
+            None => {
+                self.nodes.extend([leaf; 8]);
+                self.nodes.len() - 8
+            }
+        };
+        self.nodes[first..first + 8].fill(leaf);
+        self.nodes[index].first_child = first as u32;
+        self.nodes[index].child_mask = if leaf.block.is_air() { 0 } else { 0xff };
+    }
+
+    /// Recomputes an interior node from its children, merging them into a
+    /// leaf if they are equal leaves. Returns whether it merged.
+    fn refresh(&mut self, index: usize) -> bool {
+        let first = self.nodes[index].first_child as usize;
+        let children = &self.nodes[first..first + 8];
+        let block = children[0].block;
+        if children
+            .iter()
+            .all(|child| child.is_leaf() && child.block == block)
+        {
+            self.nodes[index] = SvoNode::leaf(block);
+            return true;
+        }
+
+        let mut child_mask = 0;
+        let mut best = (0, BlockId::AIR);
+        for (i, child) in children.iter().enumerate() {
+            if child.is_empty() {
+                continue;
+            }
+            child_mask |= 1 << i;
+            let votes = children
+                .iter()
+                .filter(|other| !other.is_empty() && other.block == child.block)
+                .count();
+            if votes > best.0 {
+                best = (votes, child.block);
+            }
+        }
+        self.nodes[index].child_mask = child_mask;
+        self.nodes[index].block = best.1;
+        false
+    }
+
+    /// The first non-air block along the ray within `max_distance`.
+    pub fn raycast(&self, ray: &Ray<f64>, max_distance: f64) -> Option<(VoxelHit<f64>, BlockId)> {
+        self.raycast_lod(ray, max_distance, 0)
+    }
+
+    /// [`raycast`](Self::raycast) against the tree seen at a level of detail
+    /// as in [`lod`](Self::lod), for cheaper distant rays. The hit cell is
+    /// the block where the ray enters the coarse cube.
+    ///
+    /// Children are visited nearest first and the search stops at the first
+    /// solid leaf, so the cost depends on what the ray passes rather than on
+    /// the tree's size.
+    pub fn raycast_lod(
+        &self,
+        ray: &Ray<f64>,
+        max_distance: f64,
+        level: u32,
+    ) -> Option<(VoxelHit<f64>, BlockId)> {
+        raycast(
+            &self.nodes,
+            self.origin,
+            self.depth,
+            ray,
+            max_distance,
+            level,
+        )
+    }
+
+    /// Rewrites the node array in depth-first order, dropping released
+    /// groups. A compacted tree matches a fresh build of the same blocks.
+    pub fn compact(&mut self) {
+        let mut nodes = vec![self.nodes[0]];
+        self.copy_children(0, 0, &mut nodes);
+        self.nodes = nodes;
+        self.free.clear();
+    }
+
+    fn copy_children(&self, old: usize, new: usize, nodes: &mut Vec<SvoNode>) {
+        let node = self.nodes[old];
+        if node.is_leaf() {
+            return;
+        }
+        let old_first = node.first_child as usize;
+        let new_first = nodes.len();
+        nodes.extend_from_slice(&self.nodes[old_first..old_first + 8]);
+        nodes[new].first_child = new_first as u32;
+        for child in 0..8 {
+            self.copy_children(old_first + child, new_first + child, nodes);
+        }
+    }
+
+    /// The nodes as two `u32` words each, ready for a storage buffer: the
+    /// first child index, then `block | child_mask << 16`.
+    pub fn to_gpu_buffer(&self) -> Vec<u32> {
+        gpu_words(&self.nodes)
+    }
+}
+
+pub(super) fn gpu_words(nodes: &[SvoNode]) -> Vec<u32> {
+    nodes
+        .iter()
+        .flat_map(|node| {
+            [
+                node.first_child,
+                node.block.0 as u32 | (node.child_mask as u32) << 16,
+            ]
+        })
+        .collect()
+}
+
+fn local_position(origin: Vector3i, depth: u32, position: Vector3i) -> Option<Vector3i> {
+    // Offsets too large for an i32 are outside any tree.
+    let offset = |axis: usize| {
+        position[axis]
+            .checked_sub(origin[axis])
+            .filter(|v| (0..1 << depth).contains(v))
+    };
+    Some(Vector3i::new(offset(0)?, offset(1)?, offset(2)?))
+}
+
+#[inline]
+fn child_containing(local: Vector3i, min: Vector3i, half: i32) -> usize {
+    let bit = |axis: usize| ((local[axis] - min[axis]) >= half) as usize;
+    bit(0) | (bit(1) << 1) | (bit(2) << 2)
+}
+
+/// [`SparseVoxelOctree::lod`] over any node array in the octree layout,
+/// including the shared groups of a DAG.
+pub(super) fn lookup(
+    nodes: &[SvoNode],
+    origin: Vector3i,
+    depth: u32,
+    position: Vector3i,
+    level: u32,
+) -> BlockId {
+    let Some(local) = local_position(origin, depth, position) else {
+        return BlockId::AIR;
+    };
+    let mut index = 0;
+    let mut size = 1 << depth;
+    let mut min = Vector3i::zero();
+    while !nodes[index].is_leaf() && size > 1 << level {
+        size /= 2;
+        let child = child_containing(local, min, size);
+        min += child_offset(child) * size;
+        index = nodes[index].first_child as usize + child;
+    }
+    nodes[index].block
+}
+
+/// [`SparseVoxelOctree::raycast_lod`] over any node array in the octree
+/// layout. Rays with non-finite components never hit.
+pub(super) fn raycast(
+    nodes: &[SvoNode],
+    origin: Vector3i,
+    depth: u32,
+    ray: &Ray<f64>,
+    max_distance: f64,
+    level: u32,
+) -> Option<(VoxelHit<f64>, BlockId)> {
+    let finite = (0..3).all(|axis| ray.origin[axis].is_finite() && ray.direction[axis].is_finite());
+    if !finite || max_distance.is_nan() {
+        return None;
+    }
+    let traversal = Traversal {
+        nodes,
+        ray,
+        max_distance,
+        lod_size: 1 << level,
+    };
+    traversal.node(0, origin, 1 << depth)
+}
+
+struct Traversal<'a> {
+    nodes: &'a [SvoNode],
+    ray: &'a Ray<f64>,
+    max_distance: f64,
+    lod_size: i32,
+}
+
+impl Traversal<'_> {
+    fn node(&self, index: usize, min: Vector3i, size: i32) -> Option<(VoxelHit<f64>, BlockId)> {
+        let ray = self.ray;
+        let node = self.nodes[index];
+        if node.is_empty() {
+            return None;
+        }
+        let (t_enter, t_exit, axis) = slab(ray, min, size)?;
+        if t_exit < 0.0 || t_enter > self.max_distance {
+            return None;
+        }
+
+        if node.is_leaf() || size <= self.lod_size {
+            let mut normal = Vector3i::zero();
+            let distance = if t_enter > 0.0 {
+                normal[axis] = if ray.direction[axis] > 0.0 { -1 } else { 1 };
+                t_enter
+            } else {
+                0.0
+            };
+            let point = ray.at(distance);
+            let mut cell: Vector3i = point.floor_cast();
+            for a in 0..3 {
+                cell[a] = cell[a].clamp(min[a], min[a] + size - 1);
+            }
+            return Some((
+                VoxelHit {
+                    cell,
+                    distance,
+                    normal,
+                },
+                node.block,
+            ));
+        }
+
+        let half = size / 2;
+        let first = node.first_child as usize;
+        let mut order: Vec<(f64, usize)> = (0..8)
+            .filter(|&child| node.child_mask & (1 << child) != 0)
+            .filter_map(|child| {
+                let (t_enter, _, _) = slab(ray, min + child_offset(child) * half, half)?;
+                Some((t_enter, child))
+            })
+            .collect();
+        order.sort_by(|a, b| a.0.total_cmp(&b.0));
+        order
+            .into_iter()
+            .find_map(|(_, child)| self.node(first + child, min + child_offset(child) * half, half))
+    }
+}
+
+/// The ray's parameter interval inside a cube and the axis it enters
+/// through.
+fn slab(ray: &Ray<f64>, min: Vector3i, size: i32) -> Option<(f64, f64, usize)> {
+    let min: Vector3d = min.cast();
+    let max = min + Vector3d::new(size as f64, size as f64, size as f64);
+    let mut t_enter = f64::NEG_INFINITY;
+    let mut t_exit = f64::INFINITY;
+    let mut enter_axis = 0;
+    for axis in 0..3 {
+        let origin = ray.origin[axis];
+        let direction = ray.direction[axis];
+        if direction == 0.0 {
+            if origin < min[axis] || origin > max[axis] {
+                return None;
+            }
+            continue;
+        }
+
+        let inv = 1.0 / direction;
+        let mut t0 = (min[axis] - origin) * inv;
+        let mut t1 = (max[axis] - origin) * inv;
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+        if t0 > t_enter {
+            t_enter = t0;
+            enter_axis = axis;
+        }
+        t_exit = t_exit.min(t1);
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+    Some((t_enter, t_exit, enter_axis))
+}
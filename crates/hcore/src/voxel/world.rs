@@ -0,0 +1,246 @@
+use super::block::BlockId;
+use super::chunk::{Chunk, CHUNK_BITS, CHUNK_SIZE};
+use hmath::vector::Vector3i;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+const FACE_NEIGHBOURS: [Vector3i; 6] = [
+    Vector3i { x: -1, y: 0, z: 0 },
+    Vector3i { x: 1, y: 0, z: 0 },
+    Vector3i { x: 0, y: -1, z: 0 },
+    Vector3i { x: 0, y: 1, z: 0 },
+    Vector3i { x: 0, y: 0, z: -1 },
+    Vector3i { x: 0, y: 0, z: 1 },
+];
+
+/// Sparse voxel storage: chunks keyed by chunk coordinates, where chunk `c`
+/// covers blocks `c * CHUNK_SIZE` up to `(c + 1) * CHUNK_SIZE` on each axis.
+///
+/// Missing chunks read as air, and [`set_block`](Self::set_block) and
+/// [`fill`](Self::fill) drop chunks they leave all air.
+/// Every edit marks the chunks whose contents or exposed faces changed as
+/// dirty, including neighbours across a chunk border, so meshes and other
+/// derived data can be rebuilt from [`drain_dirty`](Self::drain_dirty).
+#[derive(Debug, Clone, Default)]
+pub struct VoxelWorld {
+    chunks: HashMap<Vector3i, Chunk>,
+    dirty: HashSet<Vector3i>,
+}
+
+impl VoxelWorld {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The chunk containing a block.
+    #[inline]
+    pub fn chunk_position(block: Vector3i) -> Vector3i {
+        Vector3i::new(
+            block.x >> CHUNK_BITS,
+            block.y >> CHUNK_BITS,
+            block.z >> CHUNK_BITS,
+        )
+    }
+
+    /// A block's position inside its chunk.
+    #[inline]
+    pub fn local_position(block: Vector3i) -> (usize, usize, usize) {
+        let mask = CHUNK_SIZE as i32 - 1;
+        (
+            (block.x & mask) as usize,
+            (block.y & mask) as usize,
+            (block.z & mask) as usize,
+        )
+    }
+
+    /// The block at a chunk's minimum corner.
+    #[inline]
+    pub fn chunk_origin(chunk: Vector3i) -> Vector3i {
+        Vector3i::new(
+            chunk.x << CHUNK_BITS,
+            chunk.y << CHUNK_BITS,
+            chunk.z << CHUNK_BITS,
+        )
+    }
+
+    pub fn block(&self, position: Vector3i) -> BlockId {
+        let (x, y, z) = Self::local_position(position);
+        self.chunks
+            .get(&Self::chunk_position(position))
+            .map_or(BlockId::AIR, |chunk| chunk.get(x, y, z))
+    }
+
+    /// Stores `block` and returns the block that was there.
+    pub fn set_block(&mut self, position: Vector3i, block: BlockId) -> BlockId {
+        let chunk_position = Self::chunk_position(position);
+        let (x, y, z) = Self::local_position(position);
+        let previous = match self.chunks.get_mut(&chunk_position) {
+            Some(chunk) => chunk.set(x, y, z, block),
+            None if block.is_air() => return BlockId::AIR,
+            None => self
+                .chunks
+                .entry(chunk_position)
+                .or_default()
+                .set(x, y, z, block),
+        };
+        if previous == block {
+            return previous;
+        }
+
+        if self.chunks[&chunk_position].is_empty() {
+            self.chunks.remove(&chunk_position);
+        }
+        self.dirty.insert(chunk_position);
+        let last = CHUNK_SIZE - 1;
+        for (axis, local) in [x, y, z].into_iter().enumerate() {
+            let step = match local {
+                0 => -1,
+                l if l == last => 1,
+                _ => continue,
+            };
+            let mut neighbour = chunk_position;
+            neighbour[axis] += step;
+            if self.chunks.contains_key(&neighbour) {
+                self.dirty.insert(neighbour);
+            }
+        }
+        previous
+    }
+
+    /// Sets every block with `min <= position < max` on all axes. Chunks the
+    /// box covers completely are replaced outright instead of block by block.
+    pub fn fill(&mut self, min: Vector3i, max: Vector3i, block: BlockId) {
+        if min.x >= max.x || min.y >= max.y || min.z >= max.z {
+            return;
+        }
+        let first = Self::chunk_position(min);
+        let last = Self::chunk_position(max - Vector3i::new(1, 1, 1));
+        for cz in first.z..=last.z {
+            for cy in first.y..=last.y {
+                for cx in first.x..=last.x {
+                    self.fill_chunk(Vector3i::new(cx, cy, cz), min, max, block);
+                }
+            }
+        }
+    }
+
+    fn fill_chunk(
+        &mut self,
+        chunk_position: Vector3i,
+        min: Vector3i,
+        max: Vector3i,
+        block: BlockId,
+    ) {
+        let origin = Self::chunk_origin(chunk_position);
+        let size = CHUNK_SIZE as i32;
+        let start = Vector3i::new(
+            (min.x - origin.x).max(0),
+            (min.y - origin.y).max(0),
+            (min.z - origin.z).max(0),
+        );
+        let end = Vector3i::new(
+            (max.x - origin.x).min(size),
+            (max.y - origin.y).min(size),
+            (max.z - origin.z).min(size),
+        );
+        let covered = start == Vector3i::zero() && end == Vector3i::new(size, size, size);
+
+        let chunk = match self.chunks.entry(chunk_position) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(_) if block.is_air() => return,
+            Entry::Vacant(entry) => entry.insert(Chunk::default()),
+        };
+        if chunk.uniform_block() == Some(block) {
+            return;
+        }
+        if covered {
+            chunk.fill(block);
+        } else {
+            for z in start.z..end.z {
+                for y in start.y..end.y {
+                    for x in start.x..end.x {
+                        chunk.set(x as usize, y as usize, z as usize, block);
+                    }
+                }
+            }
+        }
+
+        if chunk.is_empty() {
+            self.chunks.remove(&chunk_position);
+        }
+        self.mark_dirty_with_neighbours(chunk_position);
+    }
+
+    pub fn chunk(&self, position: Vector3i) -> Option<&Chunk> {
+        self.chunks.get(&position)
+    }
+
+    /// Mutable access to a chunk, which is marked dirty along with its
+    /// neighbours.
+    pub fn chunk_mut(&mut self, position: Vector3i) -> Option<&mut Chunk> {
+        if !self.chunks.contains_key(&position) {
+            return None;
+        }
+        self.mark_dirty_with_neighbours(position);
+        self.chunks.get_mut(&position)
+    }
+
+    /// Inserts or replaces a chunk, returning the old one. Empty chunks are
+    /// not stored.
+    pub fn insert_chunk(&mut self, position: Vector3i, chunk: Chunk) -> Option<Chunk> {
+        self.mark_dirty_with_neighbours(position);
+        if chunk.is_empty() {
+            self.chunks.remove(&position)
+        } else {
+            self.chunks.insert(position, chunk)
+        }
+    }
+
+    pub fn remove_chunk(&mut self, position: Vector3i) -> Option<Chunk> {
+        let removed = self.chunks.remove(&position);
+        if removed.is_some() {
+            self.mark_dirty_with_neighbours(position);
+        }
+        removed
+    }
+
+    /// Stored chunks in no particular order.
+    pub fn chunks(&self) -> impl Iterator<Item = (Vector3i, &Chunk)> {
+        self.chunks
+            .iter()
+            .map(|(&position, chunk)| (position, chunk))
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn mark_dirty(&mut self, position: Vector3i) {
+        self.dirty.insert(position);
+    }
+
+    fn mark_dirty_with_neighbours(&mut self, position: Vector3i) {
+        self.dirty.insert(position);
+        for offset in FACE_NEIGHBOURS {
+            let neighbour = position + offset;
+            if self.chunks.contains_key(&neighbour) {
+                self.dirty.insert(neighbour);
+            }
+        }
+    }
+
+    pub fn is_dirty(&self, position: Vector3i) -> bool {
+        self.dirty.contains(&position)
+    }
+
+    /// Dirty chunk positions in no particular order. A position may no
+    /// longer hold a chunk if its last solid block was removed.
+    pub fn dirty_chunks(&self) -> impl Iterator<Item = Vector3i> + '_ {
+        self.dirty.iter().copied()
+    }
+
+    /// Takes the dirty set, leaving it empty.
+    pub fn drain_dirty(&mut self) -> impl Iterator<Item = Vector3i> + '_ {
+        self.dirty.drain()
+    }
+}
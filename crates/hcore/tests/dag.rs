@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use hcore::voxel::block::BlockId;
+    use hcore::voxel::dag::SparseVoxelDag;
+    use hcore::voxel::svo::SparseVoxelOctree;
+    use hcore::voxel::world::VoxelWorld;
+    use hmath::geometry::ray::Ray;
+    use hmath::vector::{Vector3d, Vector3i};
+
+    const STONE: BlockId = BlockId(1);
+    const DIRT: BlockId = BlockId(2);
+
+    /// A repeating pattern of small pillars, which a DAG shares heavily.
+    fn pillars() -> SparseVoxelOctree {
+        let mut world = VoxelWorld::new();
+        world.fill(Vector3i::zero(), Vector3i::new(64, 2, 64), STONE);
+        for z in (0..64).step_by(8) {
+            for x in (0..64).step_by(8) {
+                world.fill(
+                    Vector3i::new(x + 2, 2, z + 2),
+                    Vector3i::new(x + 4, 6, z + 4),
+                    DIRT,
+                );
+            }
+        }
+        SparseVoxelOctree::from_world(&world, Vector3i::new(0, 0, 0), 6)
+    }
+
+    #[test]
+    fn test_dag_matches_octree() {
+        let octree = pillars();
+        let dag = SparseVoxelDag::from_octree(&octree);
+        assert_eq!(dag.origin(), octree.origin());
+        assert_eq!(dag.depth(), 6);
+        for z in 0..64 {
+            for y in 0..8 {
+                for x in 0..64 {
+                    let position = Vector3i::new(x, y, z);
+                    assert_eq!(dag.block(position), octree.block(position));
+                    assert_eq!(dag.lod(position, 2), octree.lod(position, 2));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookups_at_i32_extremes() {
+        let mut octree = SparseVoxelOctree::new(Vector3i::new(-5, 0, 0), 5);
+        octree.set_block(Vector3i::zero(), STONE);
+        let dag = SparseVoxelDag::from_octree(&octree);
+        assert_eq!(dag.block(Vector3i::zero()), STONE);
+        assert_eq!(dag.block(Vector3i::new(i32::MAX, 0, 0)), BlockId::AIR);
+        assert_eq!(dag.lod(Vector3i::new(0, 0, i32::MIN), 1), BlockId::AIR);
+    }
+
+    #[test]
+    fn test_compression() {
+        let octree = pillars();
+        let dag = SparseVoxelDag::from_octree(&octree);
+        assert_eq!(dag.octree_node_count(), octree.nodes().len() as u64);
+        assert!(dag.nodes().len() < octree.nodes().len() / 10);
+        assert!(dag.compression_ratio() > 10.0);
+
+        // Nothing to share in a single leaf.
+        let empty = SparseVoxelDag::from_octree(&SparseVoxelOctree::new(Vector3i::zero(), 4));
+        assert_eq!(empty.nodes().len(), 1);
+        assert_eq!(empty.compression_ratio(), 1.0);
+    }
+
+    #[test]
+    fn test_raycast_matches_octree() {
+        let octree = pillars();
+        let dag = SparseVoxelDag::from_octree(&octree);
+        for i in 0..200 {
+            let angle = i as f64 * 0.37;
+            let origin = Vector3d::new(32.0 + 40.0 * angle.cos(), 10.0, 32.0 + 40.0 * angle.sin());
+            let target = Vector3d::new((i * 7 % 64) as f64 + 0.5, 3.0, (i * 13 % 64) as f64 + 0.5);
+            let ray = Ray::new(origin, (target - origin).normalize());
+            assert_eq!(dag.raycast(&ray, 200.0), octree.raycast(&ray, 200.0));
+            assert_eq!(
+                dag.raycast_lod(&ray, 200.0, 1),
+                octree.raycast_lod(&ray, 200.0, 1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let octree = pillars();
+        let dag = SparseVoxelDag::from_octree(&SparseVoxelOctree::from_world(
+            &VoxelWorld::new(),
+            Vector3i::new(-5, 7, 1_000_000),
+            3,
+        ));
+        assert_eq!(SparseVoxelDag::from_bytes(&dag.to_bytes()).unwrap(), dag);
+
+        let dag = SparseVoxelDag::from_octree(&octree);
+        let bytes = dag.to_bytes();
+        assert_eq!(bytes.len(), 36 + dag.nodes().len() * 8);
+        assert_eq!(&bytes[0..4], b"HDAG");
+        assert_eq!(SparseVoxelDag::from_bytes(&bytes).unwrap(), dag);
+        assert_eq!(dag.to_gpu_buffer().len(), dag.nodes().len() * 2);
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        let dag = SparseVoxelDag::from_octree(&pillars());
+        let bytes = dag.to_bytes();
+
+        assert!(SparseVoxelDag::from_bytes(&bytes[..20]).is_err());
+        assert!(SparseVoxelDag::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut wrong_magic = bytes.clone();
+        wrong_magic[0] = b'X';
+        assert!(SparseVoxelDag::from_bytes(&wrong_magic).is_err());
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] = 9;
+        assert!(SparseVoxelDag::from_bytes(&wrong_version).is_err());
+
+        // A node pointing at its own group would loop forever.
+        let mut cycle = bytes.clone();
+        let last = dag.nodes().len() - 1;
+        let interior = (1..=last)
+            .rev()
+            .find(|&i| !dag.nodes()[i].is_leaf())
+            .unwrap();
+        let own_group = (interior - (interior - 1) % 8) as u32;
+        let offset = 36 + interior * 8;
+        cycle[offset..offset + 4].copy_from_slice(&own_group.to_le_bytes());
+        assert!(SparseVoxelDag::from_bytes(&cycle).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+#[cfg(test)]
+mod tests {
+    use hcore::voxel::density::{face_bit, DensityChunk};
+    use hcore::voxel::dual_contouring::dual_contouring;
+    use hcore::voxel::marching_cubes::marching_cubes;
+    use hcore::voxel::surface::{SurfaceMesh, VertexKey};
+    use hmath::vector::{Vector3d, Vector3f, Vector3i};
+    use std::collections::HashMap;
+
+    fn sphere(center: Vector3d, radius: f64) -> impl Fn(Vector3d) -> f32 {
+        move |p| ((p - center).length() - radius) as f32
+    }
+
+    fn cube(center: Vector3d, half: f64) -> impl Fn(Vector3d) -> f32 {
+        move |p| {
+            let d = p - center;
+            (d.x.abs().max(d.y.abs()).max(d.z.abs()) - half) as f32
+        }
+    }
+
+    /// Every edge is used once in each direction: the surface is closed and
+    /// consistently wound.
+    fn assert_closed(mesh: &SurfaceMesh) {
+        assert!(!mesh.is_empty());
+        let mut edges = HashMap::new();
+        for triangle in mesh.indices.chunks_exact(3) {
+            assert!(
+                triangle[0] != triangle[1]
+                    && triangle[1] != triangle[2]
+                    && triangle[2] != triangle[0]
+            );
+            for i in 0..3 {
+                *edges
+                    .entry((triangle[i], triangle[(i + 1) % 3]))
+                    .or_insert(0) += 1;
+            }
+        }
+        for (&(a, b), &count) in &edges {
+            assert_eq!(count, 1, "edge {} {} repeated", a, b);
+            assert_eq!(edges.get(&(b, a)), Some(&1), "edge {} {} is open", a, b);
+        }
+    }
+
+    /// Triangles face away from `center` and agree with the vertex normals.
+    fn assert_outward(mesh: &SurfaceMesh, center: Vector3f) {
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.positions[triangle[i] as usize]);
+            let normal = (b - a).cross(&(c - a));
+            assert!(normal.dot(&(a - center)) > 0.0);
+            for &index in triangle {
+                assert!(mesh.normals[index as usize].dot(&normal) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_marching_cubes_sphere() {
+        let center = Vector3d::new(8.3, 7.6, 8.1);
+        let chunk = DensityChunk::sample(Vector3i::zero(), 16, 0, 0, sphere(center, 5.0));
+        let mesh = marching_cubes(&chunk);
+
+        assert_closed(&mesh);
+        assert_outward(&mesh, center.cast());
+        for (position, normal) in mesh.positions.iter().zip(&mesh.normals) {
+            let offset = position.cast::<f64>() - center;
+            assert!((offset.length() - 5.0).abs() < 0.1);
+            assert!((normal.cast::<f64>() - offset.normalize()).length() < 0.05);
+        }
+        assert_eq!(mesh.keys.len(), mesh.positions.len());
+        assert_eq!(marching_cubes(&chunk), mesh);
+
+        let empty = DensityChunk::sample(Vector3i::zero(), 4, 0, 0, |_| 1.0);
+        assert!(marching_cubes(&empty).is_empty());
+        let solid = DensityChunk::sample(Vector3i::zero(), 4, 0, 0, |_| -1.0);
+        assert!(marching_cubes(&solid).is_empty());
+    }
+
+    #[test]
+    fn test_coarsest_level_of_detail() {
+        let chunk = DensityChunk::sample(Vector3i::zero(), 1, 30, 0, |p| p.y as f32 - 1e9);
+        assert_eq!(chunk.step(), 1 << 30);
+        let mesh = marching_cubes(&chunk);
+        assert!(!mesh.is_empty());
+        assert!(mesh.keys.iter().all(|key| key.lod == 30));
+    }
+
+    #[test]
+    #[should_panic(expected = "too coarse")]
+    fn test_rejects_levels_past_30() {
+        DensityChunk::sample(Vector3i::zero(), 1, 31, 0, |_| 1.0);
+    }
+
+    #[test]
+    fn test_chunks_weld_into_one_surface() {
+        let center = Vector3d::new(0.4, -0.3, 0.2);
+        let density = sphere(center, 6.0);
+        let whole = marching_cubes(&DensityChunk::sample(
+            Vector3i::new(-8, -8, -8),
+            16,
+            0,
+            0,
+            &density,
+        ));
+
+        let mut chunks = Vec::new();
+        for corner in 0..8 {
+            let origin = Vector3i::new(corner & 1, corner >> 1 & 1, corner >> 2 & 1) * 8
+                - Vector3i::new(8, 8, 8);
+            chunks.push((
+                origin,
+                marching_cubes(&DensityChunk::sample(origin, 8, 0, 0, &density)),
+            ));
+        }
+        let stitched = SurfaceMesh::stitch(
+            Vector3i::new(-8, -8, -8),
+            chunks.iter().map(|(o, m)| (*o, m)),
+        );
+
+        assert_closed(&stitched);
+        assert_eq!(stitched.positions.len(), whole.positions.len());
+        assert_eq!(stitched.triangle_count(), whole.triangle_count());
+        for (key, position) in stitched.keys.iter().zip(&stitched.positions) {
+            let index = whole.keys.iter().position(|k| k == key).unwrap();
+            assert!((whole.positions[index] - *position).length() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_dual_contouring_keeps_sharp_corners() {
+        let center = Vector3d::new(8.0, 8.0, 8.0);
+        let density = cube(center, 4.5);
+        let mut chunks = Vec::new();
+        for corner in 0..8 {
+            let origin = Vector3i::new(corner & 1, corner >> 1 & 1, corner >> 2 & 1) * 8;
+            chunks.push((
+                origin,
+                dual_contouring(&DensityChunk::sample(origin, 8, 0, 0, &density)),
+            ));
+        }
+        let mesh = SurfaceMesh::stitch(Vector3i::zero(), chunks.iter().map(|(o, m)| (*o, m)));
+
+        assert_closed(&mesh);
+        assert!(mesh.keys.iter().all(|key| key.kind == VertexKey::CELL));
+        for corner in 0..8 {
+            let sign = |bit: i32| if corner >> bit & 1 == 1 { 4.5 } else { -4.5 };
+            let expected = (center + Vector3d::new(sign(0), sign(1), sign(2))).cast::<f32>();
+            assert!(
+                mesh.positions
+                    .iter()
+                    .any(|p| (*p - expected).length() < 0.02),
+                "corner {:?}",
+                expected
+            );
+        }
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.positions[triangle[i] as usize]);
+            assert!((b - a).cross(&(c - a)).dot(&(a - center.cast())) > 0.0);
+        }
+
+        // A smooth shape comes out closed and on the surface too.
+        let round = sphere(Vector3d::new(4.2, 3.9, 4.1), 2.5);
+        let mesh = dual_contouring(&DensityChunk::sample(Vector3i::zero(), 8, 0, 0, round));
+        assert_closed(&mesh);
+        for position in &mesh.positions {
+            assert!(
+                ((position.cast::<f64>() - Vector3d::new(4.2, 3.9, 4.1)).length() - 2.5).abs()
+                    < 0.2
+            );
+        }
+    }
+
+    #[test]
+    fn test_transition_cells_close_lod_seams() {
+        // A coarse chunk over [0, 16) next to four fine chunks over
+        // x in [16, 24), with a sphere straddling the seam.
+        let center = Vector3d::new(16.3, 8.2, 7.7);
+        let density = sphere(center, 5.0);
+        let fine: Vec<(Vector3i, SurfaceMesh)> = [(0, 0), (8, 0), (0, 8), (8, 8)]
+            .iter()
+            .map(|&(y, z)| {
+                let origin = Vector3i::new(16, y, z);
+                (
+                    origin,
+                    marching_cubes(&DensityChunk::sample(origin, 8, 0, 0, &density)),
+                )
+            })
+            .collect();
+
+        let seam = DensityChunk::sample(Vector3i::zero(), 8, 1, 0, &density);
+        let open = SurfaceMesh::stitch(
+            Vector3i::zero(),
+            fine.iter()
+                .map(|(o, m)| (*o, m))
+                .chain([(Vector3i::zero(), &marching_cubes(&seam))]),
+        );
+        let open_edges = open
+            .indices
+            .chunks_exact(3)
+            .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+            .filter(|&(a, b)| {
+                !open
+                    .indices
+                    .chunks_exact(3)
+                    .any(|t| (0..3).any(|i| (t[i], t[(i + 1) % 3]) == (b, a)))
+            })
+            .count();
+        assert!(open_edges > 0);
+
+        let coarse = DensityChunk::sample(Vector3i::zero(), 8, 1, face_bit(0, true), &density);
+        let coarse_mesh = marching_cubes(&coarse);
+        let mesh = SurfaceMesh::stitch(
+            Vector3i::zero(),
+            fine.iter()
+                .map(|(o, m)| (*o, m))
+                .chain([(Vector3i::zero(), &coarse_mesh)]),
+        );
+        assert_closed(&mesh);
+        assert_outward(&mesh, center.cast());
+
+        // The squeezed coarse cells stay clear of the seam.
+        for (key, position) in coarse_mesh.keys.iter().zip(&coarse_mesh.positions) {
+            if key.lod == 1 {
+                assert!(position.x <= 15.0 + 1e-4);
+            } else {
+                assert_eq!(position.x, 16.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,330 @@
+#[cfg(test)]
+mod tests {
+    use hcore::voxel::block::BlockId;
+    use hcore::voxel::chunk::Chunk;
+    use hcore::voxel::svo::{SparseVoxelOctree, SvoNode};
+    use hcore::voxel::world::VoxelWorld;
+    use hmath::geometry::ray::Ray;
+    use hmath::geometry::voxel_traversal::VoxelTraversal;
+    use hmath::vector::{Vector3d, Vector3i};
+
+    const STONE: BlockId = BlockId(1);
+    const DIRT: BlockId = BlockId(2);
+
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn range(&mut self, max: i32) -> i32 {
+            (self.next() % max as u64) as i32
+        }
+
+        fn unit(&mut self) -> f64 {
+            (self.next() >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    /// Hills of stone under a dirt layer with scattered holes.
+    fn terrain(world: &mut VoxelWorld, origin: Vector3i, size: i32) {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for z in 0..size {
+            for x in 0..size {
+                let height =
+                    8 + ((x as f64 * 0.3).sin() * 4.0 + (z as f64 * 0.2).cos() * 5.0) as i32;
+                for y in 0..height {
+                    let block = if y + 2 >= height { DIRT } else { STONE };
+                    world.set_block(origin + Vector3i::new(x, y, z), block);
+                }
+            }
+        }
+        for _ in 0..200 {
+            let hole = Vector3i::new(rng.range(size), rng.range(12), rng.range(size));
+            world.set_block(origin + hole, BlockId::AIR);
+        }
+    }
+
+    fn assert_matches_world(tree: &SparseVoxelOctree, world: &VoxelWorld) {
+        let size = tree.size();
+        for z in 0..size {
+            for y in 0..size {
+                for x in 0..size {
+                    let position = tree.origin() + Vector3i::new(x, y, z);
+                    assert_eq!(
+                        tree.block(position),
+                        world.block(position),
+                        "{:?}",
+                        position
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_and_uniform_trees() {
+        let tree = SparseVoxelOctree::from_chunk(&Chunk::default(), Vector3i::zero());
+        assert_eq!(tree.nodes(), [SvoNode::leaf(BlockId::AIR)]);
+
+        let tree = SparseVoxelOctree::from_chunk(&Chunk::new(STONE), Vector3i::zero());
+        assert_eq!(tree.nodes(), [SvoNode::leaf(STONE)]);
+        assert_eq!(tree.block(Vector3i::new(31, 0, 5)), STONE);
+        assert_eq!(tree.block(Vector3i::new(32, 0, 5)), BlockId::AIR);
+        assert_eq!(tree.block(Vector3i::new(-1, 0, 5)), BlockId::AIR);
+    }
+
+    #[test]
+    fn test_positions_at_i32_extremes() {
+        let mut tree = SparseVoxelOctree::new(Vector3i::new(1, 0, 0), 5);
+        tree.set_block(Vector3i::new(1, 0, 0), STONE);
+        assert_eq!(tree.block(Vector3i::new(i32::MIN, 0, 0)), BlockId::AIR);
+        assert_eq!(tree.lod(Vector3i::new(0, i32::MIN, 0), 2), BlockId::AIR);
+
+        let mut tree = SparseVoxelOctree::new(Vector3i::new(-5, 0, 0), 5);
+        assert_eq!(tree.set_block(Vector3i::new(i32::MAX, 0, 0), STONE), None);
+        assert_eq!(tree.set_block(Vector3i::new(0, 0, i32::MIN), STONE), None);
+        assert_eq!(tree.nodes(), [SvoNode::leaf(BlockId::AIR)]);
+
+        // A tree in the far corner still reaches its last block.
+        let mut tree = SparseVoxelOctree::new(Vector3i::new(i32::MAX - 31, i32::MIN, 0), 5);
+        let corner = Vector3i::new(i32::MAX, i32::MIN, 0);
+        assert_eq!(tree.set_block(corner, DIRT), Some(BlockId::AIR));
+        assert_eq!(tree.block(corner), DIRT);
+        assert_eq!(
+            tree.block(Vector3i::new(i32::MIN, i32::MIN, 0)),
+            BlockId::AIR
+        );
+    }
+
+    #[test]
+    fn test_build_from_chunk() {
+        let mut chunk = Chunk::default();
+        let mut rng = Rng(12345);
+        for _ in 0..500 {
+            let (x, y, z) = (rng.range(32), rng.range(32), rng.range(32));
+            chunk.set(
+                x as usize,
+                y as usize,
+                z as usize,
+                BlockId(1 + rng.range(3) as u16),
+            );
+        }
+        let origin = Vector3i::new(64, -32, 0);
+        let tree = SparseVoxelOctree::from_chunk(&chunk, origin);
+        assert_eq!(tree.depth(), 5);
+        for (index, block) in chunk.blocks().enumerate() {
+            let (x, y, z) = Chunk::position(index);
+            let position = origin + Vector3i::new(x as i32, y as i32, z as i32);
+            assert_eq!(tree.block(position), block);
+        }
+    }
+
+    #[test]
+    fn test_build_from_world() {
+        let mut world = VoxelWorld::new();
+        let origin = Vector3i::new(-32, -32, -32);
+        terrain(&mut world, origin + Vector3i::new(0, 32, 0), 64);
+        world.fill(origin, origin + Vector3i::new(64, 32, 64), STONE);
+
+        let tree = SparseVoxelOctree::from_world(&world, origin, 6);
+        assert_matches_world(&tree, &world);
+        // The solid lower half is the four root children with a clear y bit.
+        for child in [0, 1, 4, 5] {
+            assert_eq!(tree.nodes()[1 + child], SvoNode::leaf(STONE));
+        }
+
+        // Unaligned origins still build correctly.
+        let shifted = SparseVoxelOctree::from_world(&world, origin + Vector3i::new(5, 7, 3), 5);
+        assert_matches_world(&shifted, &world);
+    }
+
+    #[test]
+    fn test_build_deep_sparse_world() {
+        let mut world = VoxelWorld::new();
+        world.fill(
+            Vector3i::new(64, 0, -128),
+            Vector3i::new(128, 64, -64),
+            STONE,
+        );
+        world.set_block(Vector3i::new(-1000, 5, 77), DIRT);
+        // Chunks written back to air are stored but empty.
+        world.set_block(Vector3i::new(4000, -300, 20), DIRT);
+        world.set_block(Vector3i::new(4000, -300, 20), BlockId::AIR);
+
+        // A million blocks a side; only the paths to stored chunks split.
+        let origin = Vector3i::new(-1 << 19, -1 << 19, -1 << 19);
+        let tree = SparseVoxelOctree::from_world(&world, origin, 20);
+        assert!(tree.nodes().len() < 1000, "{} nodes", tree.nodes().len());
+        assert_eq!(tree.block(Vector3i::new(-1000, 5, 77)), DIRT);
+        assert_eq!(tree.block(Vector3i::new(-1000, 5, 78)), BlockId::AIR);
+        assert_eq!(tree.block(Vector3i::new(100, 30, -100)), STONE);
+        assert_eq!(tree.block(Vector3i::new(100, 64, -100)), BlockId::AIR);
+        assert_eq!(tree.block(Vector3i::new(4000, -300, 20)), BlockId::AIR);
+
+        let shifted = SparseVoxelOctree::from_world(&world, origin + Vector3i::new(3, 9, 5), 20);
+        assert!(
+            shifted.nodes().len() < 100_000,
+            "{} nodes",
+            shifted.nodes().len()
+        );
+        assert_eq!(shifted.block(Vector3i::new(-1000, 5, 77)), DIRT);
+        assert_eq!(shifted.block(Vector3i::new(100, 30, -100)), STONE);
+        assert_eq!(shifted.block(Vector3i::new(63, 30, -100)), BlockId::AIR);
+    }
+
+    #[test]
+    fn test_incremental_update_matches_rebuild() {
+        let mut world = VoxelWorld::new();
+        terrain(&mut world, Vector3i::zero(), 32);
+        let mut tree = SparseVoxelOctree::from_world(&world, Vector3i::zero(), 5);
+
+        let mut rng = Rng(777);
+        for step in 0..2000 {
+            let position = Vector3i::new(rng.range(32), rng.range(16), rng.range(32));
+            let block = BlockId(rng.range(3) as u16);
+            assert_eq!(tree.set_block(position, block), Some(world.block(position)));
+            world.set_block(position, block);
+
+            if step % 500 == 0 {
+                assert_matches_world(&tree, &world);
+            }
+        }
+        assert_matches_world(&tree, &world);
+
+        tree.compact();
+        let rebuilt = SparseVoxelOctree::from_world(&world, Vector3i::zero(), 5);
+        assert_eq!(tree.nodes(), rebuilt.nodes());
+
+        assert_eq!(tree.set_block(Vector3i::new(32, 0, 0), STONE), None);
+    }
+
+    #[test]
+    fn test_update_merges_back_to_a_leaf() {
+        let mut tree = SparseVoxelOctree::new(Vector3i::zero(), 3);
+        tree.set_block(Vector3i::new(3, 4, 5), STONE);
+        assert!(tree.nodes().len() > 1);
+        assert_eq!(
+            tree.set_block(Vector3i::new(3, 4, 5), BlockId::AIR),
+            Some(STONE)
+        );
+        assert_eq!(tree.nodes()[0], SvoNode::leaf(BlockId::AIR));
+
+        // Released groups are reused.
+        let len = tree.nodes().len();
+        tree.set_block(Vector3i::new(7, 0, 1), DIRT);
+        assert_eq!(tree.nodes().len(), len);
+    }
+
+    #[test]
+    fn test_lod_majority_material() {
+        let mut tree = SparseVoxelOctree::new(Vector3i::zero(), 2);
+        for (i, position) in [(0, 0, 0), (1, 0, 0), (0, 1, 0), (1, 1, 0), (0, 0, 1)]
+            .iter()
+            .enumerate()
+        {
+            let block = if i < 2 { STONE } else { DIRT };
+            tree.set_block(Vector3i::new(position.0, position.1, position.2), block);
+        }
+
+        assert_eq!(tree.lod(Vector3i::new(0, 0, 0), 0), STONE);
+        assert_eq!(tree.lod(Vector3i::new(1, 1, 1), 0), BlockId::AIR);
+        assert_eq!(tree.lod(Vector3i::new(1, 1, 1), 1), DIRT);
+        assert_eq!(tree.lod(Vector3i::new(3, 3, 3), 1), BlockId::AIR);
+        assert_eq!(tree.lod(Vector3i::new(3, 3, 3), 2), DIRT);
+    }
+
+    #[test]
+    fn test_raycast_matches_grid_traversal() {
+        let mut world = VoxelWorld::new();
+        terrain(&mut world, Vector3i::zero(), 64);
+        let tree = SparseVoxelOctree::from_world(&world, Vector3i::zero(), 6);
+
+        let mut rng = Rng(4242);
+        let mut hits = 0;
+        for _ in 0..300 {
+            let origin = Vector3d::new(
+                rng.unit() * 64.0,
+                20.0 + rng.unit() * 20.0,
+                rng.unit() * 64.0,
+            );
+            let direction =
+                Vector3d::new(rng.unit() - 0.5, -rng.unit(), rng.unit() - 0.5).normalize();
+            let ray = Ray::new(origin, direction);
+
+            let expected = VoxelTraversal::new(&ray, 1.0, 200.0)
+                .take_while(|hit| {
+                    let cell = hit.cell;
+                    (0..64).contains(&cell.x) && (0..64).contains(&cell.z) && cell.y >= 0
+                })
+                .find(|hit| !world.block(hit.cell).is_air());
+            let actual = tree.raycast(&ray, 200.0);
+
+            match (expected, actual) {
+                (None, None) => {}
+                (Some(expected), Some((hit, block))) => {
+                    hits += 1;
+                    assert_eq!(hit.cell, expected.cell);
+                    assert_eq!(hit.normal, expected.normal);
+                    assert!((hit.distance - expected.distance).abs() < 1e-9);
+                    assert_eq!(block, world.block(hit.cell));
+                }
+                other => panic!("mismatch {:?}", other),
+            }
+        }
+        assert!(hits > 100);
+    }
+
+    #[test]
+    fn test_raycast_early_out_and_range() {
+        let mut tree = SparseVoxelOctree::new(Vector3i::zero(), 4);
+        tree.set_block(Vector3i::new(10, 2, 2), STONE);
+        tree.set_block(Vector3i::new(5, 2, 2), DIRT);
+
+        let ray = Ray::new(Vector3d::new(-3.0, 2.5, 2.5), Vector3d::new(1.0, 0.0, 0.0));
+        let (hit, block) = tree.raycast(&ray, 100.0).unwrap();
+        assert_eq!(block, DIRT);
+        assert_eq!(hit.cell, Vector3i::new(5, 2, 2));
+        assert_eq!(hit.normal, Vector3i::new(-1, 0, 0));
+        assert_eq!(hit.distance, 8.0);
+
+        assert!(tree.raycast(&ray, 7.5).is_none());
+        let backwards = Ray::new(ray.origin, -ray.direction);
+        assert!(tree.raycast(&backwards, 100.0).is_none());
+
+        // Starting inside a solid block hits it immediately.
+        let inside = Ray::new(Vector3d::new(5.5, 2.5, 2.5), Vector3d::new(0.0, 1.0, 0.0));
+        let (hit, _) = tree.raycast(&inside, 100.0).unwrap();
+        assert_eq!((hit.distance, hit.normal), (0.0, Vector3i::zero()));
+
+        // At a coarse level the ray stops at the 4³ cube holding the dirt.
+        let (hit, block) = tree.raycast_lod(&ray, 100.0, 2).unwrap();
+        assert_eq!(block, DIRT);
+        assert_eq!(hit.distance, 7.0);
+        assert_eq!(hit.cell, Vector3i::new(4, 2, 2));
+
+        // Bad ray data misses instead of panicking.
+        for (origin, direction) in [
+            (Vector3d::new(f64::NAN, 2.5, 2.5), ray.direction),
+            (ray.origin, Vector3d::new(f64::INFINITY, 0.0, 0.0)),
+        ] {
+            assert!(tree.raycast(&Ray::new(origin, direction), 100.0).is_none());
+        }
+        assert!(tree.raycast(&ray, f64::NAN).is_none());
+    }
+
+    #[test]
+    fn test_gpu_buffer_layout() {
+        let mut tree = SparseVoxelOctree::new(Vector3i::zero(), 1);
+        tree.set_block(Vector3i::new(1, 0, 0), BlockId(7));
+        let buffer = tree.to_gpu_buffer();
+        assert_eq!(buffer.len(), tree.nodes().len() * 2);
+        assert_eq!(buffer[0], 1);
+        assert_eq!(buffer[1], 7 | 0b10 << 16);
+        assert_eq!(&buffer[4..6], [0, 7]);
+    }
+}
@@ -0,0 +1,228 @@
+#[cfg(test)]
+mod tests {
+    use hcore::voxel::block::BlockId;
+    use hcore::voxel::chunk::{Chunk, CHUNK_SIZE, CHUNK_VOLUME};
+    use hcore::voxel::palette::{PackedArray, PalettedStorage};
+    use hcore::voxel::world::VoxelWorld;
+    use hmath::vector::Vector3i;
+
+    const STONE: BlockId = BlockId(1);
+    const DIRT: BlockId = BlockId(2);
+
+    fn sorted_dirty(world: &VoxelWorld) -> Vec<(i32, i32, i32)> {
+        let mut dirty: Vec<_> = world.dirty_chunks().map(|c| (c.x, c.y, c.z)).collect();
+        dirty.sort();
+        dirty
+    }
+
+    #[test]
+    fn test_packed_array_round_trip() {
+        for bits in [1, 2, 4, 8, 16, 32] {
+            let mut array = PackedArray::new(100, bits);
+            let max = if bits == 32 {
+                u32::MAX
+            } else {
+                (1 << bits) - 1
+            };
+            for i in 0..100 {
+                array.set(i, (i as u32 * 7919) & max);
+            }
+            for i in 0..100 {
+                assert_eq!(array.get(i), (i as u32 * 7919) & max);
+            }
+
+            if bits < 32 {
+                let wider = array.with_bits(bits * 2);
+                assert!(wider.iter().eq(array.iter()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_palette_grows_and_compacts() {
+        let mut storage = PalettedStorage::new(64, BlockId::AIR);
+        assert_eq!(storage.bits_per_index(), 1);
+
+        for i in 0..5 {
+            storage.set(i, BlockId(i as u16 + 1));
+        }
+        assert_eq!(storage.bits_per_index(), 4);
+        assert_eq!(storage.palette().count(), 6);
+
+        for i in 0..4 {
+            assert_eq!(storage.set(i, BlockId::AIR), BlockId(i as u16 + 1));
+        }
+        assert_eq!(
+            storage.palette().collect::<Vec<_>>(),
+            [BlockId::AIR, BlockId(5)]
+        );
+
+        // Freed slots are reused before the palette grows.
+        storage.set(10, BlockId(9));
+        assert_eq!(storage.bits_per_index(), 4);
+
+        let before: Vec<_> = storage.iter().collect();
+        storage.compact();
+        assert_eq!(storage.bits_per_index(), 2);
+        assert_eq!(storage.iter().collect::<Vec<_>>(), before);
+    }
+
+    #[test]
+    fn test_chunk_index_round_trip() {
+        for index in [0, 1, 31, 32, 1023, 1024, CHUNK_VOLUME - 1] {
+            let (x, y, z) = Chunk::position(index);
+            assert_eq!(Chunk::index(x, y, z), index);
+        }
+        assert_eq!(Chunk::index(1, 2, 3), 1 + 2 * 32 + 3 * 1024);
+    }
+
+    #[test]
+    fn test_chunk_uniform_fast_path() {
+        let mut chunk = Chunk::default();
+        assert!(chunk.is_empty());
+        assert_eq!(chunk.bits_per_block(), 0);
+
+        assert_eq!(chunk.set(1, 2, 3, STONE), BlockId::AIR);
+        assert_eq!(chunk.uniform_block(), None);
+        assert_eq!(chunk.bits_per_block(), 1);
+        assert_eq!(chunk.get(1, 2, 3), STONE);
+        assert_eq!(chunk.get(3, 2, 1), BlockId::AIR);
+
+        // Clearing the only solid block collapses back to the uniform form.
+        assert_eq!(chunk.set(1, 2, 3, BlockId::AIR), STONE);
+        assert!(chunk.is_empty());
+
+        chunk.fill(DIRT);
+        assert_eq!(chunk.uniform_block(), Some(DIRT));
+        assert!(chunk.blocks().all(|block| block == DIRT));
+    }
+
+    #[test]
+    fn test_chunk_many_block_types() {
+        let mut chunk = Chunk::default();
+        for index in 0..CHUNK_VOLUME {
+            chunk.set_index(index, BlockId((index % 300) as u16));
+        }
+        assert_eq!(chunk.bits_per_block(), 16);
+        assert_eq!(chunk.palette().len(), 300);
+        for (index, block) in chunk.blocks().enumerate() {
+            assert_eq!(block, BlockId((index % 300) as u16));
+        }
+    }
+
+    #[test]
+    fn test_world_coordinates() {
+        let size = CHUNK_SIZE as i32;
+        let position = Vector3i::new(-1, size, 2 * size + 5);
+        assert_eq!(
+            VoxelWorld::chunk_position(position),
+            Vector3i::new(-1, 1, 2)
+        );
+        assert_eq!(VoxelWorld::local_position(position), (CHUNK_SIZE - 1, 0, 5));
+        assert_eq!(
+            VoxelWorld::chunk_origin(Vector3i::new(-1, 1, 2)),
+            Vector3i::new(-size, size, 2 * size)
+        );
+    }
+
+    #[test]
+    fn test_world_get_set() {
+        let mut world = VoxelWorld::new();
+        let position = Vector3i::new(-40, 7, 1_000_000);
+        assert_eq!(world.block(position), BlockId::AIR);
+        assert_eq!(world.set_block(position, STONE), BlockId::AIR);
+        assert_eq!(world.block(position), STONE);
+        assert_eq!(world.block(position + Vector3i::new(1, 0, 0)), BlockId::AIR);
+        assert_eq!(world.chunk_count(), 1);
+
+        // Setting air where there is no chunk does not create one.
+        world.set_block(Vector3i::new(500, 500, 500), BlockId::AIR);
+        assert_eq!(world.chunk_count(), 1);
+
+        // Removing the last solid block drops the chunk but leaves it dirty.
+        world.drain_dirty().for_each(drop);
+        assert_eq!(world.set_block(position, BlockId::AIR), STONE);
+        assert_eq!(world.chunk_count(), 0);
+        assert!(world.is_dirty(VoxelWorld::chunk_position(position)));
+    }
+
+    #[test]
+    fn test_world_dirty_neighbours() {
+        let mut world = VoxelWorld::new();
+        world.set_block(Vector3i::new(0, 0, 0), STONE);
+        world.set_block(Vector3i::new(-1, 0, 0), STONE);
+        world.set_block(Vector3i::new(0, 40, 0), STONE);
+        world.drain_dirty().for_each(drop);
+        assert_eq!(sorted_dirty(&world), []);
+
+        // An interior block only affects its own chunk.
+        world.set_block(Vector3i::new(5, 5, 5), DIRT);
+        assert_eq!(sorted_dirty(&world), [(0, 0, 0)]);
+        world.drain_dirty().for_each(drop);
+
+        // A border block also dirties the existing chunk across that face.
+        world.set_block(Vector3i::new(0, 31, 3), DIRT);
+        assert_eq!(sorted_dirty(&world), [(-1, 0, 0), (0, 0, 0), (0, 1, 0)]);
+        world.drain_dirty().for_each(drop);
+
+        // Unchanged blocks are not edits.
+        world.set_block(Vector3i::new(0, 31, 3), DIRT);
+        assert_eq!(sorted_dirty(&world), []);
+    }
+
+    #[test]
+    fn test_world_fill() {
+        let mut world = VoxelWorld::new();
+        let size = CHUNK_SIZE as i32;
+        world.fill(
+            Vector3i::new(-size, 0, 0),
+            Vector3i::new(size + 3, size, size),
+            STONE,
+        );
+        assert_eq!(world.chunk_count(), 3);
+        assert_eq!(
+            world
+                .chunk(Vector3i::new(-1, 0, 0))
+                .unwrap()
+                .uniform_block(),
+            Some(STONE)
+        );
+        assert_eq!(
+            world.chunk(Vector3i::new(0, 0, 0)).unwrap().uniform_block(),
+            Some(STONE)
+        );
+        assert_eq!(
+            world.chunk(Vector3i::new(1, 0, 0)).unwrap().uniform_block(),
+            None
+        );
+        assert_eq!(world.block(Vector3i::new(size + 2, 5, 5)), STONE);
+        assert_eq!(world.block(Vector3i::new(size + 3, 5, 5)), BlockId::AIR);
+        assert_eq!(sorted_dirty(&world), [(-1, 0, 0), (0, 0, 0), (1, 0, 0)]);
+
+        world.fill(
+            Vector3i::new(-size, 0, 0),
+            Vector3i::new(size, size, size),
+            BlockId::AIR,
+        );
+        assert_eq!(world.chunk_count(), 1);
+        assert_eq!(world.block(Vector3i::new(0, 0, 0)), BlockId::AIR);
+    }
+
+    #[test]
+    fn test_world_insert_and_remove_chunks() {
+        let mut world = VoxelWorld::new();
+        let position = Vector3i::new(3, -2, 1);
+        assert!(world.insert_chunk(position, Chunk::new(DIRT)).is_none());
+        assert_eq!(world.block(VoxelWorld::chunk_origin(position)), DIRT);
+
+        assert!(world.insert_chunk(position, Chunk::default()).is_some());
+        assert_eq!(world.chunk_count(), 0);
+        assert!(world.remove_chunk(position).is_none());
+
+        world.insert_chunk(position, Chunk::new(DIRT));
+        world.drain_dirty().for_each(drop);
+        world.chunk_mut(position).unwrap().set(0, 0, 0, STONE);
+        assert!(world.is_dirty(position));
+        assert_eq!(world.block(VoxelWorld::chunk_origin(position)), STONE);
+    }
+}
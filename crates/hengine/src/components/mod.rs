@@ -1,2 +1,3 @@
 pub mod camera_component;
-pub mod transform_component;
+pub mod path_follower_component;
+pub mod transform_component;
\ No newline at end of file
@@ -0,0 +1,18 @@
+use hmath::curve::arc_length::ArcLengthCurve;
+use hmath::curve::catmull_rom::CatmullRom;
+use hmath::curve::squad::QuaternionSpline;
+
+/// Moves an entity's transform along a spline at constant speed, e.g. for
+/// camera fly-throughs and moving platforms.
+pub struct PathFollowerComponent {
+    pub path: ArcLengthCurve<CatmullRom<f64>, f64>,
+    /// Explicit rotation keys spread evenly over the path. When absent the
+    /// rotation is left alone unless `orient_to_path` is set.
+    pub rotations: Option<QuaternionSpline<f64>>,
+    /// Units per second; negative values travel backwards.
+    pub speed: f64,
+    pub distance: f64,
+    pub looping: bool,
+    /// Faces +Z along the direction of travel, keeping +Y up.
+    pub orient_to_path: bool,
+}
@@ -1,8 +1,22 @@
 use hmath::quaternion::Quaternion;
+use hmath::transform::Transform;
 use hmath::vector::{Vector3d, Vector3f};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransformComponent {
     pub position: Vector3d,
     pub rotation: Quaternion<f64>,
     pub scale: Vector3f,
 }
+
+impl TransformComponent {
+    pub fn to_transform(&self) -> Transform<f64> {
+        Transform::new(self.position, self.rotation, self.scale.into())
+    }
+
+    pub fn set_transform(&mut self, transform: &Transform<f64>) {
+        self.position = transform.translation;
+        self.rotation = transform.rotation;
+        self.scale = transform.scale.cast();
+    }
+}
@@ -3,6 +3,8 @@ use crate::components::transform_component::TransformComponent;
 use crate::input_manager::InputManager;
 use crate::renderer::camera_utils;
 use crate::systems::camera_controller_system::{CameraControllerConfig, CameraControllerSystem};
+use crate::systems::floating_origin_system::{FloatingOriginConfig, FloatingOriginSystem};
+use crate::systems::path_follower_system::PathFollowerSystem;
 use anyhow::Result;
 use hmath::vector::{Vector3d, Vector3f};
 use std::sync::Arc;
@@ -17,6 +19,8 @@ pub struct Engine {
     world: hecs::World,
     input_manager: InputManager,
     camera_controller: CameraControllerSystem,
+    path_follower: PathFollowerSystem,
+    floating_origin: FloatingOriginSystem,
     last_update: Instant,
 }
 
@@ -33,6 +37,8 @@ impl Engine {
             world: hecs::World::new(),
             input_manager,
             camera_controller,
+            path_follower: PathFollowerSystem::new(),
+            floating_origin: FloatingOriginSystem::new(FloatingOriginConfig::default()),
             last_update: Instant::now(),
         })
     }
@@ -67,6 +73,10 @@ impl Engine {
         self.last_update = now;
         
         self.camera_controller.update(&mut self.world, &self.input_manager, delta_time);
+        self.path_follower.update(&mut self.world, delta_time, self.floating_origin.origin());
+        if let Some(shift) = self.floating_origin.update(&mut self.world) {
+            self.renderer.shift_origin(shift);
+        }
         
         self.input_manager.update();
     }
@@ -90,16 +100,9 @@ impl Engine {
                     camera.far,
                 );
                 
-                let forward = transform.rotation.rotate_vector(&Vector3d::new(0.0, 0.0, 1.0));
-                let up = transform.rotation.rotate_vector(&Vector3d::new(0.0, 1.0, 0.0));
-                let target = transform.position + forward;
-                
-                let view = camera_utils::build_view_matrix(
-                    transform.position,
-                    target,
-                    up,
-                );
+                let view = camera_utils::build_camera_relative_view_matrix(&transform.rotation);
 
+                self.renderer.set_camera_position(transform.position);
                 self.renderer.set_camera_matrices(&view, &projection);
             });
             
@@ -110,6 +113,22 @@ impl Engine {
         &mut self.renderer
     }
     
+    /// The absolute world position that `TransformComponent` positions are
+    /// relative to. It only moves when rebasing is enabled.
+    pub fn world_origin(&self) -> Vector3d {
+        self.floating_origin.origin()
+    }
+
+    /// Shifts the world back towards the origin whenever the camera gets
+    /// further than `distance` from it; `None` turns rebasing off.
+    pub fn set_rebase_distance(&mut self, distance: Option<f64>) {
+        self.floating_origin.set_rebase_distance(distance);
+    }
+
+    pub fn world(&mut self) -> &mut hecs::World {
+        &mut self.world
+    }
+
     pub fn input_manager(&mut self) -> &mut InputManager {
         &mut self.input_manager
     }
@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
+use hmath::vector::Vector2f;
+
 use winit::event::{DeviceEvent, ElementState, KeyEvent, MouseButton, WindowEvent};
 use winit::keyboard::{KeyCode, PhysicalKey};
 
@@ -10,7 +12,7 @@ pub struct InputAction(pub String);
 pub enum InputValue {
     Button(bool),
     Scalar(f32),
-    Vector2(f32, f32),
+    Vector2(Vector2f),
 }
 
 impl Default for InputValue {
@@ -48,8 +50,8 @@ pub struct InputManager {
     action_values: HashMap<InputAction, InputValue>,
     key_bindings: HashMap<KeyCode, InputAction>,
     mouse_bindings: HashMap<MouseButton, InputAction>,
-    mouse_position: (f32, f32),
-    mouse_delta: (f32, f32),
+    mouse_position: Vector2f,
+    mouse_delta: Vector2f,
     
     action_callbacks: HashMap<InputAction, Vec<Box<dyn Fn(InputValue) + Send + Sync>>>,
 }
@@ -61,8 +63,8 @@ impl InputManager {
             action_values: HashMap::new(),
             key_bindings: HashMap::new(),
             mouse_bindings: HashMap::new(),
-            mouse_position: (0.0, 0.0),
-            mouse_delta: (0.0, 0.0),
+            mouse_position: Vector2f::zero(),
+            mouse_delta: Vector2f::zero(),
             action_callbacks: HashMap::new(),
         }
     }
@@ -84,7 +86,7 @@ impl InputManager {
     }
 
     pub fn bind_mouse_motion(&mut self, action: InputAction) {
-        self.action_values.insert(action, InputValue::Vector2(0.0, 0.0));
+        self.action_values.insert(action, InputValue::Vector2(Vector2f::zero()));
     }
 
     pub fn register_action_callback<F>(&mut self, action: InputAction, callback: F)
@@ -114,7 +116,7 @@ impl InputManager {
                 }
             }
             WindowEvent::CursorMoved { position, .. } => {
-                let new_pos = (position.x as f32, position.y as f32);
+                let new_pos = Vector2f::new(position.x as f32, position.y as f32);
                 self.mouse_position = new_pos;
             }
             _ => {}
@@ -124,11 +126,11 @@ impl InputManager {
     pub fn process_device_event(&mut self, event: &DeviceEvent) {
         match event {
             DeviceEvent::MouseMotion { delta } => {
-                self.mouse_delta = (delta.0 as f32, delta.1 as f32);
+                self.mouse_delta = Vector2f::new(delta.0 as f32, delta.1 as f32);
                 
                 for (action, value) in self.action_values.iter_mut() {
-                    if let InputValue::Vector2(_, _) = value {
-                        *value = InputValue::Vector2(delta.0 as f32, delta.1 as f32);
+                    if let InputValue::Vector2(_) = value {
+                        *value = InputValue::Vector2(self.mouse_delta);
                         
                         if let Some(callbacks) = self.action_callbacks.get(action) {
                             for callback in callbacks {
@@ -165,6 +167,6 @@ impl InputManager {
     }
 
     pub fn update(&mut self) {
-        self.mouse_delta = (0.0, 0.0);
+        self.mouse_delta = Vector2f::zero();
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-mod components;
+pub mod components;
 pub mod engine;
 mod renderer;
 mod input_manager;
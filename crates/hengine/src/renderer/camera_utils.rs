@@ -1,4 +1,5 @@
-use hmath::matrix::Matrix4x4;
+use hmath::matrix::{Matrix4d, Matrix4x4};
+use hmath::quaternion::Quaternion;
 use hmath::vector::Vector3d;
 
 pub fn build_perspective_projection_matrix(fovy: f32, aspect: f32, near: f32, far: f32) -> Matrix4x4 {
@@ -32,24 +33,34 @@ pub fn build_view_matrix(position: Vector3d, target: Vector3d, up: Vector3d) ->
     let s = f.cross(&up).normalize();
     let u = s.cross(&f);
 
-    Matrix4x4 {
+    Matrix4d {
         data: [
-            s.x as f32,
-            u.x as f32,
-            -f.x as f32,
+            s.x,
+            u.x,
+            -f.x,
             0.0,
-            s.y as f32,
-            u.y as f32,
-            -f.y as f32,
+            s.y,
+            u.y,
+            -f.y,
             0.0,
-            s.z as f32,
-            u.z as f32,
-            -f.z as f32,
+            s.z,
+            u.z,
+            -f.z,
             0.0,
-            -s.dot(&position) as f32,
-            -u.dot(&position) as f32,
-            f.dot(&position) as f32,
+            -s.dot(&position),
+            -u.dot(&position),
+            f.dot(&position),
             1.0,
         ],
     }
+    .cast()
+}
+
+/// A view matrix with the camera at the origin, for camera-relative
+/// rendering: the renderer adds each mesh's offset from the camera, computed
+/// in `f64`, so no large coordinate is ever rounded to `f32`.
+pub fn build_camera_relative_view_matrix(rotation: &Quaternion<f64>) -> Matrix4x4 {
+    let forward = rotation.rotate_vector(&Vector3d::new(0.0, 0.0, 1.0));
+    let up = rotation.rotate_vector(&Vector3d::new(0.0, 1.0, 0.0));
+    build_view_matrix(Vector3d::zero(), forward, up)
 }
\ No newline at end of file
@@ -90,9 +90,9 @@ impl CameraControllerSystem {
             let delta_movement = rotated_movement * self.config.move_speed * delta_time as f64;
             transform.position += delta_movement;
             
-            if let Some(InputValue::Vector2(dx, dy)) = input_manager.get_action_value(&self.look_action) {
-                let yaw = -dx as f64 * self.config.rotation_speed;
-                let pitch = -dy as f64 * self.config.rotation_speed;
+            if let Some(InputValue::Vector2(delta)) = input_manager.get_action_value(&self.look_action) {
+                let yaw = -delta.x as f64 * self.config.rotation_speed;
+                let pitch = -delta.y as f64 * self.config.rotation_speed;
                 
                 let yaw_quat = Quaternion::from_axis_angle(Vector3d::new(0.0, 1.0, 0.0), yaw);
                 let right = rotation.rotate_vector(&Vector3d::new(1.0, 0.0, 0.0));
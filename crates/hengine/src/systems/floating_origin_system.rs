@@ -0,0 +1,92 @@
+use crate::components::camera_component::CameraComponent;
+use crate::components::transform_component::TransformComponent;
+use hecs::World;
+use hmath::vector::Vector3d;
+
+pub struct FloatingOriginConfig {
+    /// How far the camera may get from the origin before the world is
+    /// shifted back; `None` never rebases.
+    pub rebase_distance: Option<f64>,
+    /// Shifts are whole multiples of this, so grid-aligned data such as
+    /// voxel chunks stays aligned after a rebase.
+    pub grid_size: f64,
+}
+
+impl Default for FloatingOriginConfig {
+    fn default() -> Self {
+        Self {
+            rebase_distance: None,
+            grid_size: 1024.0,
+        }
+    }
+}
+
+/// Keeps the camera near the origin of the coordinate frame that
+/// `TransformComponent` positions are stored in.
+///
+/// When the camera travels further than the rebase distance, every
+/// transform is moved by the same grid-aligned amount and that amount is
+/// added to [`origin`](Self::origin), so `local + origin` is the position in
+/// absolute world coordinates.
+pub struct FloatingOriginSystem {
+    config: FloatingOriginConfig,
+    origin: Vector3d,
+}
+
+impl FloatingOriginSystem {
+    pub fn new(config: FloatingOriginConfig) -> Self {
+        Self {
+            config,
+            origin: Vector3d::zero(),
+        }
+    }
+
+    /// The absolute world position of the local frame's origin.
+    pub fn origin(&self) -> Vector3d {
+        self.origin
+    }
+
+    pub fn set_rebase_distance(&mut self, distance: Option<f64>) {
+        self.config.rebase_distance = distance;
+    }
+
+    pub fn to_local(&self, world_position: Vector3d) -> Vector3d {
+        world_position - self.origin
+    }
+
+    pub fn to_world(&self, local_position: Vector3d) -> Vector3d {
+        local_position + self.origin
+    }
+
+    /// Rebases the world if the camera is too far out, returning the shift
+    /// that was subtracted from every local position.
+    pub fn update(&mut self, world: &mut World) -> Option<Vector3d> {
+        let distance = self.config.rebase_distance?;
+        let camera_position = world
+            .query_mut::<(&CameraComponent, &TransformComponent)>()
+            .into_iter()
+            .map(|(_, (_, transform))| transform.position)
+            .next()?;
+        if camera_position.length() <= distance {
+            return None;
+        }
+
+        let grid = self.config.grid_size;
+        let shift = (camera_position / grid).round() * grid;
+        if shift == Vector3d::zero() {
+            return None;
+        }
+
+        for (_, transform) in world.query_mut::<&mut TransformComponent>() {
+            transform.position -= shift;
+        }
+        self.origin += shift;
+        Some(shift)
+    }
+}
+
+impl Default for FloatingOriginSystem {
+    fn default() -> Self {
+        Self::new(FloatingOriginConfig::default())
+    }
+}
@@ -1 +1,3 @@
-pub mod camera_controller_system;
\ No newline at end of file
+pub mod camera_controller_system;
+pub mod floating_origin_system;
+pub mod path_follower_system;
\ No newline at end of file
@@ -0,0 +1,51 @@
+use crate::components::path_follower_component::PathFollowerComponent;
+use crate::components::transform_component::TransformComponent;
+use hecs::World;
+use hmath::quaternion::Quaternion;
+use hmath::vector::Vector3d;
+
+pub struct PathFollowerSystem;
+
+impl PathFollowerSystem {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Paths are in absolute world coordinates; `origin` is the floating
+    /// origin that transforms are stored relative to.
+    pub fn update(&self, world: &mut World, delta_time: f32, origin: Vector3d) {
+        for (_, (transform, follower)) in world.query_mut::<(&mut TransformComponent, &mut PathFollowerComponent)>() {
+            let length = follower.path.length();
+            if length <= 0.0 {
+                continue;
+            }
+
+            follower.distance += follower.speed * delta_time as f64;
+            follower.distance = if follower.looping {
+                follower.distance.rem_euclid(length)
+            } else {
+                follower.distance.clamp(0.0, length)
+            };
+
+            transform.position = follower.path.position_at(follower.distance) - origin;
+
+            if let Some(rotations) = &follower.rotations {
+                transform.rotation = rotations.rotation(follower.distance / length);
+            } else if follower.orient_to_path {
+                let mut direction = follower.path.direction_at(follower.distance);
+                if follower.speed < 0.0 {
+                    direction = -direction;
+                }
+                if direction.norm_squared() > 0.0 {
+                    transform.rotation = Quaternion::look_rotation(&direction, &Vector3d::new(0.0, 1.0, 0.0));
+                }
+            }
+        }
+    }
+}
+
+impl Default for PathFollowerSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
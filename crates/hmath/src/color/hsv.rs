@@ -0,0 +1,115 @@
+//! Hue-based colour models. Like colour pickers, both operate on
+//! sRGB-encoded channels; hue is in degrees in `[0, 360)`.
+
+use crate::color::srgb::Srgba;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsva {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+    pub a: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsla {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+    pub a: f32,
+}
+
+/// Hue in degrees plus the maximum and minimum channel.
+fn hue_max_min(color: &Srgba) -> (f32, f32, f32) {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let delta = max - min;
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == color.r {
+        60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+    } else if max == color.g {
+        60.0 * ((color.b - color.r) / delta + 2.0)
+    } else {
+        60.0 * ((color.r - color.g) / delta + 4.0)
+    };
+    (hue, max, min)
+}
+
+/// RGB from hue, chroma and the value added to every channel.
+fn from_hue(h: f32, chroma: f32, offset: f32, a: f32) -> Srgba {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Srgba::new(r + offset, g + offset, b + offset, a)
+}
+
+impl Hsva {
+    pub const fn new(h: f32, s: f32, v: f32, a: f32) -> Self {
+        Self { h, s, v, a }
+    }
+
+    pub fn from_srgba(color: &Srgba) -> Self {
+        let (h, max, min) = hue_max_min(color);
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Self::new(h, s, max, color.a)
+    }
+
+    pub fn to_srgba(&self) -> Srgba {
+        let chroma = self.v * self.s;
+        from_hue(self.h, chroma, self.v - chroma, self.a)
+    }
+}
+
+impl Hsla {
+    pub const fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        Self { h, s, l, a }
+    }
+
+    pub fn from_srgba(color: &Srgba) -> Self {
+        let (h, max, min) = hue_max_min(color);
+        let l = (max + min) * 0.5;
+        let s = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Self::new(h, s, l, color.a)
+    }
+
+    pub fn to_srgba(&self) -> Srgba {
+        let chroma = (1.0 - (2.0 * self.l - 1.0).abs()) * self.s;
+        from_hue(self.h, chroma, self.l - chroma * 0.5, self.a)
+    }
+}
+
+impl From<Srgba> for Hsva {
+    fn from(color: Srgba) -> Self {
+        Self::from_srgba(&color)
+    }
+}
+
+impl From<Hsva> for Srgba {
+    fn from(color: Hsva) -> Self {
+        color.to_srgba()
+    }
+}
+
+impl From<Srgba> for Hsla {
+    fn from(color: Srgba) -> Self {
+        Self::from_srgba(&color)
+    }
+}
+
+impl From<Hsla> for Srgba {
+    fn from(color: Hsla) -> Self {
+        color.to_srgba()
+    }
+}
@@ -0,0 +1,137 @@
+use crate::color::oklab::Oklab;
+use crate::color::pack;
+use crate::color::srgb::Srgba;
+use crate::vector::{Vector3, Vector4};
+use bytemuck::{Pod, Zeroable};
+
+/// A colour with linear-light RGB channels (Rec. 709 primaries) and straight
+/// alpha. This is the space lighting and blending happen in and what shaders
+/// should receive; sRGB encoding is applied only when storing to 8-bit
+/// targets.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LinearRgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+unsafe impl Zeroable for LinearRgba {}
+unsafe impl Pod for LinearRgba {}
+
+impl LinearRgba {
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    /// Multiplies the colour channels by alpha.
+    pub fn premultiply(&self) -> Self {
+        Self::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    /// Inverse of [`LinearRgba::premultiply`]. Fully transparent colours
+    /// become transparent black.
+    pub fn unpremultiply(&self) -> Self {
+        if self.a == 0.0 {
+            return Self::TRANSPARENT;
+        }
+        Self::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+
+    /// Relative luminance (Rec. 709 weights).
+    pub fn luminance(&self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::new(
+            self.r + (other.r - self.r) * t,
+            self.g + (other.g - self.g) * t,
+            self.b + (other.b - self.b) * t,
+            self.a + (other.a - self.a) * t,
+        )
+    }
+
+    /// The RGB channels, e.g. for `Vertex::color`.
+    pub fn to_vector3(&self) -> Vector3<f32> {
+        Vector3::new(self.r, self.g, self.b)
+    }
+
+    pub fn to_vector4(&self) -> Vector4<f32> {
+        Vector4::new(self.r, self.g, self.b, self.a)
+    }
+
+    /// Packs for an `R8G8B8A8_SRGB` target: sRGB-encodes the colour channels
+    /// so the hardware decode returns this colour.
+    pub fn to_rgba8_srgb(&self) -> u32 {
+        Srgba::from(*self).to_rgba8()
+    }
+
+    /// Packs for an `R8G8B8A8_UNORM` target, storing linear values directly.
+    pub fn to_rgba8_unorm(&self) -> u32 {
+        pack::pack_rgba8(self.r, self.g, self.b, self.a)
+    }
+
+    /// Packs for an `A2B10G10R10_UNORM_PACK32` target.
+    pub fn to_rgb10a2(&self) -> u32 {
+        pack::pack_rgb10a2(self.r, self.g, self.b, self.a)
+    }
+
+    /// Packs for an `E5B9G9R9_UFLOAT_PACK32` texture. Alpha is dropped and
+    /// negative channels clamp to zero.
+    pub fn to_rgb9e5(&self) -> u32 {
+        pack::pack_rgb9e5(self.r, self.g, self.b)
+    }
+
+    /// Packs for an `R16G16B16A16_SFLOAT` target.
+    pub fn to_rgba16f(&self) -> [u16; 4] {
+        [
+            pack::f32_to_f16(self.r),
+            pack::f32_to_f16(self.g),
+            pack::f32_to_f16(self.b),
+            pack::f32_to_f16(self.a),
+        ]
+    }
+
+    pub fn from_rgb10a2(packed: u32) -> Self {
+        let (r, g, b, a) = pack::unpack_rgb10a2(packed);
+        Self::new(r, g, b, a)
+    }
+
+    pub fn from_rgb9e5(packed: u32) -> Self {
+        let (r, g, b) = pack::unpack_rgb9e5(packed);
+        Self::rgb(r, g, b)
+    }
+
+    pub fn from_rgba16f(halves: [u16; 4]) -> Self {
+        let [r, g, b, a] = halves.map(pack::f16_to_f32);
+        Self::new(r, g, b, a)
+    }
+}
+
+impl From<Srgba> for LinearRgba {
+    fn from(color: Srgba) -> Self {
+        color.to_linear()
+    }
+}
+
+impl From<Oklab> for LinearRgba {
+    fn from(color: Oklab) -> Self {
+        color.to_linear()
+    }
+}
+
+impl From<LinearRgba> for [f32; 4] {
+    fn from(color: LinearRgba) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
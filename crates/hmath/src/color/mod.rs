@@ -0,0 +1,5 @@
+pub mod hsv;
+pub mod linear;
+pub mod oklab;
+pub mod pack;
+pub mod srgb;
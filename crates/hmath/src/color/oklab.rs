@@ -0,0 +1,78 @@
+use crate::color::linear::LinearRgba;
+
+/// Björn Ottosson's Oklab perceptual colour space. Distances and
+/// interpolation in Oklab track perceived differences far better than in
+/// RGB, which makes it the space to build gradients and palettes in.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklab {
+    /// Perceived lightness, 0 for black and 1 for white.
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+impl Oklab {
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    pub fn from_linear(color: &LinearRgba) -> Self {
+        let l = 0.412_221_46 * color.r + 0.536_332_55 * color.g + 0.051_445_995 * color.b;
+        let m = 0.211_903_5 * color.r + 0.680_699_5 * color.g + 0.107_396_96 * color.b;
+        let s = 0.088_302_46 * color.r + 0.281_718_85 * color.g + 0.629_978_7 * color.b;
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+        Self {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha: color.a,
+        }
+    }
+
+    pub fn to_linear(&self) -> LinearRgba {
+        let l = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        LinearRgba::new(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            self.alpha,
+        )
+    }
+
+    /// Chroma, the distance from the neutral axis.
+    pub fn chroma(&self) -> f32 {
+        self.a.hypot(self.b)
+    }
+
+    /// Hue angle in degrees, in `[0, 360)`.
+    pub fn hue(&self) -> f32 {
+        self.b.atan2(self.a).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Euclidean distance, a simple perceptual difference measure.
+    pub fn distance(&self, other: &Self) -> f32 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        (dl * dl + da * da + db * db).sqrt()
+    }
+
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::new(
+            self.l + (other.l - self.l) * t,
+            self.a + (other.a - self.a) * t,
+            self.b + (other.b - self.b) * t,
+            self.alpha + (other.alpha - self.alpha) * t,
+        )
+    }
+}
+
+impl From<LinearRgba> for Oklab {
+    fn from(color: LinearRgba) -> Self {
+        Self::from_linear(&color)
+    }
+}
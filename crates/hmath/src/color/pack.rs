@@ -0,0 +1,169 @@
+//! Bit-exact packing of colour channels into GPU formats.
+//!
+//! Layouts follow the Vulkan format definitions, with the first named
+//! channel in the most significant bits of packed formats and the first
+//! byte in memory for byte-array formats. `pack_rgba8` therefore returns
+//! red in the low byte, matching `R8G8B8A8_*` on little-endian hosts.
+
+#[inline]
+fn to_unorm(value: f32, max: u32) -> u32 {
+    // `clamp` keeps NaN, so map it to zero explicitly.
+    let value = if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    };
+    (value * max as f32).round() as u32
+}
+
+#[inline]
+fn from_unorm(value: u32, max: u32) -> f32 {
+    value as f32 / max as f32
+}
+
+/// Exact `2^exponent` for exponents in the normal `f32` range.
+#[inline]
+fn exp2i(exponent: i32) -> f32 {
+    f32::from_bits(((exponent + 127) as u32) << 23)
+}
+
+pub fn pack_rgba8(r: f32, g: f32, b: f32, a: f32) -> u32 {
+    to_unorm(r, 255) | (to_unorm(g, 255) << 8) | (to_unorm(b, 255) << 16) | (to_unorm(a, 255) << 24)
+}
+
+pub fn unpack_rgba8(packed: u32) -> (f32, f32, f32, f32) {
+    (
+        from_unorm(packed & 0xff, 255),
+        from_unorm((packed >> 8) & 0xff, 255),
+        from_unorm((packed >> 16) & 0xff, 255),
+        from_unorm(packed >> 24, 255),
+    )
+}
+
+/// `A2B10G10R10_UNORM_PACK32`: red in bits 0-9, alpha in bits 30-31.
+pub fn pack_rgb10a2(r: f32, g: f32, b: f32, a: f32) -> u32 {
+    to_unorm(r, 1023)
+        | (to_unorm(g, 1023) << 10)
+        | (to_unorm(b, 1023) << 20)
+        | (to_unorm(a, 3) << 30)
+}
+
+pub fn unpack_rgb10a2(packed: u32) -> (f32, f32, f32, f32) {
+    (
+        from_unorm(packed & 0x3ff, 1023),
+        from_unorm((packed >> 10) & 0x3ff, 1023),
+        from_unorm((packed >> 20) & 0x3ff, 1023),
+        from_unorm(packed >> 30, 3),
+    )
+}
+
+const RGB9E5_MANTISSA_BITS: i32 = 9;
+const RGB9E5_EXPONENT_BIAS: i32 = 15;
+const RGB9E5_MAX_EXPONENT: i32 = 31;
+/// Largest representable channel value, `511/512 * 2^16`.
+pub const RGB9E5_MAX: f32 = 65408.0;
+
+/// `E5B9G9R9_UFLOAT_PACK32`, following the encoding in the Vulkan
+/// specification: three 9-bit mantissas sharing a 5-bit exponent.
+pub fn pack_rgb9e5(r: f32, g: f32, b: f32) -> u32 {
+    let clamp = |v: f32| {
+        if v.is_nan() {
+            0.0
+        } else {
+            v.clamp(0.0, RGB9E5_MAX)
+        }
+    };
+    let (r, g, b) = (clamp(r), clamp(g), clamp(b));
+    let max = r.max(g).max(b);
+
+    // floor(log2(max)) read from the float's exponent field; anything below
+    // the smallest shared exponent takes the minimum.
+    let floor_log2 = if max < exp2i(-RGB9E5_EXPONENT_BIAS - 1) {
+        -RGB9E5_EXPONENT_BIAS - 1
+    } else {
+        ((max.to_bits() >> 23) & 0xff) as i32 - 127
+    };
+    let mut exponent = floor_log2 + 1 + RGB9E5_EXPONENT_BIAS;
+    let scale = |e: i32| exp2i(e - RGB9E5_EXPONENT_BIAS - RGB9E5_MANTISSA_BITS);
+    if (max / scale(exponent) + 0.5).floor() as i32 == 1 << RGB9E5_MANTISSA_BITS {
+        exponent += 1;
+    }
+    debug_assert!(exponent <= RGB9E5_MAX_EXPONENT);
+
+    let mantissa = |v: f32| (v / scale(exponent) + 0.5).floor() as u32;
+    mantissa(r) | (mantissa(g) << 9) | (mantissa(b) << 18) | ((exponent as u32) << 27)
+}
+
+pub fn unpack_rgb9e5(packed: u32) -> (f32, f32, f32) {
+    let exponent = (packed >> 27) as i32;
+    let scale = exp2i(exponent - RGB9E5_EXPONENT_BIAS - RGB9E5_MANTISSA_BITS);
+    (
+        (packed & 0x1ff) as f32 * scale,
+        ((packed >> 9) & 0x1ff) as f32 * scale,
+        ((packed >> 18) & 0x1ff) as f32 * scale,
+    )
+}
+
+/// Converts to IEEE 754 half precision, rounding to nearest even. Values
+/// beyond the half range become infinity and NaN stays NaN.
+pub fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x007f_ffff;
+
+    if exponent == 0xff {
+        let nan = if mantissa != 0 {
+            0x0200 | (mantissa >> 13) as u16
+        } else {
+            0
+        };
+        return sign | 0x7c00 | nan;
+    }
+
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    if half_exponent <= 0 {
+        // Subnormal half (or zero): shift the implicit bit into the mantissa.
+        if half_exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x0080_0000;
+        let shift = (14 - half_exponent) as u32;
+        let mut half = mantissa >> shift;
+        let remainder = mantissa & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        if remainder > halfway || (remainder == halfway && half & 1 == 1) {
+            half += 1;
+        }
+        return sign | half as u16;
+    }
+
+    // A carry out of the mantissa correctly bumps the exponent, up to
+    // infinity.
+    let mut half = ((half_exponent as u32) << 10) | (mantissa >> 13);
+    let remainder = mantissa & 0x1fff;
+    if remainder > 0x1000 || (remainder == 0x1000 && half & 1 == 1) {
+        half += 1;
+    }
+    sign | half as u16
+}
+
+pub fn f16_to_f32(half: u16) -> f32 {
+    let sign = ((half & 0x8000) as u32) << 16;
+    let exponent = ((half >> 10) & 0x1f) as u32;
+    let mantissa = (half & 0x03ff) as u32;
+    let bits = match exponent {
+        0 => {
+            // Subnormal: exact in f32.
+            let magnitude = mantissa as f32 * exp2i(-24);
+            return if sign != 0 { -magnitude } else { magnitude };
+        }
+        0x1f => sign | 0x7f80_0000 | (mantissa << 13),
+        _ => sign | ((exponent + 112) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(bits)
+}
@@ -0,0 +1,96 @@
+use crate::color::linear::LinearRgba;
+use crate::color::pack;
+
+/// Decodes one sRGB-encoded channel to linear light using the exact
+/// piecewise IEC 61966-2-1 curve.
+pub fn decode_srgb(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes one linear channel with the sRGB transfer function.
+pub fn encode_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// A colour with sRGB-encoded channels and linear alpha, as found in colour
+/// pickers, hex codes and 8-bit textures.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Srgba {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Srgba {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    /// From 8-bit channels.
+    pub fn from_bytes(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        )
+    }
+
+    /// From a `0xRRGGBBAA` hex code.
+    pub fn from_hex(hex: u32) -> Self {
+        Self::from_bytes(
+            (hex >> 24) as u8,
+            (hex >> 16) as u8,
+            (hex >> 8) as u8,
+            hex as u8,
+        )
+    }
+
+    pub fn to_linear(&self) -> LinearRgba {
+        LinearRgba::new(
+            decode_srgb(self.r),
+            decode_srgb(self.g),
+            decode_srgb(self.b),
+            self.a,
+        )
+    }
+
+    pub fn from_linear(color: &LinearRgba) -> Self {
+        Self::new(
+            encode_srgb(color.r),
+            encode_srgb(color.g),
+            encode_srgb(color.b),
+            color.a,
+        )
+    }
+
+    /// Packs the encoded channels as `R8G8B8A8`, red in the low byte.
+    pub fn to_rgba8(&self) -> u32 {
+        pack::pack_rgba8(self.r, self.g, self.b, self.a)
+    }
+
+    pub fn from_rgba8(packed: u32) -> Self {
+        let (r, g, b, a) = pack::unpack_rgba8(packed);
+        Self::new(r, g, b, a)
+    }
+}
+
+impl From<LinearRgba> for Srgba {
+    fn from(color: LinearRgba) -> Self {
+        Self::from_linear(&color)
+    }
+}
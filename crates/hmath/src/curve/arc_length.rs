@@ -0,0 +1,123 @@
+use crate::curve::Curve;
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// Five-point Gauss-Legendre nodes and weights on `[-1, 1]`.
+const GAUSS_NODES: [f64; 5] = [
+    0.0,
+    -0.538_469_310_105_683_1,
+    0.538_469_310_105_683_1,
+    -0.906_179_845_938_664,
+    0.906_179_845_938_664,
+];
+const GAUSS_WEIGHTS: [f64; 5] = [
+    0.568_888_888_888_888_9,
+    0.478_628_670_499_366_5,
+    0.478_628_670_499_366_5,
+    0.236_926_885_056_189_1,
+    0.236_926_885_056_189_1,
+];
+
+/// Length of `curve` between parameters `a` and `b`.
+pub fn arc_length<T: Float, C: Curve<T>>(curve: &C, a: T, b: T) -> T {
+    let half = T::from(0.5).unwrap();
+    let center = (a + b) * half;
+    let radius = (b - a) * half;
+    let mut sum = T::zero();
+    for (node, weight) in GAUSS_NODES.iter().zip(GAUSS_WEIGHTS.iter()) {
+        let t = center + radius * T::from(*node).unwrap();
+        sum = sum + T::from(*weight).unwrap() * curve.derivative(t).length();
+    }
+    sum * radius
+}
+
+/// A curve reparameterized by distance travelled, for constant-speed motion.
+///
+/// A table of cumulative lengths is built once; lookups binary-search it and
+/// refine the parameter with Newton's method.
+#[derive(Debug, Clone)]
+pub struct ArcLengthCurve<C, T> {
+    curve: C,
+    parameters: Vec<T>,
+    lengths: Vec<T>,
+}
+
+impl<T: Float, C: Curve<T>> ArcLengthCurve<C, T> {
+    /// Builds the length table from `samples` equal parameter intervals.
+    /// Piecewise curves should use at least a few samples per segment.
+    pub fn new(curve: C, samples: usize) -> Self {
+        let samples = samples.max(1);
+        let step = T::one() / T::from(samples).unwrap();
+        let mut parameters = Vec::with_capacity(samples + 1);
+        let mut lengths = Vec::with_capacity(samples + 1);
+        parameters.push(T::zero());
+        lengths.push(T::zero());
+        let mut total = T::zero();
+        for i in 1..=samples {
+            let a = step * T::from(i - 1).unwrap();
+            let b = if i == samples {
+                T::one()
+            } else {
+                step * T::from(i).unwrap()
+            };
+            total = total + arc_length(&curve, a, b);
+            parameters.push(b);
+            lengths.push(total);
+        }
+        Self {
+            curve,
+            parameters,
+            lengths,
+        }
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn length(&self) -> T {
+        *self.lengths.last().unwrap()
+    }
+
+    /// Curve parameter at `distance` along the curve, clamped to the ends.
+    pub fn parameter_at(&self, distance: T) -> T {
+        let total = self.length();
+        if distance <= T::zero() || total <= T::zero() {
+            return T::zero();
+        }
+        if distance >= total {
+            return T::one();
+        }
+
+        let upper = self.lengths.partition_point(|&length| length < distance);
+        let lower = upper - 1;
+        let (t0, t1) = (self.parameters[lower], self.parameters[upper]);
+        let (s0, s1) = (self.lengths[lower], self.lengths[upper]);
+        let mut t = t0 + (t1 - t0) * (distance - s0) / (s1 - s0);
+
+        for _ in 0..3 {
+            let error = s0 + arc_length(&self.curve, t0, t) - distance;
+            let speed = self.curve.derivative(t).length();
+            if speed <= T::epsilon() {
+                break;
+            }
+            t = (t - error / speed).max(t0).min(t1);
+        }
+        t
+    }
+
+    pub fn position_at(&self, distance: T) -> Vector3<T> {
+        self.curve.position(self.parameter_at(distance))
+    }
+
+    /// Unit direction of travel at `distance`, or zero where the curve stops.
+    pub fn direction_at(&self, distance: T) -> Vector3<T> {
+        let derivative = self.curve.derivative(self.parameter_at(distance));
+        let length = derivative.length();
+        if length <= T::epsilon() {
+            Vector3::new(T::zero(), T::zero(), T::zero())
+        } else {
+            derivative / length
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::curve::Curve;
+use crate::vector::Vector3;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticBezier<T> {
+    pub p0: Vector3<T>,
+    pub p1: Vector3<T>,
+    pub p2: Vector3<T>,
+}
+
+impl<T: Float> QuadraticBezier<T> {
+    pub fn new(p0: Vector3<T>, p1: Vector3<T>, p2: Vector3<T>) -> Self {
+        Self { p0, p1, p2 }
+    }
+}
+
+impl<T: Float> Curve<T> for QuadraticBezier<T> {
+    fn position(&self, t: T) -> Vector3<T> {
+        let s = T::one() - t;
+        self.p0 * (s * s) + self.p1 * (s * t * (T::one() + T::one())) + self.p2 * (t * t)
+    }
+
+    fn derivative(&self, t: T) -> Vector3<T> {
+        let two = T::one() + T::one();
+        ((self.p1 - self.p0) * (T::one() - t) + (self.p2 - self.p1) * t) * two
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier<T> {
+    pub p0: Vector3<T>,
+    pub p1: Vector3<T>,
+    pub p2: Vector3<T>,
+    pub p3: Vector3<T>,
+}
+
+impl<T: Float> CubicBezier<T> {
+    pub fn new(p0: Vector3<T>, p1: Vector3<T>, p2: Vector3<T>, p3: Vector3<T>) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+
+    /// Splits the curve at `t` with de Casteljau's algorithm, returning the
+    /// two halves.
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let a = self.p0.lerp(&self.p1, t);
+        let b = self.p1.lerp(&self.p2, t);
+        let c = self.p2.lerp(&self.p3, t);
+        let d = a.lerp(&b, t);
+        let e = b.lerp(&c, t);
+        let f = d.lerp(&e, t);
+        (Self::new(self.p0, a, d, f), Self::new(f, e, c, self.p3))
+    }
+}
+
+impl<T: Float> Curve<T> for CubicBezier<T> {
+    fn position(&self, t: T) -> Vector3<T> {
+        let three = T::from(3.0).unwrap();
+        let s = T::one() - t;
+        self.p0 * (s * s * s)
+            + self.p1 * (three * s * s * t)
+            + self.p2 * (three * s * t * t)
+            + self.p3 * (t * t * t)
+    }
+
+    fn derivative(&self, t: T) -> Vector3<T> {
+        let three = T::from(3.0).unwrap();
+        let two = T::one() + T::one();
+        let s = T::one() - t;
+        ((self.p1 - self.p0) * (s * s)
+            + (self.p2 - self.p1) * (two * s * t)
+            + (self.p3 - self.p2) * (t * t))
+            * three
+    }
+}
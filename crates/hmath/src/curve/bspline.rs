@@ -0,0 +1,63 @@
+use crate::curve::{locate_segment, Curve};
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// A uniform cubic B-spline. It is C2 continuous but only approximates its
+/// control points; repeat the first and last point three times to make the
+/// curve start and end on them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BSpline<T> {
+    points: Vec<Vector3<T>>,
+}
+
+impl<T: Float> BSpline<T> {
+    /// Returns `None` with fewer than four control points.
+    pub fn new(points: Vec<Vector3<T>>) -> Option<Self> {
+        if points.len() < 4 {
+            return None;
+        }
+        Some(Self { points })
+    }
+
+    pub fn points(&self) -> &[Vector3<T>] {
+        &self.points
+    }
+
+    pub fn segment_count(&self) -> usize {
+        self.points.len() - 3
+    }
+}
+
+impl<T: Float> Curve<T> for BSpline<T> {
+    fn position(&self, t: T) -> Vector3<T> {
+        let (i, u) = locate_segment(t, self.segment_count());
+        let p = &self.points[i..i + 4];
+        let sixth = T::one() / T::from(6.0).unwrap();
+        let three = T::from(3.0).unwrap();
+        let four = T::from(4.0).unwrap();
+        let s = T::one() - u;
+        let u2 = u * u;
+        let u3 = u2 * u;
+        let b0 = s * s * s;
+        let b1 = three * u3 - (three + three) * u2 + four;
+        let b2 = -three * u3 + three * u2 + three * u + T::one();
+        let b3 = u3;
+        (p[0] * b0 + p[1] * b1 + p[2] * b2 + p[3] * b3) * sixth
+    }
+
+    fn derivative(&self, t: T) -> Vector3<T> {
+        let count = self.segment_count();
+        let (i, u) = locate_segment(t, count);
+        let p = &self.points[i..i + 4];
+        let half = T::from(0.5).unwrap();
+        let three = T::from(3.0).unwrap();
+        let four = T::from(4.0).unwrap();
+        let s = T::one() - u;
+        let u2 = u * u;
+        let b0 = -s * s;
+        let b1 = three * u2 - four * u;
+        let b2 = -three * u2 + (T::one() + T::one()) * u + T::one();
+        let b3 = u2;
+        (p[0] * b0 + p[1] * b1 + p[2] * b2 + p[3] * b3) * (half * T::from(count).unwrap())
+    }
+}
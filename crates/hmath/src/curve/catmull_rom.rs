@@ -0,0 +1,121 @@
+use crate::curve::hermite::Hermite;
+use crate::curve::{locate_segment, Curve};
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// A Catmull-Rom spline passing through every control point.
+///
+/// `alpha` selects the knot spacing: 0 is uniform, 0.5 centripetal and 1
+/// chordal. Centripetal never forms cusps or self-intersections within a
+/// segment, which makes it the usual choice for camera paths.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatmullRom<T> {
+    points: Vec<Vector3<T>>,
+    alpha: T,
+    closed: bool,
+}
+
+impl<T: Float> CatmullRom<T> {
+    /// An open spline from the first to the last point. Returns `None` with
+    /// fewer than two points.
+    pub fn new(points: Vec<Vector3<T>>, alpha: T) -> Option<Self> {
+        if points.len() < 2 {
+            return None;
+        }
+        Some(Self {
+            points,
+            alpha,
+            closed: false,
+        })
+    }
+
+    /// A loop that returns from the last point to the first. Returns `None`
+    /// with fewer than three points.
+    pub fn closed(points: Vec<Vector3<T>>, alpha: T) -> Option<Self> {
+        if points.len() < 3 {
+            return None;
+        }
+        Some(Self {
+            points,
+            alpha,
+            closed: true,
+        })
+    }
+
+    /// Centripetal spline through `points`.
+    pub fn centripetal(points: Vec<Vector3<T>>) -> Option<Self> {
+        Self::new(points, T::from(0.5).unwrap())
+    }
+
+    pub fn points(&self) -> &[Vector3<T>] {
+        &self.points
+    }
+
+    pub fn segment_count(&self) -> usize {
+        if self.closed {
+            self.points.len()
+        } else {
+            self.points.len() - 1
+        }
+    }
+
+    /// Control point `index`, wrapping for closed splines and mirroring the
+    /// neighbouring point past the ends of open ones.
+    fn point(&self, index: isize) -> Vector3<T> {
+        let count = self.points.len() as isize;
+        if self.closed {
+            return self.points[index.rem_euclid(count) as usize];
+        }
+        if index < 0 {
+            let two = T::one() + T::one();
+            self.points[0] * two - self.points[1]
+        } else if index >= count {
+            let two = T::one() + T::one();
+            self.points[count as usize - 1] * two - self.points[count as usize - 2]
+        } else {
+            self.points[index as usize]
+        }
+    }
+
+    /// Segment `index` as a Hermite curve over a unit parameter range.
+    pub fn segment(&self, index: usize) -> Hermite<T> {
+        let i = index as isize;
+        let (p0, p1, p2, p3) = (
+            self.point(i - 1),
+            self.point(i),
+            self.point(i + 1),
+            self.point(i + 2),
+        );
+
+        // Knot intervals; coincident points fall back to a tiny interval.
+        let epsilon = T::from(1e-6).unwrap();
+        let interval = |a: Vector3<T>, b: Vector3<T>| {
+            (b - a)
+                .norm_squared()
+                .powf(self.alpha * T::from(0.5).unwrap())
+                .max(epsilon)
+        };
+        let d0 = interval(p0, p1);
+        let d1 = interval(p1, p2);
+        let d2 = interval(p2, p3);
+
+        // Tangents of the non-uniform spline, rescaled to the segment's unit
+        // parameter range.
+        let m1 = ((p1 - p0) / d0 - (p2 - p0) / (d0 + d1) + (p2 - p1) / d1) * d1;
+        let m2 = ((p2 - p1) / d1 - (p3 - p1) / (d1 + d2) + (p3 - p2) / d2) * d1;
+        Hermite::new(p1, m1, p2, m2)
+    }
+}
+
+impl<T: Float> Curve<T> for CatmullRom<T> {
+    fn position(&self, t: T) -> Vector3<T> {
+        let (index, local) = locate_segment(t, self.segment_count());
+        self.segment(index).position(local)
+    }
+
+    fn derivative(&self, t: T) -> Vector3<T> {
+        let count = self.segment_count();
+        let (index, local) = locate_segment(t, count);
+        self.segment(index).derivative(local) * T::from(count).unwrap()
+    }
+}
@@ -0,0 +1,45 @@
+use crate::curve::Curve;
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// A cubic Hermite segment from `p0` to `p1` with tangents `m0` and `m1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hermite<T> {
+    pub p0: Vector3<T>,
+    pub m0: Vector3<T>,
+    pub p1: Vector3<T>,
+    pub m1: Vector3<T>,
+}
+
+impl<T: Float> Hermite<T> {
+    pub fn new(p0: Vector3<T>, m0: Vector3<T>, p1: Vector3<T>, m1: Vector3<T>) -> Self {
+        Self { p0, m0, p1, m1 }
+    }
+}
+
+impl<T: Float> Curve<T> for Hermite<T> {
+    fn position(&self, t: T) -> Vector3<T> {
+        let two = T::one() + T::one();
+        let three = T::from(3.0).unwrap();
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h00 = two * t3 - three * t2 + T::one();
+        let h10 = t3 - two * t2 + t;
+        let h01 = three * t2 - two * t3;
+        let h11 = t3 - t2;
+        self.p0 * h00 + self.m0 * h10 + self.p1 * h01 + self.m1 * h11
+    }
+
+    fn derivative(&self, t: T) -> Vector3<T> {
+        let two = T::one() + T::one();
+        let three = T::from(3.0).unwrap();
+        let four = two + two;
+        let six = three + three;
+        let t2 = t * t;
+        let h00 = six * t2 - six * t;
+        let h10 = three * t2 - four * t + T::one();
+        let h01 = six * t - six * t2;
+        let h11 = three * t2 - two * t;
+        self.p0 * h00 + self.m0 * h10 + self.p1 * h01 + self.m1 * h11
+    }
+}
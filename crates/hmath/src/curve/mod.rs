@@ -0,0 +1,35 @@
+pub mod arc_length;
+pub mod bezier;
+pub mod bspline;
+pub mod catmull_rom;
+pub mod hermite;
+pub mod squad;
+
+use crate::vector::Vector3;
+
+/// A parametric curve in 3D evaluated over `t` in `[0, 1]`.
+pub trait Curve<T> {
+    fn position(&self, t: T) -> Vector3<T>;
+
+    /// First derivative with respect to `t`. Its length is the speed at which
+    /// the curve is traversed, which is generally not constant.
+    fn derivative(&self, t: T) -> Vector3<T>;
+}
+
+impl<T, C: Curve<T> + ?Sized> Curve<T> for &C {
+    fn position(&self, t: T) -> Vector3<T> {
+        (**self).position(t)
+    }
+
+    fn derivative(&self, t: T) -> Vector3<T> {
+        (**self).derivative(t)
+    }
+}
+
+/// Maps a global parameter over `count` equal segments to a segment index
+/// and the local parameter inside it. `t` is clamped to `[0, 1]`.
+pub(crate) fn locate_segment<T: num_traits::Float>(t: T, count: usize) -> (usize, T) {
+    let scaled = t.max(T::zero()).min(T::one()) * T::from(count).unwrap();
+    let index = scaled.floor().to_usize().unwrap_or(0).min(count - 1);
+    (index, scaled - T::from(index).unwrap())
+}
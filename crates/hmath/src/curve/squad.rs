@@ -0,0 +1,59 @@
+use crate::curve::locate_segment;
+use crate::quaternion::Quaternion;
+use num_traits::Float;
+
+/// Smooth rotation through a sequence of keys using squad, the rotational
+/// counterpart of a Catmull-Rom spline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuaternionSpline<T> {
+    keys: Vec<Quaternion<T>>,
+    tangents: Vec<Quaternion<T>>,
+}
+
+impl<T: Float> QuaternionSpline<T> {
+    /// Returns `None` with fewer than two keys. Keys are normalized and
+    /// flipped into a common hemisphere so every segment takes the short way.
+    pub fn new(keys: Vec<Quaternion<T>>) -> Option<Self> {
+        if keys.len() < 2 {
+            return None;
+        }
+
+        let mut aligned: Vec<Quaternion<T>> = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key = key.normalize();
+            let key = match aligned.last() {
+                Some(previous) if previous.dot(&key) < T::zero() => -key,
+                _ => key,
+            };
+            aligned.push(key);
+        }
+
+        let last = aligned.len() - 1;
+        let tangents = (0..aligned.len())
+            .map(|i| {
+                let previous = aligned[i.saturating_sub(1)];
+                let next = aligned[(i + 1).min(last)];
+                aligned[i].squad_tangent(&previous, &next)
+            })
+            .collect();
+        Some(Self {
+            keys: aligned,
+            tangents,
+        })
+    }
+
+    pub fn keys(&self) -> &[Quaternion<T>] {
+        &self.keys
+    }
+
+    /// Rotation at `t` in `[0, 1]`, with keys spaced evenly.
+    pub fn rotation(&self, t: T) -> Quaternion<T> {
+        let (i, u) = locate_segment(t, self.keys.len() - 1);
+        self.keys[i].squad(
+            &self.tangents[i],
+            &self.tangents[i + 1],
+            &self.keys[i + 1],
+            u,
+        )
+    }
+}
@@ -0,0 +1,731 @@
+use bytemuck::{Pod, Zeroable};
+use num_traits::{Euclid, Float, Num, NumCast, One, ParseFloatError, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// A Q32.32 fixed-point number: a signed 64-bit integer counting units of
+/// `2^-32`.
+///
+/// Every operation, including `sqrt`, the trigonometric functions, `exp`
+/// and `ln`, is computed with integer arithmetic only, so results are
+/// bit-identical on every machine and compiler. This makes it suitable for
+/// lockstep simulation and replays: `Vector3<Fixed64>`, `Quaternion<Fixed64>`
+/// and the rest of hmath work unchanged because it implements [`Float`].
+///
+/// Differences from IEEE floats:
+/// - The range is about ±2.1e9 with a resolution of 2.3e-10.
+/// - Overflow saturates to [`Fixed64::MAX`] or [`Fixed64::MIN`], which also
+///   stand in for the infinities. Dividing by zero saturates the same way,
+///   and `0 / 0` is zero.
+/// - There is no NaN. Operations that would produce one (such as the square
+///   root or logarithm of a negative number) return zero or `MIN`, as
+///   documented on each method.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Hash, Default)]
+pub struct Fixed64(i64);
+
+unsafe impl Zeroable for Fixed64 {}
+unsafe impl Pod for Fixed64 {}
+
+const FRACTION_BITS: u32 = 32;
+const FRACTION_MASK: i64 = (1 << FRACTION_BITS) - 1;
+
+// Transcendental functions are evaluated in Q2.62 held in `i128`, leaving
+// 30 guard bits over the Q32.32 result.
+const Q: u32 = 62;
+const Q_ONE: i128 = 1 << Q;
+const PI_Q: i128 = 14_488_038_916_154_245_685;
+const FRAC_PI_2_Q: i128 = 7_244_019_458_077_122_842;
+const LN_2_Q: i128 = 3_196_577_161_300_663_915;
+
+/// `1 / n!` in Q62 for `n` in `0..=20`.
+const INVERSE_FACTORIALS: [i128; 21] = inverse_factorials();
+
+const fn inverse_factorials() -> [i128; 21] {
+    let mut table = [0i128; 21];
+    let mut factorial: i128 = 1;
+    let mut n = 0;
+    while n < 21 {
+        if n > 0 {
+            factorial *= n as i128;
+        }
+        table[n] = Q_ONE / factorial;
+        n += 1;
+    }
+    table
+}
+
+#[inline]
+fn mul_q(a: i128, b: i128) -> i128 {
+    (a * b + (1 << (Q - 1))) >> Q
+}
+
+#[inline]
+fn div_q(a: i128, b: i128) -> i128 {
+    (a << Q) / b
+}
+
+/// Integer square root of a non-negative `i128`.
+#[inline]
+fn isqrt(value: i128) -> i128 {
+    (value.max(0) as u128).isqrt() as i128
+}
+
+#[inline]
+fn sqrt_q(value: i128) -> i128 {
+    isqrt(value << Q)
+}
+
+#[inline]
+fn to_q(value: Fixed64) -> i128 {
+    (value.0 as i128) << (Q - FRACTION_BITS)
+}
+
+#[inline]
+fn from_q(value: i128) -> Fixed64 {
+    let shift = Q - FRACTION_BITS;
+    saturate((value + (1 << (shift - 1))) >> shift)
+}
+
+#[inline]
+fn saturate(raw: i128) -> Fixed64 {
+    Fixed64(raw.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+}
+
+/// `(sin r, cos r)` for `|r| <= pi/4` in Q62, by Taylor series.
+fn sin_cos_q(r: i128) -> (i128, i128) {
+    let r2 = mul_q(r, r);
+    let mut sin = 0;
+    let mut cos = 0;
+    for k in (0..9).rev() {
+        let sign = if k % 2 == 0 { 1 } else { -1 };
+        sin = sign * INVERSE_FACTORIALS[2 * k + 1] + mul_q(r2, sin);
+        cos = sign * INVERSE_FACTORIALS[2 * k] + mul_q(r2, cos);
+    }
+    (mul_q(r, sin), cos)
+}
+
+/// `atan t` for `0 <= t <= 1` in Q62.
+fn atan_unit_q(t: i128) -> i128 {
+    // Two half-angle reductions bring t below tan(pi/16) ~ 0.2, where the
+    // series converges quickly.
+    let mut t = t;
+    for _ in 0..2 {
+        t = div_q(t, Q_ONE + sqrt_q(Q_ONE + mul_q(t, t)));
+    }
+    let t2 = mul_q(t, t);
+    let mut sum = 0;
+    for k in (0..13).rev() {
+        let sign = if k % 2 == 0 { 1 } else { -1 };
+        sum = sign * (Q_ONE / (2 * k + 1)) + mul_q(t2, sum);
+    }
+    mul_q(t, sum) * 4
+}
+
+/// `exp r` for `|r| <= ln(2) / 2` in Q62.
+fn exp_small_q(r: i128) -> i128 {
+    let mut sum = 0;
+    for k in (0..18).rev() {
+        sum = INVERSE_FACTORIALS[k] + mul_q(r, sum);
+    }
+    sum
+}
+
+impl Fixed64 {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 << FRACTION_BITS);
+    pub const MAX: Self = Self(i64::MAX);
+    pub const MIN: Self = Self(i64::MIN);
+    /// The smallest positive value, `2^-32`.
+    pub const EPSILON: Self = Self(1);
+    pub const PI: Self = Self(13_493_037_705);
+    pub const FRAC_PI_2: Self = Self(6_746_518_852);
+    pub const TAU: Self = Self(26_986_075_409);
+    pub const E: Self = Self(11_674_931_555);
+    pub const LN_2: Self = Self(2_977_044_472);
+    pub const LN_10: Self = Self(9_889_527_671);
+    const DEGREES_PER_RADIAN: Self = Self(246_083_499_208);
+    const RADIANS_PER_DEGREE: Self = Self(74_961_321);
+
+    /// Wraps a raw Q32.32 value.
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// The raw Q32.32 value, e.g. for hashing simulation state.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    pub const fn from_int(value: i32) -> Self {
+        Self((value as i64) << FRACTION_BITS)
+    }
+
+    /// Rounds to the nearest representable value, saturating out of range
+    /// values. NaN maps to zero. Deterministic, since scaling by a power of
+    /// two and rounding are exact in IEEE 754.
+    pub fn from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            return Self::ZERO;
+        }
+        let scaled = (value * (1u64 << FRACTION_BITS) as f64).round();
+        if scaled >= i64::MAX as f64 {
+            Self::MAX
+        } else if scaled <= i64::MIN as f64 {
+            Self::MIN
+        } else {
+            Self(scaled as i64)
+        }
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << FRACTION_BITS) as f64
+    }
+}
+
+impl fmt::Debug for Fixed64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fixed64({})", Fixed64::to_f64(*self))
+    }
+}
+
+impl fmt::Display for Fixed64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Fixed64::to_f64(*self), f)
+    }
+}
+
+impl Add for Fixed64 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+}
+
+impl Sub for Fixed64 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+}
+
+/// Rounds to nearest, ties toward positive infinity.
+impl Mul for Fixed64 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let product = self.0 as i128 * other.0 as i128;
+        saturate((product + (1 << (FRACTION_BITS - 1))) >> FRACTION_BITS)
+    }
+}
+
+/// Truncates toward zero.
+impl Div for Fixed64 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        match (self.0.cmp(&0), other.0) {
+            (Ordering::Equal, _) => Self::ZERO,
+            (Ordering::Greater, 0) => Self::MAX,
+            (Ordering::Less, 0) => Self::MIN,
+            _ => saturate(((self.0 as i128) << FRACTION_BITS) / other.0 as i128),
+        }
+    }
+}
+
+/// Remainder with the sign of the dividend, like `f64`. `x % 0` is zero.
+impl Rem for Fixed64 {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        if other.0 == 0 {
+            Self::ZERO
+        } else {
+            Self(self.0.wrapping_rem(other.0))
+        }
+    }
+}
+
+impl Neg for Fixed64 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.saturating_neg())
+    }
+}
+
+macro_rules! impl_assign_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl $trait for Fixed64 {
+            fn $method(&mut self, other: Self) {
+                *self = *self $op other;
+            }
+        }
+    };
+}
+
+impl_assign_op!(AddAssign, add_assign, +);
+impl_assign_op!(SubAssign, sub_assign, -);
+impl_assign_op!(MulAssign, mul_assign, *);
+impl_assign_op!(DivAssign, div_assign, /);
+impl_assign_op!(RemAssign, rem_assign, %);
+
+impl Zero for Fixed64 {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Fixed64 {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl Num for Fixed64 {
+    type FromStrRadixErr = ParseFloatError;
+
+    fn from_str_radix(text: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        <f64 as Num>::from_str_radix(text, radix).map(Self::from_f64)
+    }
+}
+
+impl ToPrimitive for Fixed64 {
+    /// Truncates toward zero, like `f64` casts.
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.trunc().0 >> FRACTION_BITS)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        let whole = self.trunc().0 >> FRACTION_BITS;
+        u64::try_from(whole).ok()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(Fixed64::to_f64(*self))
+    }
+}
+
+impl NumCast for Fixed64 {
+    /// Returns `None` for NaN and for values outside the representable range.
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        let value = n.to_f64()?;
+        let limit = (1u64 << 31) as f64;
+        if value.is_nan() || value >= limit || value < -limit {
+            return None;
+        }
+        Some(Self::from_f64(value))
+    }
+}
+
+impl Euclid for Fixed64 {
+    fn div_euclid(&self, other: &Self) -> Self {
+        let quotient = (*self / *other).trunc();
+        if (*self % *other).0 < 0 {
+            if other.0 > 0 {
+                quotient - Self::ONE
+            } else {
+                quotient + Self::ONE
+            }
+        } else {
+            quotient
+        }
+    }
+
+    fn rem_euclid(&self, other: &Self) -> Self {
+        let remainder = *self % *other;
+        if remainder.0 < 0 {
+            remainder + other.abs()
+        } else {
+            remainder
+        }
+    }
+}
+
+impl Float for Fixed64 {
+    /// There is no NaN; returns zero.
+    fn nan() -> Self {
+        Self::ZERO
+    }
+
+    /// Saturates to [`Fixed64::MAX`].
+    fn infinity() -> Self {
+        Self::MAX
+    }
+
+    /// Saturates to [`Fixed64::MIN`].
+    fn neg_infinity() -> Self {
+        Self::MIN
+    }
+
+    fn neg_zero() -> Self {
+        Self::ZERO
+    }
+
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    fn min_positive_value() -> Self {
+        Self::EPSILON
+    }
+
+    fn epsilon() -> Self {
+        Self::EPSILON
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+
+    fn is_nan(self) -> bool {
+        false
+    }
+
+    fn is_infinite(self) -> bool {
+        false
+    }
+
+    fn is_finite(self) -> bool {
+        true
+    }
+
+    fn is_normal(self) -> bool {
+        self.0 != 0
+    }
+
+    fn classify(self) -> FpCategory {
+        if self.0 == 0 {
+            FpCategory::Zero
+        } else {
+            FpCategory::Normal
+        }
+    }
+
+    fn floor(self) -> Self {
+        Self(self.0 & !FRACTION_MASK)
+    }
+
+    fn ceil(self) -> Self {
+        Self(self.0.saturating_add(FRACTION_MASK) & !FRACTION_MASK)
+    }
+
+    /// Rounds half away from zero, like `f64::round`.
+    fn round(self) -> Self {
+        let half = 1 << (FRACTION_BITS - 1);
+        if self.0 >= 0 {
+            Self(self.0.saturating_add(half) & !FRACTION_MASK)
+        } else {
+            -Self(self.0.saturating_neg().saturating_add(half) & !FRACTION_MASK)
+        }
+    }
+
+    fn trunc(self) -> Self {
+        if self.0 >= 0 {
+            self.floor()
+        } else {
+            self.ceil()
+        }
+    }
+
+    fn fract(self) -> Self {
+        self - self.trunc()
+    }
+
+    fn abs(self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+
+    /// `1` for zero and positive values, `-1` otherwise, like `f64`.
+    fn signum(self) -> Self {
+        if self.0 >= 0 {
+            Self::ONE
+        } else {
+            -Self::ONE
+        }
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.0 >= 0
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Computed with a single rounding.
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        let product = self.0 as i128 * a.0 as i128 + ((b.0 as i128) << FRACTION_BITS);
+        saturate((product + (1 << (FRACTION_BITS - 1))) >> FRACTION_BITS)
+    }
+
+    fn recip(self) -> Self {
+        Self::ONE / self
+    }
+
+    fn powi(self, n: i32) -> Self {
+        let mut base = self;
+        let mut exponent = n.unsigned_abs();
+        let mut result = Self::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        if n < 0 {
+            result.recip()
+        } else {
+            result
+        }
+    }
+
+    /// `exp(n * ln(self))`. Non-positive bases return zero.
+    fn powf(self, n: Self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+        (n * self.ln()).exp()
+    }
+
+    /// Correctly rounded down. Negative values return zero.
+    fn sqrt(self) -> Self {
+        Self(isqrt((self.0 as i128) << FRACTION_BITS) as i64)
+    }
+
+    fn exp(self) -> Self {
+        // Beyond these bounds the result saturates or underflows.
+        if self > Self::from_int(22) {
+            return Self::MAX;
+        }
+        if self < Self::from_int(-23) {
+            return Self::ZERO;
+        }
+        let x = to_q(self);
+        let k = (x + LN_2_Q / 2).div_euclid(LN_2_Q);
+        let r = x - k * LN_2_Q;
+        let scaled = exp_small_q(r);
+        let shift = k as i32 - (Q - FRACTION_BITS) as i32;
+        if shift >= 0 {
+            saturate(scaled << shift)
+        } else {
+            let shift = -shift as u32;
+            saturate((scaled + (1 << (shift - 1))) >> shift)
+        }
+    }
+
+    fn exp2(self) -> Self {
+        (self * Self::LN_2).exp()
+    }
+
+    /// Non-positive values return [`Fixed64::MIN`].
+    fn ln(self) -> Self {
+        if self.0 <= 0 {
+            return Self::MIN;
+        }
+        // self = m * 2^e with m in [1, 2).
+        let top_bit = 63 - self.0.leading_zeros() as i32;
+        let e = top_bit - FRACTION_BITS as i32;
+        let m = (self.0 as i128) << (Q as i32 - top_bit);
+
+        // ln m = 2 atanh(u) with u = (m - 1) / (m + 1) in [0, 1/3].
+        let u = div_q(m - Q_ONE, m + Q_ONE);
+        let u2 = mul_q(u, u);
+        let mut sum = 0;
+        for k in (0..20).rev() {
+            sum = Q_ONE / (2 * k + 1) + mul_q(u2, sum);
+        }
+        from_q(2 * mul_q(u, sum) + e as i128 * LN_2_Q)
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.ln() / Self::LN_2
+    }
+
+    fn log10(self) -> Self {
+        self.ln() / Self::LN_10
+    }
+
+    fn to_degrees(self) -> Self {
+        self * Self::DEGREES_PER_RADIAN
+    }
+
+    fn to_radians(self) -> Self {
+        self * Self::RADIANS_PER_DEGREE
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self.0 >= other.0 {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if self.0 <= other.0 {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        Float::max(self - other, Self::ZERO)
+    }
+
+    fn cbrt(self) -> Self {
+        if self.0 == 0 {
+            return Self::ZERO;
+        }
+        let root = (self.abs().ln() / Self::from_int(3)).exp();
+        if self.0 < 0 {
+            -root
+        } else {
+            root
+        }
+    }
+
+    /// Exact up to the final rounding; never overflows internally.
+    fn hypot(self, other: Self) -> Self {
+        let (a, b) = (
+            self.0.unsigned_abs() as u128,
+            other.0.unsigned_abs() as u128,
+        );
+        saturate((a * a + b * b).isqrt() as i128)
+    }
+
+    fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    fn tan(self) -> Self {
+        let (sin, cos) = self.sin_cos();
+        sin / cos
+    }
+
+    /// Arguments outside `[-1, 1]` are clamped.
+    fn asin(self) -> Self {
+        let x = self.clamp(-Self::ONE, Self::ONE);
+        x.atan2((Self::ONE - x * x).sqrt())
+    }
+
+    /// Arguments outside `[-1, 1]` are clamped.
+    fn acos(self) -> Self {
+        let x = self.clamp(-Self::ONE, Self::ONE);
+        (Self::ONE - x * x).sqrt().atan2(x)
+    }
+
+    fn atan(self) -> Self {
+        self.atan2(Self::ONE)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self.0 as i128, other.0 as i128);
+        if x == 0 && y == 0 {
+            return Self::ZERO;
+        }
+        let (ay, ax) = (y.abs(), x.abs());
+        let mut angle = if ay <= ax {
+            atan_unit_q(div_q(ay, ax))
+        } else {
+            FRAC_PI_2_Q - atan_unit_q(div_q(ax, ay))
+        };
+        if x < 0 {
+            angle = PI_Q - angle;
+        }
+        if y < 0 {
+            angle = -angle;
+        }
+        from_q(angle)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        // Reduce to r in [-pi/4, pi/4] plus a quadrant count.
+        let x = to_q(self);
+        let quadrant = (x + FRAC_PI_2_Q / 2).div_euclid(FRAC_PI_2_Q);
+        let r = x - quadrant * FRAC_PI_2_Q;
+        let (sin, cos) = sin_cos_q(r);
+        let (sin, cos) = match quadrant.rem_euclid(4) {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        };
+        (from_q(sin), from_q(cos))
+    }
+
+    fn exp_m1(self) -> Self {
+        self.exp() - Self::ONE
+    }
+
+    fn ln_1p(self) -> Self {
+        (self + Self::ONE).ln()
+    }
+
+    fn sinh(self) -> Self {
+        (self.exp() - (-self).exp()) / Self::from_int(2)
+    }
+
+    fn cosh(self) -> Self {
+        (self.exp() + (-self).exp()) / Self::from_int(2)
+    }
+
+    fn tanh(self) -> Self {
+        // Saturated exponentials would give MAX / MAX; tanh is within one
+        // unit of +-1 well before that.
+        if self > Self::from_int(12) {
+            return Self::ONE;
+        }
+        if self < Self::from_int(-12) {
+            return -Self::ONE;
+        }
+        let (a, b) = (self.exp(), (-self).exp());
+        (a - b) / (a + b)
+    }
+
+    fn asinh(self) -> Self {
+        let root = self.hypot(Self::ONE);
+        if self.0 >= 0 {
+            (self + root).ln()
+        } else {
+            -(root - self).ln()
+        }
+    }
+
+    /// Arguments below one return zero.
+    fn acosh(self) -> Self {
+        if self < Self::ONE {
+            return Self::ZERO;
+        }
+        (self + (self * self - Self::ONE).sqrt()).ln()
+    }
+
+    fn atanh(self) -> Self {
+        ((Self::ONE + self) / (Self::ONE - self)).ln() / Self::from_int(2)
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        let sign = if self.0 < 0 { -1 } else { 1 };
+        (self.0.unsigned_abs(), -(FRACTION_BITS as i16), sign)
+    }
+}
@@ -0,0 +1,117 @@
+use crate::geometry::plane::Plane;
+use crate::geometry::sphere::Sphere;
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// An axis-aligned bounding box spanning `min..=max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb<T> {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+impl<T: Float> Aabb<T> {
+    pub fn new(min: Vector3<T>, max: Vector3<T>) -> Self {
+        Self { min, max }
+    }
+
+    /// An inverted box that contains nothing and acts as the identity for
+    /// [`Aabb::union`] and [`Aabb::expand_to_include`].
+    pub fn empty() -> Self {
+        let inf = T::infinity();
+        Self {
+            min: Vector3::new(inf, inf, inf),
+            max: Vector3::new(-inf, -inf, -inf),
+        }
+    }
+
+    pub fn from_center_half_extents(center: Vector3<T>, half_extents: Vector3<T>) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    pub fn from_points(points: &[Vector3<T>]) -> Self {
+        points
+            .iter()
+            .fold(Self::empty(), |aabb, point| aabb.expand_to_include(point))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn center(&self) -> Vector3<T> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+
+    pub fn size(&self) -> Vector3<T> {
+        self.max - self.min
+    }
+
+    pub fn half_extents(&self) -> Vector3<T> {
+        self.size() / (T::one() + T::one())
+    }
+
+    pub fn surface_area(&self) -> T {
+        let size = self.size();
+        (size.x * size.y + size.y * size.z + size.z * size.x) * (T::one() + T::one())
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(self.min.min(&other.min), self.max.max(&other.max))
+    }
+
+    pub fn expand_to_include(&self, point: &Vector3<T>) -> Self {
+        Self::new(self.min.min(point), self.max.max(point))
+    }
+
+    /// Grows the box by `margin` on every side.
+    pub fn expand(&self, margin: T) -> Self {
+        let margin = Vector3::new(margin, margin, margin);
+        Self::new(self.min - margin, self.max + margin)
+    }
+
+    pub fn contains_point(&self, point: &Vector3<T>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    pub fn intersects_aabb(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        let closest = self.closest_point(&sphere.center);
+        closest.distance(&sphere.center) <= sphere.radius
+    }
+
+    /// Whether the box touches or straddles the plane.
+    pub fn intersects_plane(&self, plane: &Plane<T>) -> bool {
+        let radius = self.half_extents().dot(&plane.normal.abs());
+        plane.signed_distance(&self.center()).abs() <= radius
+    }
+
+    pub fn closest_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        point.clamp(&self.min, &self.max)
+    }
+
+    /// The eight corners, ordered by the bits of the index (x in bit 0,
+    /// y in bit 1, z in bit 2; a set bit selects `max`).
+    pub fn corners(&self) -> [Vector3<T>; 8] {
+        std::array::from_fn(|i| {
+            Vector3::new(
+                if i & 1 == 0 { self.min.x } else { self.max.x },
+                if i & 2 == 0 { self.min.y } else { self.max.y },
+                if i & 4 == 0 { self.min.z } else { self.max.z },
+            )
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use crate::geometry::aabb::Aabb;
+use crate::geometry::plane::Plane;
+use crate::geometry::sphere::Sphere;
+use crate::matrix::Matrix4;
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// How a volume relates to a [`Frustum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Containment {
+    Outside,
+    Intersecting,
+    Inside,
+}
+
+/// The six clipping planes of a camera, with normals pointing inwards.
+///
+/// Plane order is left, right, bottom, top, near, far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum<T> {
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T: Float> Frustum<T> {
+    /// Extracts the planes from a view-projection matrix (`view * projection`
+    /// in this crate's row-vector order).
+    ///
+    /// The projection is expected to map depth to `[-w, w]`, as the ones
+    /// built by `camera_utils::build_perspective_projection_matrix` do.
+    pub fn from_matrix(view_projection: &Matrix4<T>) -> Self {
+        let m = &view_projection.data;
+        // Clip coordinate `j` is the dot product of the point with column `j`.
+        let column = |j: usize| (Vector3::new(m[j], m[4 + j], m[8 + j]), m[12 + j]);
+        let (x, xw) = column(0);
+        let (y, yw) = column(1);
+        let (z, zw) = column(2);
+        let (w, ww) = column(3);
+
+        let plane = |normal: Vector3<T>, d: T| Plane::new(normal, d).normalize();
+        Self {
+            planes: [
+                plane(w + x, ww + xw),
+                plane(w - x, ww - xw),
+                plane(w + y, ww + yw),
+                plane(w - y, ww - yw),
+                plane(w + z, ww + zw),
+                plane(w - z, ww - zw),
+            ],
+        }
+    }
+
+    pub fn contains_point(&self, point: &Vector3<T>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= T::zero())
+    }
+
+    pub fn test_sphere(&self, sphere: &Sphere<T>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.signed_distance(&sphere.center);
+            if distance < -sphere.radius {
+                return Containment::Outside;
+            }
+            if distance < sphere.radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    pub fn test_aabb(&self, aabb: &Aabb<T>) -> Containment {
+        let center = aabb.center();
+        let half_extents = aabb.half_extents();
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.signed_distance(&center);
+            let radius = half_extents.dot(&plane.normal.abs());
+            if distance < -radius {
+                return Containment::Outside;
+            }
+            if distance < radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Conservative visibility check: `false` means the box is certainly
+    /// outside, while `true` may include boxes near the frustum's corners.
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        self.test_aabb(aabb) != Containment::Outside
+    }
+
+    /// Appends the indices of every box in `aabbs` that may be visible.
+    ///
+    /// This is the batch form of [`Frustum::intersects_aabb`] for testing
+    /// many chunk bounds at once; the plane data is prepared once up front
+    /// and the loop only tests for rejection.
+    pub fn cull_aabbs(&self, aabbs: &[Aabb<T>], visible: &mut Vec<usize>) {
+        let abs_normals = self.planes.map(|plane| plane.normal.abs());
+        let two = T::one() + T::one();
+
+        'boxes: for (index, aabb) in aabbs.iter().enumerate() {
+            let center2 = aabb.min + aabb.max;
+            let extent2 = aabb.max - aabb.min;
+            for (plane, abs_normal) in self.planes.iter().zip(abs_normals.iter()) {
+                // Both sides are scaled by two to avoid halving per box.
+                let distance2 = plane.normal.dot(&center2) + plane.d * two;
+                if distance2 < -extent2.dot(abs_normal) {
+                    continue 'boxes;
+                }
+            }
+            visible.push(index);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod aabb;
+pub mod frustum;
+pub mod obb;
+pub mod plane;
+pub mod ray;
+pub mod sphere;
+pub mod triangle;
+pub mod voxel_traversal;
@@ -0,0 +1,132 @@
+use crate::geometry::aabb::Aabb;
+use crate::geometry::sphere::Sphere;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// An oriented bounding box: an AABB of `half_extents` around the origin,
+/// rotated by `rotation` and then moved to `center`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb<T> {
+    pub center: Vector3<T>,
+    pub half_extents: Vector3<T>,
+    pub rotation: Quaternion<T>,
+}
+
+impl<T: Float> Obb<T> {
+    pub fn new(center: Vector3<T>, half_extents: Vector3<T>, rotation: Quaternion<T>) -> Self {
+        Self {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+
+    pub fn from_aabb(aabb: &Aabb<T>) -> Self {
+        Self::new(aabb.center(), aabb.half_extents(), Quaternion::identity())
+    }
+
+    /// The box's local X, Y and Z axes in world space.
+    pub fn axes(&self) -> [Vector3<T>; 3] {
+        let (zero, one) = (T::zero(), T::one());
+        [
+            self.rotation.rotate_vector(&Vector3::new(one, zero, zero)),
+            self.rotation.rotate_vector(&Vector3::new(zero, one, zero)),
+            self.rotation.rotate_vector(&Vector3::new(zero, zero, one)),
+        ]
+    }
+
+    pub fn to_local(&self, point: &Vector3<T>) -> Vector3<T> {
+        self.rotation
+            .conjugate()
+            .rotate_vector(&(*point - self.center))
+    }
+
+    pub fn contains_point(&self, point: &Vector3<T>) -> bool {
+        let local = self.to_local(point).abs();
+        local.x <= self.half_extents.x
+            && local.y <= self.half_extents.y
+            && local.z <= self.half_extents.z
+    }
+
+    pub fn closest_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        let local = self
+            .to_local(point)
+            .clamp(&-self.half_extents, &self.half_extents);
+        self.center + self.rotation.rotate_vector(&local)
+    }
+
+    pub fn bounding_aabb(&self) -> Aabb<T> {
+        let axes = self.axes();
+        let extent = |i: usize| {
+            axes[0][i].abs() * self.half_extents.x
+                + axes[1][i].abs() * self.half_extents.y
+                + axes[2][i].abs() * self.half_extents.z
+        };
+        let half = Vector3::new(extent(0), extent(1), extent(2));
+        Aabb::from_center_half_extents(self.center, half)
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        self.closest_point(&sphere.center).distance(&sphere.center) <= sphere.radius
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        self.intersects_obb(&Self::from_aabb(aabb))
+    }
+
+    /// Separating axis test over the 15 candidate axes.
+    pub fn intersects_obb(&self, other: &Self) -> bool {
+        let a = self.axes();
+        let b = other.axes();
+        let ea = self.half_extents;
+        let eb = other.half_extents;
+        let epsilon = T::epsilon();
+
+        // Rotation of `other` expressed in `self`'s frame, plus the absolute
+        // value padded to stay robust when edges are nearly parallel.
+        let mut r = [[T::zero(); 3]; 3];
+        let mut abs_r = [[T::zero(); 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = a[i].dot(&b[j]);
+                abs_r[i][j] = r[i][j].abs() + epsilon;
+            }
+        }
+
+        let offset = other.center - self.center;
+        let t = [offset.dot(&a[0]), offset.dot(&a[1]), offset.dot(&a[2])];
+
+        for i in 0..3 {
+            let ra = ea[i];
+            let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+            if t[i].abs() > ra + rb {
+                return false;
+            }
+        }
+
+        for j in 0..3 {
+            let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+            let rb = eb[j];
+            let projection = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if projection.abs() > ra + rb {
+                return false;
+            }
+        }
+
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                let projection = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if projection.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
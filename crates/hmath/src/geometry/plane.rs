@@ -0,0 +1,50 @@
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// The set of points `p` with `normal.dot(p) + d == 0`.
+///
+/// Points on the side the normal faces have a positive signed distance. The
+/// normal is expected to be unit length; planes built from raw coefficients
+/// should go through [`Plane::normalize`] first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane<T> {
+    pub normal: Vector3<T>,
+    pub d: T,
+}
+
+impl<T: Float> Plane<T> {
+    pub fn new(normal: Vector3<T>, d: T) -> Self {
+        Self { normal, d }
+    }
+
+    pub fn from_point_normal(point: &Vector3<T>, normal: &Vector3<T>) -> Self {
+        let normal = normal.normalize();
+        Self::new(normal, -normal.dot(point))
+    }
+
+    /// Builds the plane through three points, facing the side from which
+    /// they appear counter-clockwise.
+    pub fn from_points(a: &Vector3<T>, b: &Vector3<T>, c: &Vector3<T>) -> Self {
+        let normal = (*b - *a).cross(&(*c - *a));
+        Self::from_point_normal(a, &normal)
+    }
+
+    /// Rescales the coefficients so the normal has unit length.
+    pub fn normalize(&self) -> Self {
+        let length = self.normal.length();
+        if length == T::zero() {
+            *self
+        } else {
+            Self::new(self.normal / length, self.d / length)
+        }
+    }
+
+    #[inline]
+    pub fn signed_distance(&self, point: &Vector3<T>) -> T {
+        self.normal.dot(point) + self.d
+    }
+
+    pub fn closest_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        *point - self.normal * self.signed_distance(point)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::geometry::aabb::Aabb;
+use crate::geometry::obb::Obb;
+use crate::geometry::plane::Plane;
+use crate::geometry::sphere::Sphere;
+use crate::geometry::triangle::Triangle;
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// A half-line starting at `origin`.
+///
+/// `direction` does not have to be normalized; every distance returned by the
+/// intersection methods is a parameter `t` such that the hit point is
+/// `origin + direction * t`. Only hits with `t >= 0` are reported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray<T> {
+    pub origin: Vector3<T>,
+    pub direction: Vector3<T>,
+}
+
+impl<T: Float> Ray<T> {
+    pub fn new(origin: Vector3<T>, direction: Vector3<T>) -> Self {
+        Self { origin, direction }
+    }
+
+    #[inline]
+    pub fn at(&self, t: T) -> Vector3<T> {
+        self.origin + self.direction * t
+    }
+
+    /// Returns the parameter interval `(t_enter, t_exit)` over which the
+    /// infinite line lies inside the box, or `None` if it misses.
+    ///
+    /// `t_enter` is negative when the origin is inside the box.
+    pub fn aabb_interval(&self, aabb: &Aabb<T>) -> Option<(T, T)> {
+        let mut t_enter = T::neg_infinity();
+        let mut t_exit = T::infinity();
+
+        for axis in 0..3 {
+            let origin = self.origin[axis];
+            let direction = self.direction[axis];
+            if direction == T::zero() {
+                if origin < aabb.min[axis] || origin > aabb.max[axis] {
+                    return None;
+                }
+                continue;
+            }
+
+            let inv = T::one() / direction;
+            let mut t0 = (aabb.min[axis] - origin) * inv;
+            let mut t1 = (aabb.max[axis] - origin) * inv;
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            t_enter = t_enter.max(t0);
+            t_exit = t_exit.min(t1);
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+
+        Some((t_enter, t_exit))
+    }
+
+    pub fn intersect_aabb(&self, aabb: &Aabb<T>) -> Option<T> {
+        let (t_enter, t_exit) = self.aabb_interval(aabb)?;
+        if t_exit < T::zero() {
+            None
+        } else {
+            Some(t_enter.max(T::zero()))
+        }
+    }
+
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<T> {
+        let offset = self.origin - sphere.center;
+        let a = self.direction.norm_squared();
+        if a == T::zero() {
+            return None;
+        }
+        let half_b = offset.dot(&self.direction);
+        let c = offset.norm_squared() - sphere.radius * sphere.radius;
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < T::zero() {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let near = (-half_b - root) / a;
+        let far = (-half_b + root) / a;
+        if far < T::zero() {
+            None
+        } else {
+            Some(near.max(T::zero()))
+        }
+    }
+
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<T> {
+        let denominator = plane.normal.dot(&self.direction);
+        if denominator == T::zero() {
+            return None;
+        }
+        let t = -plane.signed_distance(&self.origin) / denominator;
+        if t < T::zero() {
+            None
+        } else {
+            Some(t)
+        }
+    }
+
+    /// Möller-Trumbore intersection; both faces of the triangle are hit.
+    ///
+    /// Rays parallel to the triangle's plane, within a tolerance relative to
+    /// the triangle's size, and degenerate triangles are never hit.
+    pub fn intersect_triangle(&self, triangle: &Triangle<T>) -> Option<T> {
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+        let p = self.direction.cross(&edge2);
+        let determinant = edge1.dot(&p);
+        // The determinant is |edge1| |p| times the cosine of their angle, so
+        // comparing that cosine keeps the test independent of scale.
+        if determinant.abs() <= T::epsilon() * edge1.length() * p.length() {
+            return None;
+        }
+
+        let inv_determinant = T::one() / determinant;
+        let s = self.origin - triangle.a;
+        let u = s.dot(&p) * inv_determinant;
+        if u < T::zero() || u > T::one() {
+            return None;
+        }
+
+        let q = s.cross(&edge1);
+        let v = self.direction.dot(&q) * inv_determinant;
+        if v < T::zero() || u + v > T::one() {
+            return None;
+        }
+
+        let t = edge2.dot(&q) * inv_determinant;
+        if t < T::zero() {
+            None
+        } else {
+            Some(t)
+        }
+    }
+
+    pub fn intersect_obb(&self, obb: &Obb<T>) -> Option<T> {
+        // In the box's local frame it is just an AABB around the origin.
+        let inverse = obb.rotation.conjugate();
+        let local = Ray::new(
+            inverse.rotate_vector(&(self.origin - obb.center)),
+            inverse.rotate_vector(&self.direction),
+        );
+        local.intersect_aabb(&Aabb::new(-obb.half_extents, obb.half_extents))
+    }
+}
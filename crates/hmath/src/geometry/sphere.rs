@@ -0,0 +1,49 @@
+use crate::geometry::aabb::Aabb;
+use crate::geometry::plane::Plane;
+use crate::vector::Vector3;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere<T> {
+    pub center: Vector3<T>,
+    pub radius: T,
+}
+
+impl<T: Float> Sphere<T> {
+    pub fn new(center: Vector3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn contains_point(&self, point: &Vector3<T>) -> bool {
+        (*point - self.center).norm_squared() <= self.radius * self.radius
+    }
+
+    pub fn intersects_sphere(&self, other: &Self) -> bool {
+        let radii = self.radius + other.radius;
+        (other.center - self.center).norm_squared() <= radii * radii
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        aabb.intersects_sphere(self)
+    }
+
+    /// Whether the sphere touches or straddles the plane.
+    pub fn intersects_plane(&self, plane: &Plane<T>) -> bool {
+        plane.signed_distance(&self.center).abs() <= self.radius
+    }
+
+    /// The closest point on or inside the sphere.
+    pub fn closest_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        let offset = *point - self.center;
+        if offset.norm_squared() <= self.radius * self.radius {
+            *point
+        } else {
+            self.center + offset.normalize() * self.radius
+        }
+    }
+
+    pub fn bounding_aabb(&self) -> Aabb<T> {
+        let r = Vector3::new(self.radius, self.radius, self.radius);
+        Aabb::from_center_half_extents(self.center, r)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::geometry::aabb::Aabb;
+use crate::vector::Vector3;
+use num_traits::Float;
+
+/// A triangle with counter-clockwise winding `a -> b -> c` seen from the
+/// side its normal faces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle<T> {
+    pub a: Vector3<T>,
+    pub b: Vector3<T>,
+    pub c: Vector3<T>,
+}
+
+impl<T: Float> Triangle<T> {
+    pub fn new(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Self { a, b, c }
+    }
+
+    pub fn normal(&self) -> Vector3<T> {
+        (self.b - self.a).cross(&(self.c - self.a)).normalize()
+    }
+
+    pub fn area(&self) -> T {
+        (self.b - self.a).cross(&(self.c - self.a)).length() / (T::one() + T::one())
+    }
+
+    pub fn bounding_aabb(&self) -> Aabb<T> {
+        Aabb::from_points(&[self.a, self.b, self.c])
+    }
+
+    /// Returns the barycentric weights `(u, v, w)` of `point` projected onto
+    /// the triangle's plane, so that `point ≈ a * u + b * v + c * w`, or
+    /// `None` if the triangle is degenerate.
+    pub fn barycentric(&self, point: &Vector3<T>) -> Option<Vector3<T>> {
+        let v0 = self.b - self.a;
+        let v1 = self.c - self.a;
+        let v2 = *point - self.a;
+        let d00 = v0.dot(&v0);
+        let d01 = v0.dot(&v1);
+        let d11 = v1.dot(&v1);
+        let d20 = v2.dot(&v0);
+        let d21 = v2.dot(&v1);
+        let denominator = d00 * d11 - d01 * d01;
+        if denominator == T::zero() {
+            return None;
+        }
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+        Some(Vector3::new(T::one() - v - w, v, w))
+    }
+
+    /// The closest point on the triangle, including its edges and vertices.
+    pub fn closest_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        // Voronoi region walk from Ericson, "Real-Time Collision Detection".
+        let (a, b, c, p) = (self.a, self.b, self.c, *point);
+        let ab = b - a;
+        let ac = c - a;
+        let ap = p - a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= T::zero() && d2 <= T::zero() {
+            return a;
+        }
+
+        let bp = p - b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= T::zero() && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= T::zero() && d1 >= T::zero() && d3 <= T::zero() {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = p - c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= T::zero() && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= T::zero() && d2 >= T::zero() && d6 <= T::zero() {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= T::zero() && (d4 - d3) >= T::zero() && (d5 - d6) >= T::zero() {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = T::one() / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+}
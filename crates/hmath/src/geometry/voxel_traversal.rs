@@ -0,0 +1,152 @@
+use crate::geometry::ray::Ray;
+use crate::vector::{Vector3, Vector3i};
+use num_traits::Float;
+
+/// A grid cell visited by a [`VoxelTraversal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VoxelHit<T> {
+    /// Integer coordinates of the cell.
+    pub cell: Vector3i,
+    /// Ray parameter at which the ray enters the cell; zero for the cell
+    /// containing the origin.
+    pub distance: T,
+    /// Outward normal of the face the ray entered through, or zero for the
+    /// cell containing the origin.
+    pub normal: Vector3i,
+}
+
+/// Walks every cell of a uniform grid pierced by a ray, in order, using the
+/// Amanatides-Woo DDA.
+///
+/// Cells are cubes of `cell_size` with cell `(0, 0, 0)` spanning
+/// `[0, cell_size)` on every axis. Iteration stops once the entry distance
+/// exceeds `max_distance`; callers looking for the first solid voxel simply
+/// stop pulling items when they find one. Rays with non-finite components,
+/// or starting outside the range of `i32` cell coordinates, visit no cells,
+/// and the walk ends where it would leave that range.
+#[derive(Debug, Clone)]
+pub struct VoxelTraversal<T> {
+    cell: Vector3i,
+    step: Vector3i,
+    t_max: Vector3<T>,
+    t_delta: Vector3<T>,
+    max_distance: T,
+    started: bool,
+    finished: bool,
+}
+
+impl<T: Float> VoxelTraversal<T> {
+    pub fn new(ray: &Ray<T>, cell_size: T, max_distance: T) -> Self {
+        let mut step = Vector3i::zero();
+        let mut t_max = Vector3::new(T::infinity(), T::infinity(), T::infinity());
+        let mut t_delta = t_max;
+
+        let scaled = ray.origin / cell_size;
+        // 2^31 is exact in every float type, unlike i32::MAX, which rounds
+        // up to it in f32.
+        let (min, end) = (T::from(i32::MIN).unwrap(), -T::from(i32::MIN).unwrap());
+        let valid = !max_distance.is_nan()
+            && (0..3).all(|axis| {
+                let coordinate = scaled[axis].floor();
+                coordinate.is_finite()
+                    && coordinate >= min
+                    && coordinate < end
+                    && ray.direction[axis].is_finite()
+            });
+        if !valid {
+            return Self {
+                cell: Vector3i::zero(),
+                step,
+                t_max,
+                t_delta,
+                max_distance,
+                started: true,
+                finished: true,
+            };
+        }
+        let cell: Vector3i = scaled.floor_cast();
+
+        for axis in 0..3 {
+            let direction = ray.direction[axis];
+            if direction == T::zero() {
+                continue;
+            }
+            let lower = T::from(cell[axis]).unwrap() * cell_size;
+            let boundary = if direction > T::zero() {
+                step[axis] = 1;
+                lower + cell_size
+            } else {
+                step[axis] = -1;
+                lower
+            };
+            t_max[axis] = (boundary - ray.origin[axis]) / direction;
+            t_delta[axis] = cell_size / direction.abs();
+        }
+
+        Self {
+            cell,
+            step,
+            t_max,
+            t_delta,
+            max_distance,
+            started: false,
+            finished: false,
+        }
+    }
+}
+
+impl<T: Float> Iterator for VoxelTraversal<T> {
+    type Item = VoxelHit<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(VoxelHit {
+                cell: self.cell,
+                distance: T::zero(),
+                normal: Vector3i::zero(),
+            });
+        }
+
+        // Ties go to the lowest axis so the walk is deterministic.
+        let mut axis = 0;
+        if self.t_max.y < self.t_max[axis] {
+            axis = 1;
+        }
+        if self.t_max.z < self.t_max[axis] {
+            axis = 2;
+        }
+
+        let distance = self.t_max[axis];
+        if !distance.is_finite() || distance > self.max_distance {
+            self.finished = true;
+            return None;
+        }
+
+        // Walking off the end of the i32 range ends the walk.
+        let Some(cell) = self.cell[axis].checked_add(self.step[axis]) else {
+            self.finished = true;
+            return None;
+        };
+        self.cell[axis] = cell;
+        self.t_max[axis] = self.t_max[axis] + self.t_delta[axis];
+        let mut normal = Vector3i::zero();
+        normal[axis] = -self.step[axis];
+
+        Some(VoxelHit {
+            cell: self.cell,
+            distance,
+            normal,
+        })
+    }
+}
+
+impl<T: Float> Ray<T> {
+    /// Shorthand for [`VoxelTraversal::new`].
+    pub fn voxel_traversal(&self, cell_size: T, max_distance: T) -> VoxelTraversal<T> {
+        VoxelTraversal::new(self, cell_size, max_distance)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::matrix::{Matrix2, Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector2, Vector3, Vector4};
+use glam::{
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, Quat,
+    UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+
+macro_rules! impl_vector {
+    ($hmath:ident<$scalar:ty>, $glam:ident, [$($field:ident),+]) => {
+        impl From<$hmath<$scalar>> for $glam {
+            fn from(v: $hmath<$scalar>) -> Self {
+                $glam::new($(v.$field),+)
+            }
+        }
+
+        impl From<$glam> for $hmath<$scalar> {
+            fn from(v: $glam) -> Self {
+                $hmath { $($field: v.$field),+ }
+            }
+        }
+    };
+}
+
+impl_vector!(Vector2<f32>, Vec2, [x, y]);
+impl_vector!(Vector3<f32>, Vec3, [x, y, z]);
+impl_vector!(Vector3<f32>, Vec3A, [x, y, z]);
+impl_vector!(Vector4<f32>, Vec4, [x, y, z, w]);
+impl_vector!(Vector2<f64>, DVec2, [x, y]);
+impl_vector!(Vector3<f64>, DVec3, [x, y, z]);
+impl_vector!(Vector4<f64>, DVec4, [x, y, z, w]);
+impl_vector!(Vector2<i32>, IVec2, [x, y]);
+impl_vector!(Vector3<i32>, IVec3, [x, y, z]);
+impl_vector!(Vector4<i32>, IVec4, [x, y, z, w]);
+impl_vector!(Vector2<u32>, UVec2, [x, y]);
+impl_vector!(Vector3<u32>, UVec3, [x, y, z]);
+impl_vector!(Vector4<u32>, UVec4, [x, y, z, w]);
+
+macro_rules! impl_quaternion {
+    ($scalar:ty, $glam:ident) => {
+        impl From<Quaternion<$scalar>> for $glam {
+            fn from(q: Quaternion<$scalar>) -> Self {
+                $glam::from_xyzw(q.x, q.y, q.z, q.w)
+            }
+        }
+
+        impl From<$glam> for Quaternion<$scalar> {
+            fn from(q: $glam) -> Self {
+                Quaternion {
+                    w: q.w,
+                    x: q.x,
+                    y: q.y,
+                    z: q.z,
+                }
+            }
+        }
+    };
+}
+
+impl_quaternion!(f32, Quat);
+impl_quaternion!(f64, DQuat);
+
+/// `data` is already the column-major storage of the transposed,
+/// column-vector matrix glam expects.
+macro_rules! impl_matrix {
+    ($hmath:ident<$scalar:ty>, $glam:ident) => {
+        impl From<$hmath<$scalar>> for $glam {
+            fn from(m: $hmath<$scalar>) -> Self {
+                $glam::from_cols_array(&m.data)
+            }
+        }
+
+        impl From<$glam> for $hmath<$scalar> {
+            fn from(m: $glam) -> Self {
+                $hmath {
+                    data: m.to_cols_array(),
+                }
+            }
+        }
+    };
+}
+
+impl_matrix!(Matrix2<f32>, Mat2);
+impl_matrix!(Matrix3<f32>, Mat3);
+impl_matrix!(Matrix4<f32>, Mat4);
+impl_matrix!(Matrix2<f64>, DMat2);
+impl_matrix!(Matrix3<f64>, DMat3);
+impl_matrix!(Matrix4<f64>, DMat4);
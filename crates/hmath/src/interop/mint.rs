@@ -0,0 +1,104 @@
+use crate::matrix::{Matrix2, Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector2, Vector3, Vector4};
+use mint::IntoMint;
+
+macro_rules! impl_vector {
+    ($hmath:ident, $mint:ident, [$($field:ident),+]) => {
+        impl<T> From<$hmath<T>> for mint::$mint<T> {
+            fn from(v: $hmath<T>) -> Self {
+                mint::$mint { $($field: v.$field),+ }
+            }
+        }
+
+        impl<T> From<mint::$mint<T>> for $hmath<T> {
+            fn from(v: mint::$mint<T>) -> Self {
+                $hmath { $($field: v.$field),+ }
+            }
+        }
+    };
+}
+
+impl_vector!(Vector2, Vector2, [x, y]);
+impl_vector!(Vector3, Vector3, [x, y, z]);
+impl_vector!(Vector4, Vector4, [x, y, z, w]);
+impl_vector!(Vector2, Point2, [x, y]);
+impl_vector!(Vector3, Point3, [x, y, z]);
+
+impl<T> IntoMint for Vector2<T> {
+    type MintType = mint::Vector2<T>;
+}
+
+impl<T> IntoMint for Vector3<T> {
+    type MintType = mint::Vector3<T>;
+}
+
+impl<T> IntoMint for Vector4<T> {
+    type MintType = mint::Vector4<T>;
+}
+
+impl<T> From<Quaternion<T>> for mint::Quaternion<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        mint::Quaternion {
+            v: mint::Vector3 {
+                x: q.x,
+                y: q.y,
+                z: q.z,
+            },
+            s: q.w,
+        }
+    }
+}
+
+impl<T> From<mint::Quaternion<T>> for Quaternion<T> {
+    fn from(q: mint::Quaternion<T>) -> Self {
+        Quaternion {
+            w: q.s,
+            x: q.v.x,
+            y: q.v.y,
+            z: q.v.z,
+        }
+    }
+}
+
+impl<T> IntoMint for Quaternion<T> {
+    type MintType = mint::Quaternion<T>;
+}
+
+/// mint matrices describe the column-vector form, so `data` is the flat
+/// storage of the column matrix and the row matrix is its transpose.
+macro_rules! impl_matrix {
+    ($hmath:ident, $column:ident, $row:ident) => {
+        impl<T: Clone> From<$hmath<T>> for mint::$column<T> {
+            fn from(m: $hmath<T>) -> Self {
+                mint::$column::from(m.data)
+            }
+        }
+
+        impl<T> From<mint::$column<T>> for $hmath<T> {
+            fn from(m: mint::$column<T>) -> Self {
+                $hmath { data: m.into() }
+            }
+        }
+
+        impl<T: Clone> From<$hmath<T>> for mint::$row<T> {
+            fn from(m: $hmath<T>) -> Self {
+                mint::$column::from(m.data).into()
+            }
+        }
+
+        impl<T> From<mint::$row<T>> for $hmath<T> {
+            fn from(m: mint::$row<T>) -> Self {
+                mint::$column::from(m).into()
+            }
+        }
+
+        impl<T: Clone> IntoMint for $hmath<T> {
+            type MintType = mint::$column<T>;
+        }
+    };
+}
+
+impl_matrix!(Matrix2, ColumnMatrix2, RowMatrix2);
+impl_matrix!(Matrix3, ColumnMatrix3, RowMatrix3);
+impl_matrix!(Matrix4, ColumnMatrix4, RowMatrix4);
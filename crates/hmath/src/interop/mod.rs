@@ -0,0 +1,15 @@
+//! Feature-gated conversions to and from other math crates.
+//!
+//! hmath matrices use row vectors with the translation in `data[12..15]`,
+//! while glam, nalgebra and mint's column matrices use column vectors. The
+//! two conventions describe transposed matrices with identical memory, so
+//! `data` maps straight onto their column-major storage and the converted
+//! matrix transforms points the same way. Products reverse, though: hmath's
+//! `a * b` becomes `b * a` on the other side.
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
@@ -0,0 +1,95 @@
+use crate::matrix::{Matrix2, Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector2, Vector3, Vector4};
+use nalgebra::{RealField, Scalar, UnitQuaternion};
+
+macro_rules! impl_vector {
+    ($hmath:ident, [$($field:ident),+]) => {
+        impl<T: Scalar> From<$hmath<T>> for nalgebra::$hmath<T> {
+            fn from(v: $hmath<T>) -> Self {
+                nalgebra::$hmath::new($(v.$field),+)
+            }
+        }
+
+        impl<T: Scalar> From<nalgebra::$hmath<T>> for $hmath<T> {
+            fn from(v: nalgebra::$hmath<T>) -> Self {
+                let [$($field),+] = v.into();
+                $hmath { $($field),+ }
+            }
+        }
+    };
+}
+
+impl_vector!(Vector2, [x, y]);
+impl_vector!(Vector3, [x, y, z]);
+impl_vector!(Vector4, [x, y, z, w]);
+
+macro_rules! impl_point {
+    ($hmath:ident, $point:ident, [$($field:ident),+]) => {
+        impl<T: Scalar> From<$hmath<T>> for nalgebra::$point<T> {
+            fn from(v: $hmath<T>) -> Self {
+                nalgebra::$point::new($(v.$field),+)
+            }
+        }
+
+        impl<T: Scalar> From<nalgebra::$point<T>> for $hmath<T> {
+            fn from(p: nalgebra::$point<T>) -> Self {
+                p.coords.into()
+            }
+        }
+    };
+}
+
+impl_point!(Vector2, Point2, [x, y]);
+impl_point!(Vector3, Point3, [x, y, z]);
+
+impl<T: Scalar> From<Quaternion<T>> for nalgebra::Quaternion<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        nalgebra::Quaternion::from_vector(nalgebra::Vector4::new(q.x, q.y, q.z, q.w))
+    }
+}
+
+impl<T: Scalar> From<nalgebra::Quaternion<T>> for Quaternion<T> {
+    fn from(q: nalgebra::Quaternion<T>) -> Self {
+        // nalgebra stores the vector part first.
+        let [x, y, z, w] = q.coords.into();
+        Quaternion { w, x, y, z }
+    }
+}
+
+/// Normalizes, since a unit quaternion must have unit length.
+impl<T: Scalar + RealField> From<Quaternion<T>> for UnitQuaternion<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        UnitQuaternion::new_normalize(q.into())
+    }
+}
+
+impl<T: Scalar> From<UnitQuaternion<T>> for Quaternion<T> {
+    fn from(q: UnitQuaternion<T>) -> Self {
+        q.into_inner().into()
+    }
+}
+
+/// `data` is already the column-major storage of the transposed,
+/// column-vector matrix nalgebra expects.
+macro_rules! impl_matrix {
+    ($hmath:ident, $len:literal) => {
+        impl<T: Scalar> From<$hmath<T>> for nalgebra::$hmath<T> {
+            fn from(m: $hmath<T>) -> Self {
+                nalgebra::$hmath::from_column_slice(&m.data)
+            }
+        }
+
+        impl<T: Scalar + Copy> From<nalgebra::$hmath<T>> for $hmath<T> {
+            fn from(m: nalgebra::$hmath<T>) -> Self {
+                let mut data = [m[0]; $len];
+                data.copy_from_slice(m.as_slice());
+                $hmath { data }
+            }
+        }
+    };
+}
+
+impl_matrix!(Matrix2, 4);
+impl_matrix!(Matrix3, 9);
+impl_matrix!(Matrix4, 16);
@@ -1,3 +1,15 @@
+pub mod color;
+pub mod curve;
+pub mod fixed;
+pub mod geometry;
+mod interop;
 pub mod matrix;
+pub mod noise;
 pub mod quaternion;
+pub mod sampling;
+#[cfg(feature = "serde")]
+mod serde_impl;
+pub mod simd;
+pub mod space_filling;
+pub mod transform;
 pub mod vector;
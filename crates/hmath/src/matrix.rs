@@ -1,57 +1,619 @@
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector2, Vector3, Vector4};
+use num_traits::Float;
 use std::ops::Mul;
 
+/// A 2x2 matrix stored as four contiguous scalars, using the same row-vector
+/// convention as [`Matrix4`].
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[repr(C)]
-pub struct Matrix4x4 {
-    pub data: [f32; 16],
+pub struct Matrix2<T> {
+    pub data: [T; 4],
 }
 
-unsafe impl bytemuck::Pod for Matrix4x4 {}
-unsafe impl bytemuck::Zeroable for Matrix4x4 {}
+/// A 3x3 matrix stored as nine contiguous scalars, using the same row-vector
+/// convention as [`Matrix4`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct Matrix3<T> {
+    pub data: [T; 9],
+}
+
+/// A 4x4 matrix stored as sixteen contiguous scalars.
+///
+/// Vectors are treated as rows (`v' = v * M`), so the translation lives in
+/// `data[12..15]` and `a * b` applies `a` first and then `b`. This memory
+/// layout is the same one GLSL expects for a column-major `mat4`, which lets
+/// a [`Matrix4x4`] be uploaded to the GPU as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct Matrix4<T> {
+    pub data: [T; 16],
+}
+
+pub type Matrix2f = Matrix2<f32>;
+pub type Matrix2d = Matrix2<f64>;
+pub type Matrix3f = Matrix3<f32>;
+pub type Matrix3d = Matrix3<f64>;
+pub type Matrix4f = Matrix4<f32>;
+pub type Matrix4d = Matrix4<f64>;
+
+/// The single-precision matrix handed to the GPU.
+pub type Matrix4x4 = Matrix4<f32>;
+
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Matrix2<T> {}
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Matrix2<T> {}
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Matrix3<T> {}
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Matrix3<T> {}
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Matrix4<T> {}
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Matrix4<T> {}
 
-impl Matrix4x4 {
-    pub fn new(data: [f32; 16]) -> Self {
-        Matrix4x4 { data }
+impl<T: Float> Matrix2<T> {
+    pub fn new(data: [T; 4]) -> Self {
+        Matrix2 { data }
+    }
+
+    pub fn zero() -> Self {
+        Matrix2 {
+            data: [T::zero(); 4],
+        }
     }
 
     pub fn identity() -> Self {
-        Matrix4x4 {
+        let mut result = Self::zero();
+        result.data[0] = T::one();
+        result.data[3] = T::one();
+        result
+    }
+
+    /// Builds a counter-clockwise rotation by `angle` radians.
+    pub fn from_angle(angle: T) -> Self {
+        let (s, c) = angle.sin_cos();
+        Matrix2 {
+            data: [c, s, -s, c],
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let d = &self.data;
+        Matrix2 {
+            data: [d[0], d[2], d[1], d[3]],
+        }
+    }
+
+    pub fn multiply(&self, other: &Self) -> Self {
+        let mut result = [T::zero(); 4];
+        for i in 0..2 {
+            for j in 0..2 {
+                for k in 0..2 {
+                    result[i * 2 + j] =
+                        result[i * 2 + j] + self.data[i * 2 + k] * other.data[k * 2 + j];
+                }
+            }
+        }
+        Matrix2 { data: result }
+    }
+
+    pub fn determinant(&self) -> T {
+        let d = &self.data;
+        d[0] * d[3] - d[1] * d[2]
+    }
+
+    /// Returns the inverse, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+        let d = &self.data;
+        let inv_det = T::one() / det;
+        Some(Matrix2 {
             data: [
-                1.0, 0.0, 0.0, 0.0,
-                0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, 1.0, 0.0,
-                0.0, 0.0, 0.0, 1.0,
+                d[3] * inv_det,
+                -d[1] * inv_det,
+                -d[2] * inv_det,
+                d[0] * inv_det,
             ],
+        })
+    }
+
+    pub fn transform_vector(&self, vector: &Vector2<T>) -> Vector2<T> {
+        let m = &self.data;
+        Vector2::new(
+            vector.x * m[0] + vector.y * m[2],
+            vector.x * m[1] + vector.y * m[3],
+        )
+    }
+
+    /// Converts every element to another scalar type.
+    pub fn cast<U: Float>(&self) -> Matrix2<U> {
+        Matrix2 {
+            data: self.data.map(|v| U::from(v).unwrap()),
+        }
+    }
+}
+
+impl<T: Float> Matrix3<T> {
+    pub fn new(data: [T; 9]) -> Self {
+        Matrix3 { data }
+    }
+
+    pub fn zero() -> Self {
+        Matrix3 {
+            data: [T::zero(); 9],
+        }
+    }
+
+    pub fn identity() -> Self {
+        let mut result = Self::zero();
+        result.data[0] = T::one();
+        result.data[4] = T::one();
+        result.data[8] = T::one();
+        result
+    }
+
+    pub fn from_scale(scale: &Vector3<T>) -> Self {
+        let mut result = Self::zero();
+        result.data[0] = scale.x;
+        result.data[4] = scale.y;
+        result.data[8] = scale.z;
+        result
+    }
+
+    /// Builds a rotation matrix from a unit quaternion.
+    pub fn from_rotation(rotation: &Quaternion<T>) -> Self {
+        let Quaternion { w, x, y, z } = *rotation;
+        let one = T::one();
+        let two = one + one;
+
+        let xx = x * x;
+        let yy = y * y;
+        let zz = z * z;
+        let xy = x * y;
+        let xz = x * z;
+        let yz = y * z;
+        let wx = w * x;
+        let wy = w * y;
+        let wz = w * z;
+
+        // Each row is the image of the corresponding basis vector.
+        Matrix3 {
+            data: [
+                one - two * (yy + zz),
+                two * (xy + wz),
+                two * (xz - wy),
+                two * (xy - wz),
+                one - two * (xx + zz),
+                two * (yz + wx),
+                two * (xz + wy),
+                two * (yz - wx),
+                one - two * (xx + yy),
+            ],
+        }
+    }
+
+    /// Takes the upper-left 3x3 block of a 4x4 matrix.
+    pub fn from_matrix4(matrix: &Matrix4<T>) -> Self {
+        let d = &matrix.data;
+        Matrix3 {
+            data: [d[0], d[1], d[2], d[4], d[5], d[6], d[8], d[9], d[10]],
         }
     }
 
     pub fn transpose(&self) -> Self {
-        let mut transposed = [0.0; 16];
+        let mut transposed = [T::zero(); 9];
+        for i in 0..3 {
+            for j in 0..3 {
+                transposed[i * 3 + j] = self.data[j * 3 + i];
+            }
+        }
+        Matrix3 { data: transposed }
+    }
+
+    pub fn multiply(&self, other: &Self) -> Self {
+        let mut result = [T::zero(); 9];
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    result[i * 3 + j] =
+                        result[i * 3 + j] + self.data[i * 3 + k] * other.data[k * 3 + j];
+                }
+            }
+        }
+        Matrix3 { data: result }
+    }
+
+    pub fn determinant(&self) -> T {
+        let m = &self.data;
+        m[0] * (m[4] * m[8] - m[5] * m[7]) - m[1] * (m[3] * m[8] - m[5] * m[6])
+            + m[2] * (m[3] * m[7] - m[4] * m[6])
+    }
+
+    /// Returns the inverse, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+        let m = &self.data;
+        let inv_det = T::one() / det;
+        Some(Matrix3 {
+            data: [
+                (m[4] * m[8] - m[5] * m[7]) * inv_det,
+                (m[2] * m[7] - m[1] * m[8]) * inv_det,
+                (m[1] * m[5] - m[2] * m[4]) * inv_det,
+                (m[5] * m[6] - m[3] * m[8]) * inv_det,
+                (m[0] * m[8] - m[2] * m[6]) * inv_det,
+                (m[2] * m[3] - m[0] * m[5]) * inv_det,
+                (m[3] * m[7] - m[4] * m[6]) * inv_det,
+                (m[1] * m[6] - m[0] * m[7]) * inv_det,
+                (m[0] * m[4] - m[1] * m[3]) * inv_det,
+            ],
+        })
+    }
+
+    pub fn transform_vector(&self, vector: &Vector3<T>) -> Vector3<T> {
+        let m = &self.data;
+        Vector3::new(
+            vector.x * m[0] + vector.y * m[3] + vector.z * m[6],
+            vector.x * m[1] + vector.y * m[4] + vector.z * m[7],
+            vector.x * m[2] + vector.y * m[5] + vector.z * m[8],
+        )
+    }
+
+    /// Converts every element to another scalar type.
+    pub fn cast<U: Float>(&self) -> Matrix3<U> {
+        Matrix3 {
+            data: self.data.map(|v| U::from(v).unwrap()),
+        }
+    }
+}
+
+impl<T: Float> Matrix4<T> {
+    pub fn new(data: [T; 16]) -> Self {
+        Matrix4 { data }
+    }
+
+    pub fn zero() -> Self {
+        Matrix4 {
+            data: [T::zero(); 16],
+        }
+    }
+
+    pub fn identity() -> Self {
+        let mut result = Self::zero();
+        result.data[0] = T::one();
+        result.data[5] = T::one();
+        result.data[10] = T::one();
+        result.data[15] = T::one();
+        result
+    }
+
+    pub fn from_translation(translation: &Vector3<T>) -> Self {
+        let mut result = Self::identity();
+        result.data[12] = translation.x;
+        result.data[13] = translation.y;
+        result.data[14] = translation.z;
+        result
+    }
+
+    pub fn from_scale(scale: &Vector3<T>) -> Self {
+        let mut result = Self::identity();
+        result.data[0] = scale.x;
+        result.data[5] = scale.y;
+        result.data[10] = scale.z;
+        result
+    }
+
+    /// Builds a rotation matrix from a unit quaternion.
+    pub fn from_rotation(rotation: &Quaternion<T>) -> Self {
+        Self::from_matrix3(&Matrix3::from_rotation(rotation))
+    }
+
+    /// Embeds a 3x3 matrix in the upper-left block of an identity matrix.
+    pub fn from_matrix3(matrix: &Matrix3<T>) -> Self {
+        let mut result = Self::identity();
+        for i in 0..3 {
+            for j in 0..3 {
+                result.data[i * 4 + j] = matrix.data[i * 3 + j];
+            }
+        }
+        result
+    }
+
+    /// Composes scale, then rotation, then translation into a single matrix.
+    pub fn from_trs(
+        translation: &Vector3<T>,
+        rotation: &Quaternion<T>,
+        scale: &Vector3<T>,
+    ) -> Self {
+        let mut result = Self::from_rotation(rotation);
+        for i in 0..3 {
+            result.data[i] = result.data[i] * scale.x;
+            result.data[4 + i] = result.data[4 + i] * scale.y;
+            result.data[8 + i] = result.data[8 + i] * scale.z;
+        }
+        result.data[12] = translation.x;
+        result.data[13] = translation.y;
+        result.data[14] = translation.z;
+        result
+    }
+
+    /// Splits an affine matrix back into translation, rotation and scale.
+    ///
+    /// A negative determinant is folded into the x scale. Shear cannot be
+    /// represented and is silently discarded.
+    pub fn to_trs(&self) -> (Vector3<T>, Quaternion<T>, Vector3<T>) {
+        let d = &self.data;
+        let translation = Vector3::new(d[12], d[13], d[14]);
+
+        let mut scale = Vector3::new(
+            Vector3::new(d[0], d[1], d[2]).length(),
+            Vector3::new(d[4], d[5], d[6]).length(),
+            Vector3::new(d[8], d[9], d[10]).length(),
+        );
+        if Matrix3::from_matrix4(self).determinant() < T::zero() {
+            scale.x = -scale.x;
+        }
+
+        let inv = |s: T| {
+            if s == T::zero() {
+                T::zero()
+            } else {
+                T::one() / s
+            }
+        };
+        let (ix, iy, iz) = (inv(scale.x), inv(scale.y), inv(scale.z));
+        let rotation = Matrix3::new([
+            d[0] * ix,
+            d[1] * ix,
+            d[2] * ix,
+            d[4] * iy,
+            d[5] * iy,
+            d[6] * iy,
+            d[8] * iz,
+            d[9] * iz,
+            d[10] * iz,
+        ]);
+
+        (
+            translation,
+            Quaternion::from_rotation_matrix(&rotation),
+            scale,
+        )
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut transposed = [T::zero(); 16];
         for i in 0..4 {
             for j in 0..4 {
                 transposed[i * 4 + j] = self.data[j * 4 + i];
             }
         }
-        Matrix4x4 { data: transposed }
+        Matrix4 { data: transposed }
     }
 
-    pub fn multiply(&self, other: &Matrix4x4) -> Self {
-        let mut result = [0.0; 16];
+    /// See [`crate::simd`] for vectorized `f32` versions of this and
+    /// [`Matrix4::inverse`].
+    pub fn multiply(&self, other: &Self) -> Self {
+        let mut result = [T::zero(); 16];
         for i in 0..4 {
             for j in 0..4 {
                 for k in 0..4 {
-                    result[i * 4 + j] += self.data[i * 4 + k] * other.data[k * 4 + j];
+                    result[i * 4 + j] =
+                        result[i * 4 + j] + self.data[i * 4 + k] * other.data[k * 4 + j];
                 }
             }
         }
-        Matrix4x4 { data: result }
+        Matrix4 { data: result }
+    }
+
+    pub fn determinant(&self) -> T {
+        let cofactors = self.cofactor_row();
+        let m = &self.data;
+        m[0] * cofactors[0] + m[1] * cofactors[1] + m[2] * cofactors[2] + m[3] * cofactors[3]
+    }
+
+    /// Returns the general inverse, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.data;
+        let mut inv = [T::zero(); 16];
+
+        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14]
+            + m[13] * m[6] * m[11]
+            - m[13] * m[7] * m[10];
+        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14]
+            - m[12] * m[6] * m[11]
+            + m[12] * m[7] * m[10];
+        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13]
+            + m[12] * m[5] * m[11]
+            - m[12] * m[7] * m[9];
+        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13]
+            - m[12] * m[5] * m[10]
+            + m[12] * m[6] * m[9];
+        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14]
+            - m[13] * m[2] * m[11]
+            + m[13] * m[3] * m[10];
+        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14]
+            + m[12] * m[2] * m[11]
+            - m[12] * m[3] * m[10];
+        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13]
+            - m[12] * m[1] * m[11]
+            + m[12] * m[3] * m[9];
+        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13]
+            + m[12] * m[1] * m[10]
+            - m[12] * m[2] * m[9];
+        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14]
+            + m[13] * m[2] * m[7]
+            - m[13] * m[3] * m[6];
+        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14]
+            - m[12] * m[2] * m[7]
+            + m[12] * m[3] * m[6];
+        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13]
+            + m[12] * m[1] * m[7]
+            - m[12] * m[3] * m[5];
+        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13]
+            - m[12] * m[1] * m[6]
+            + m[12] * m[2] * m[5];
+        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10]
+            - m[9] * m[2] * m[7]
+            + m[9] * m[3] * m[6];
+        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10]
+            + m[8] * m[2] * m[7]
+            - m[8] * m[3] * m[6];
+        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9]
+            - m[8] * m[1] * m[7]
+            + m[8] * m[3] * m[5];
+        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9]
+            + m[8] * m[1] * m[6]
+            - m[8] * m[2] * m[5];
+
+        let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+        if det == T::zero() {
+            return None;
+        }
+
+        let inv_det = T::one() / det;
+        Some(Matrix4 {
+            data: inv.map(|v| v * inv_det),
+        })
+    }
+
+    /// Inverts a matrix whose last column is `(0, 0, 0, 1)`.
+    ///
+    /// This is considerably cheaper than [`Matrix4::inverse`] and is the
+    /// right choice for model and view matrices. Projection matrices must go
+    /// through the general inverse instead.
+    pub fn inverse_affine(&self) -> Option<Self> {
+        let a = Matrix3::from_matrix4(self).inverse()?;
+        let mut result = Self::from_matrix3(&a);
+        let t = a.transform_vector(&Vector3::new(self.data[12], self.data[13], self.data[14]));
+        result.data[12] = -t.x;
+        result.data[13] = -t.y;
+        result.data[14] = -t.z;
+        Some(result)
+    }
+
+    /// Transforms a point, applying translation and ignoring the projective row.
+    pub fn transform_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        let m = &self.data;
+        Vector3::new(
+            point.x * m[0] + point.y * m[4] + point.z * m[8] + m[12],
+            point.x * m[1] + point.y * m[5] + point.z * m[9] + m[13],
+            point.x * m[2] + point.y * m[6] + point.z * m[10] + m[14],
+        )
+    }
+
+    /// Transforms a point and divides by the resulting `w`.
+    ///
+    /// Use this with projection and inverse view-projection matrices, e.g. to
+    /// unproject a mouse position from normalized device coordinates.
+    pub fn project_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        let m = &self.data;
+        let w = point.x * m[3] + point.y * m[7] + point.z * m[11] + m[15];
+        self.transform_point(point) / w
+    }
+
+    /// Transforms a direction, ignoring translation.
+    pub fn transform_vector(&self, vector: &Vector3<T>) -> Vector3<T> {
+        let m = &self.data;
+        Vector3::new(
+            vector.x * m[0] + vector.y * m[4] + vector.z * m[8],
+            vector.x * m[1] + vector.y * m[5] + vector.z * m[9],
+            vector.x * m[2] + vector.y * m[6] + vector.z * m[10],
+        )
+    }
+
+    /// Transforms a homogeneous vector without any division by `w`.
+    pub fn transform(&self, vector: &Vector4<T>) -> Vector4<T> {
+        let m = &self.data;
+        let column = |j: usize| {
+            vector.x * m[j] + vector.y * m[4 + j] + vector.z * m[8 + j] + vector.w * m[12 + j]
+        };
+        Vector4::new(column(0), column(1), column(2), column(3))
+    }
+
+    /// Converts every element to another scalar type.
+    ///
+    /// Narrowing from `f64` to `f32` rounds each element independently; keep
+    /// large translations out of the matrix before casting if precision matters.
+    pub fn cast<U: Float>(&self) -> Matrix4<U> {
+        Matrix4 {
+            data: self.data.map(|v| U::from(v).unwrap()),
+        }
+    }
+
+    fn cofactor_row(&self) -> [T; 4] {
+        let m = &self.data;
+        let minor = |a: usize, b: usize, c: usize| {
+            let (r1, r2, r3) = (4, 8, 12);
+            m[r1 + a] * (m[r2 + b] * m[r3 + c] - m[r2 + c] * m[r3 + b])
+                - m[r1 + b] * (m[r2 + a] * m[r3 + c] - m[r2 + c] * m[r3 + a])
+                + m[r1 + c] * (m[r2 + a] * m[r3 + b] - m[r2 + b] * m[r3 + a])
+        };
+        [
+            minor(1, 2, 3),
+            -minor(0, 2, 3),
+            minor(0, 1, 3),
+            -minor(0, 1, 2),
+        ]
+    }
+}
+
+impl From<Matrix2<f32>> for Matrix2<f64> {
+    fn from(matrix: Matrix2<f32>) -> Self {
+        matrix.cast()
+    }
+}
+
+impl From<Matrix3<f32>> for Matrix3<f64> {
+    fn from(matrix: Matrix3<f32>) -> Self {
+        matrix.cast()
     }
 }
 
-impl Mul for Matrix4x4 {
+impl From<Matrix4<f32>> for Matrix4<f64> {
+    fn from(matrix: Matrix4<f32>) -> Self {
+        matrix.cast()
+    }
+}
+
+impl<T: Float> Mul for Matrix2<T> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self::Output {
         self.multiply(&other)
     }
-}
\ No newline at end of file
+}
+
+impl<T: Float> Mul for Matrix3<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.multiply(&other)
+    }
+}
+
+impl<T: Float> Mul for Matrix4<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.multiply(&other)
+    }
+}
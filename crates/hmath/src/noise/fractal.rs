@@ -0,0 +1,166 @@
+use crate::noise::Noise;
+use crate::vector::{Vector2d, Vector3d, Vector4d};
+
+/// Octave settings shared by the fractal combinators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FractalConfig {
+    pub octaves: u32,
+    /// Frequency of the first octave.
+    pub frequency: f64,
+    /// Frequency multiplier between successive octaves.
+    pub lacunarity: f64,
+    /// Amplitude multiplier between successive octaves.
+    pub gain: f64,
+}
+
+impl Default for FractalConfig {
+    fn default() -> Self {
+        Self {
+            octaves: 6,
+            frequency: 1.0,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+}
+
+// Shifts each octave so the lattice zeros of one octave do not line up with
+// those of the next.
+const OCTAVE_OFFSET: f64 = 19.191_919;
+
+/// Sums `octaves` shaped samples and normalizes by the total amplitude, so a
+/// source in `[-1, 1]` produces output in `[-1, 1]`.
+fn accumulate(config: &FractalConfig, mut sample: impl FnMut(f64, f64) -> f64) -> f64 {
+    let mut frequency = config.frequency;
+    let mut amplitude = 1.0;
+    let mut sum = 0.0;
+    let mut total = 0.0;
+    for octave in 0..config.octaves {
+        sum += amplitude * sample(frequency, octave as f64 * OCTAVE_OFFSET);
+        total += amplitude;
+        frequency *= config.lacunarity;
+        amplitude *= config.gain;
+    }
+    if total > 0.0 {
+        sum / total
+    } else {
+        0.0
+    }
+}
+
+macro_rules! impl_fractal {
+    ($name:ident, $shape:expr) => {
+        impl<N> $name<N> {
+            pub fn new(source: N, config: FractalConfig) -> Self {
+                Self { source, config }
+            }
+        }
+
+        impl<N: Noise> Noise for $name<N> {
+            fn sample2(&self, point: &Vector2d) -> f64 {
+                accumulate(&self.config, |frequency, offset| {
+                    let p = *point * frequency + Vector2d::new(offset, offset);
+                    $shape(self.source.sample2(&p))
+                })
+            }
+
+            fn sample3(&self, point: &Vector3d) -> f64 {
+                accumulate(&self.config, |frequency, offset| {
+                    let p = *point * frequency + Vector3d::new(offset, offset, offset);
+                    $shape(self.source.sample3(&p))
+                })
+            }
+
+            fn sample4(&self, point: &Vector4d) -> f64 {
+                accumulate(&self.config, |frequency, offset| {
+                    let p = *point * frequency + Vector4d::new(offset, offset, offset, offset);
+                    $shape(self.source.sample4(&p))
+                })
+            }
+        }
+    };
+}
+
+/// Fractional Brownian motion: octaves of the source added together.
+#[derive(Debug, Clone)]
+pub struct Fbm<N> {
+    pub source: N,
+    pub config: FractalConfig,
+}
+
+/// Ridged multifractal: sharp crests where the source crosses zero.
+#[derive(Debug, Clone)]
+pub struct Ridged<N> {
+    pub source: N,
+    pub config: FractalConfig,
+}
+
+/// Billow noise: rounded lobes from the absolute value of the source.
+#[derive(Debug, Clone)]
+pub struct Billow<N> {
+    pub source: N,
+    pub config: FractalConfig,
+}
+
+impl_fractal!(Fbm, |n: f64| n);
+impl_fractal!(Ridged, |n: f64| {
+    let ridge = 1.0 - n.abs();
+    ridge * ridge * 2.0 - 1.0
+});
+impl_fractal!(Billow, |n: f64| n.abs() * 2.0 - 1.0);
+
+/// Offsets the input of `source` by a displacement read from `warp`.
+///
+/// Each displacement component samples `warp` at a different fixed offset so
+/// the components are uncorrelated.
+#[derive(Debug, Clone)]
+pub struct DomainWarp<N, W> {
+    pub source: N,
+    pub warp: W,
+    /// Displacement, in input units, for a warp sample of 1.
+    pub strength: f64,
+}
+
+const WARP_OFFSETS: [f64; 4] = [0.0, 31.416, 57.721, 86.602];
+
+impl<N, W> DomainWarp<N, W> {
+    pub fn new(source: N, warp: W, strength: f64) -> Self {
+        Self {
+            source,
+            warp,
+            strength,
+        }
+    }
+}
+
+impl<N: Noise, W: Noise> Noise for DomainWarp<N, W> {
+    fn sample2(&self, point: &Vector2d) -> f64 {
+        let offset = |o: f64| self.warp.sample2(&(*point + Vector2d::new(o, o)));
+        let displacement = Vector2d::new(offset(WARP_OFFSETS[0]), offset(WARP_OFFSETS[1]));
+        self.source
+            .sample2(&(*point + displacement * self.strength))
+    }
+
+    fn sample3(&self, point: &Vector3d) -> f64 {
+        let offset = |o: f64| self.warp.sample3(&(*point + Vector3d::new(o, o, o)));
+        let displacement = Vector3d::new(
+            offset(WARP_OFFSETS[0]),
+            offset(WARP_OFFSETS[1]),
+            offset(WARP_OFFSETS[2]),
+        );
+        self.source
+            .sample3(&(*point + displacement * self.strength))
+    }
+
+    fn sample4(&self, point: &Vector4d) -> f64 {
+        let offset = |o: f64| self.warp.sample4(&(*point + Vector4d::new(o, o, o, o)));
+        let displacement = Vector4d::new(
+            offset(WARP_OFFSETS[0]),
+            offset(WARP_OFFSETS[1]),
+            offset(WARP_OFFSETS[2]),
+            offset(WARP_OFFSETS[3]),
+        );
+        self.source
+            .sample4(&(*point + displacement * self.strength))
+    }
+}
@@ -0,0 +1,61 @@
+pub mod fractal;
+pub mod open_simplex;
+pub mod perlin;
+pub mod permutation;
+pub mod value;
+pub mod worley;
+
+use crate::vector::{Vector2d, Vector3d, Vector4d};
+
+/// A deterministic scalar field that can be sampled in two, three and four
+/// dimensions.
+///
+/// Implementations only use operations that IEEE 754 specifies exactly
+/// (no `sin`, `cos` or `exp`), so the same seed yields bit-identical output
+/// on every platform.
+pub trait Noise {
+    fn sample2(&self, point: &Vector2d) -> f64;
+    fn sample3(&self, point: &Vector3d) -> f64;
+    fn sample4(&self, point: &Vector4d) -> f64;
+}
+
+impl<N: Noise + ?Sized> Noise for &N {
+    fn sample2(&self, point: &Vector2d) -> f64 {
+        (**self).sample2(point)
+    }
+
+    fn sample3(&self, point: &Vector3d) -> f64 {
+        (**self).sample3(point)
+    }
+
+    fn sample4(&self, point: &Vector4d) -> f64 {
+        (**self).sample4(point)
+    }
+}
+
+/// Quintic smoothstep used to interpolate between lattice points.
+#[inline]
+pub(crate) fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+#[inline]
+pub(crate) fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Reduces an integral lattice coordinate to the period of the permutation
+/// table, which only looks at the low eight bits. Neighbouring cells are
+/// then a small offset away, whatever the input's magnitude.
+#[inline]
+pub(crate) fn lattice_cell(coordinate: f64) -> i32 {
+    coordinate.rem_euclid(256.0) as i32
+}
+
+/// Splits a coordinate into its lattice cell, reduced by [`lattice_cell`], and
+/// the offset inside it.
+#[inline]
+pub(crate) fn split(coordinate: f64) -> (i32, f64) {
+    let floor = coordinate.floor();
+    (lattice_cell(floor), coordinate - floor)
+}
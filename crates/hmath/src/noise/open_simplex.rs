@@ -0,0 +1,228 @@
+use crate::noise::permutation::PermutationTable;
+use crate::noise::{lattice_cell, Noise};
+use crate::vector::{Vector2d, Vector3d, Vector4d};
+
+/// OpenSimplex2-style gradient noise.
+///
+/// 2D and 4D are evaluated on the simplex (A*n) lattice and 3D on a rotated
+/// body-centred cubic lattice, which avoids the axis-aligned artifacts of
+/// Perlin noise. Every kernel has a squared radius of 0.5, the largest value
+/// for which only the enclosing simplex contributes, so the output is
+/// continuous. Output lies in `[-1, 1]`.
+#[derive(Debug, Clone)]
+pub struct OpenSimplex2 {
+    permutation: PermutationTable,
+}
+
+const SKEW_2D: f64 = 0.366_025_403_784_438_6; // (sqrt(3) - 1) / 2
+const UNSKEW_2D: f64 = 0.211_324_865_405_187_1; // (3 - sqrt(3)) / 6
+const SKEW_4D: f64 = 0.309_016_994_374_947_45; // (sqrt(5) - 1) / 4
+const UNSKEW_4D: f64 = 0.138_196_601_125_010_5; // (5 - sqrt(5)) / 20
+const ROTATE_3D: f64 = 2.0 / 3.0;
+const RADIUS_SQUARED: f64 = 0.5;
+
+// Rescale each dimension so its output spans roughly [-1, 1].
+const SCALE_2D: f64 = 99.0;
+const SCALE_3D: f64 = 108.5;
+const SCALE_4D: f64 = 108.5;
+
+// sin/cos of multiples of 15 degrees, written out so no platform libm is
+// involved in building the gradient table.
+const S15: f64 = 0.258_819_045_102_520_74;
+const S30: f64 = 0.5;
+const S45: f64 = std::f64::consts::FRAC_1_SQRT_2;
+const S60: f64 = 0.866_025_403_784_438_6;
+const S75: f64 = 0.965_925_826_289_068_3;
+
+const GRADIENTS_2D: [(f64, f64); 24] = [
+    (1.0, 0.0),
+    (S75, S15),
+    (S60, S30),
+    (S45, S45),
+    (S30, S60),
+    (S15, S75),
+    (0.0, 1.0),
+    (-S15, S75),
+    (-S30, S60),
+    (-S45, S45),
+    (-S60, S30),
+    (-S75, S15),
+    (-1.0, 0.0),
+    (-S75, -S15),
+    (-S60, -S30),
+    (-S45, -S45),
+    (-S30, -S60),
+    (-S15, -S75),
+    (0.0, -1.0),
+    (S15, -S75),
+    (S30, -S60),
+    (S45, -S45),
+    (S60, -S30),
+    (S75, -S15),
+];
+
+const GRADIENTS_3D: [(f64, f64, f64); 12] = [
+    (S45, S45, 0.0),
+    (-S45, S45, 0.0),
+    (S45, -S45, 0.0),
+    (-S45, -S45, 0.0),
+    (S45, 0.0, S45),
+    (-S45, 0.0, S45),
+    (S45, 0.0, -S45),
+    (-S45, 0.0, -S45),
+    (0.0, S45, S45),
+    (0.0, -S45, S45),
+    (0.0, S45, -S45),
+    (0.0, -S45, -S45),
+];
+
+const INV_SQRT_3: f64 = 0.577_350_269_189_625_8;
+
+impl OpenSimplex2 {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            permutation: PermutationTable::new(seed),
+        }
+    }
+
+    fn grad2(&self, i: i32, j: i32, x: f64, y: f64) -> f64 {
+        let (gx, gy) = GRADIENTS_2D[self.permutation.hash2(i, j) as usize % 24];
+        gx * x + gy * y
+    }
+
+    fn grad3(&self, vertex: [i32; 3], lattice: i32, d: [f64; 3]) -> f64 {
+        let hash = self
+            .permutation
+            .hash4(vertex[0], vertex[1], vertex[2], lattice);
+        let (gx, gy, gz) = GRADIENTS_3D[hash as usize % 12];
+        gx * d[0] + gy * d[1] + gz * d[2]
+    }
+
+    fn grad4(&self, vertex: [i32; 4], d: [f64; 4]) -> f64 {
+        // Tesseract edge directions: one component zero, the others ±1/sqrt(3).
+        let h = self
+            .permutation
+            .hash4(vertex[0], vertex[1], vertex[2], vertex[3])
+            & 31;
+        let zero_axis = (h >> 3) as usize;
+        let mut signs = [h & 4 != 0, h & 2 != 0, h & 1 != 0].into_iter();
+        let mut sum = 0.0;
+        for (axis, value) in d.iter().enumerate() {
+            if axis == zero_axis {
+                continue;
+            }
+            let negative = signs.next().unwrap();
+            sum += if negative { -value } else { *value };
+        }
+        sum * INV_SQRT_3
+    }
+}
+
+#[inline]
+fn kernel(distance_squared: f64) -> f64 {
+    let a = RADIUS_SQUARED - distance_squared;
+    if a > 0.0 {
+        (a * a) * (a * a)
+    } else {
+        0.0
+    }
+}
+
+impl Noise for OpenSimplex2 {
+    fn sample2(&self, point: &Vector2d) -> f64 {
+        let s = (point.x + point.y) * SKEW_2D;
+        let i = (point.x + s).floor();
+        let j = (point.y + s).floor();
+        let t = (i + j) * UNSKEW_2D;
+        let x0 = point.x - (i - t);
+        let y0 = point.y - (j - t);
+        let (i, j) = (lattice_cell(i), lattice_cell(j));
+
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+        let x1 = x0 - i1 as f64 + UNSKEW_2D;
+        let y1 = y0 - j1 as f64 + UNSKEW_2D;
+        let x2 = x0 - 1.0 + 2.0 * UNSKEW_2D;
+        let y2 = y0 - 1.0 + 2.0 * UNSKEW_2D;
+
+        let value = kernel(x0 * x0 + y0 * y0) * self.grad2(i, j, x0, y0)
+            + kernel(x1 * x1 + y1 * y1) * self.grad2(i + i1, j + j1, x1, y1)
+            + kernel(x2 * x2 + y2 * y2) * self.grad2(i + 1, j + 1, x2, y2);
+        value * SCALE_2D
+    }
+
+    fn sample3(&self, point: &Vector3d) -> f64 {
+        // Orthonormal reflection that points the lattice's main diagonal
+        // along the (1, 1, 1) input direction.
+        let r = ROTATE_3D * (point.x + point.y + point.z);
+        let p = [r - point.x, r - point.y, r - point.z];
+
+        let mut value = 0.0;
+        for lattice in 0..2 {
+            // The second cubic lattice is offset by half a cell on every axis.
+            let offset = lattice as f64 * 0.5;
+            let mut base = [0i32; 3];
+            let mut d = [0.0; 3];
+            for axis in 0..3 {
+                let shifted = p[axis] - offset;
+                let rounded = shifted.round();
+                base[axis] = lattice_cell(rounded);
+                d[axis] = shifted - rounded;
+            }
+
+            let distance_squared = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+            value += kernel(distance_squared) * self.grad3(base, lattice, d);
+
+            // The only other vertex of this lattice within reach lies across
+            // the face we are closest to.
+            let mut axis = 0;
+            if d[1].abs() > d[axis].abs() {
+                axis = 1;
+            }
+            if d[2].abs() > d[axis].abs() {
+                axis = 2;
+            }
+            let step = if d[axis] < 0.0 { -1 } else { 1 };
+            base[axis] += step;
+            d[axis] -= step as f64;
+            let distance_squared = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+            value += kernel(distance_squared) * self.grad3(base, lattice, d);
+        }
+        value * SCALE_3D
+    }
+
+    fn sample4(&self, point: &Vector4d) -> f64 {
+        let p = [point.x, point.y, point.z, point.w];
+        let s = (p[0] + p[1] + p[2] + p[3]) * SKEW_4D;
+        let mut base = [0.0; 4];
+        let mut fraction = [0.0; 4];
+        for axis in 0..4 {
+            let skewed = p[axis] + s;
+            base[axis] = skewed.floor();
+            fraction[axis] = skewed - base[axis];
+        }
+
+        // Walk the simplex by stepping along axes in decreasing order of
+        // their skewed fractional part; ties go to the lower axis.
+        let mut order = [0usize, 1, 2, 3];
+        order.sort_by(|&a, &b| fraction[b].total_cmp(&fraction[a]).then(a.cmp(&b)));
+
+        // Positions use the exact floors; hashing uses them reduced.
+        let mut vertex = base;
+        let mut value = 0.0;
+        for step in 0..5 {
+            if step > 0 {
+                vertex[order[step - 1]] += 1.0;
+            }
+            let t = (vertex[0] + vertex[1] + vertex[2] + vertex[3]) * UNSKEW_4D;
+            let d = [
+                p[0] - (vertex[0] - t),
+                p[1] - (vertex[1] - t),
+                p[2] - (vertex[2] - t),
+                p[3] - (vertex[3] - t),
+            ];
+            let distance_squared = d[0] * d[0] + d[1] * d[1] + d[2] * d[2] + d[3] * d[3];
+            value += kernel(distance_squared) * self.grad4(vertex.map(lattice_cell), d);
+        }
+        value * SCALE_4D
+    }
+}
@@ -0,0 +1,145 @@
+use crate::noise::permutation::PermutationTable;
+use crate::noise::{fade, lerp, split, Noise};
+use crate::vector::{Vector2d, Vector3d, Vector4d};
+
+/// Ken Perlin's improved gradient noise.
+///
+/// Output lies in `[-1, 1]` and is zero at every integer lattice point.
+#[derive(Debug, Clone)]
+pub struct Perlin {
+    permutation: PermutationTable,
+}
+
+// Rescale each dimension so its output spans roughly [-1, 1].
+const SCALE_2D: f64 = 1.0;
+const SCALE_3D: f64 = 0.97;
+const SCALE_4D: f64 = 0.79;
+
+impl Perlin {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            permutation: PermutationTable::new(seed),
+        }
+    }
+}
+
+#[inline]
+fn grad2(hash: u8, x: f64, y: f64) -> f64 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+#[inline]
+fn grad3(hash: u8, x: f64, y: f64, z: f64) -> f64 {
+    // The twelve cube edge directions, padded to sixteen.
+    match hash & 15 {
+        0 | 12 => x + y,
+        1 | 14 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x + z,
+        5 => -x + z,
+        6 => x - z,
+        7 => -x - z,
+        8 => y + z,
+        9 | 13 => -y + z,
+        10 => y - z,
+        _ => -y - z,
+    }
+}
+
+#[inline]
+fn grad4(hash: u8, x: f64, y: f64, z: f64, w: f64) -> f64 {
+    // The thirty-two tesseract edge directions: one axis zero, the rest ±1.
+    let h = hash & 31;
+    let (a, b, c) = match h >> 3 {
+        0 => (y, z, w),
+        1 => (x, z, w),
+        2 => (x, y, w),
+        _ => (x, y, z),
+    };
+    let a = if h & 4 == 0 { a } else { -a };
+    let b = if h & 2 == 0 { b } else { -b };
+    let c = if h & 1 == 0 { c } else { -c };
+    a + b + c
+}
+
+impl Noise for Perlin {
+    fn sample2(&self, point: &Vector2d) -> f64 {
+        let p = &self.permutation;
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let (u, v) = (fade(x), fade(y));
+
+        let n00 = grad2(p.hash2(xi, yi), x, y);
+        let n10 = grad2(p.hash2(xi + 1, yi), x - 1.0, y);
+        let n01 = grad2(p.hash2(xi, yi + 1), x, y - 1.0);
+        let n11 = grad2(p.hash2(xi + 1, yi + 1), x - 1.0, y - 1.0);
+
+        lerp(lerp(n00, n10, u), lerp(n01, n11, u), v) * SCALE_2D
+    }
+
+    fn sample3(&self, point: &Vector3d) -> f64 {
+        let p = &self.permutation;
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let (zi, z) = split(point.z);
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let corner = |dx: i32, dy: i32, dz: i32| {
+            let hash = p.hash3(xi + dx, yi + dy, zi + dz);
+            grad3(hash, x - dx as f64, y - dy as f64, z - dz as f64)
+        };
+
+        let x00 = lerp(corner(0, 0, 0), corner(1, 0, 0), u);
+        let x10 = lerp(corner(0, 1, 0), corner(1, 1, 0), u);
+        let x01 = lerp(corner(0, 0, 1), corner(1, 0, 1), u);
+        let x11 = lerp(corner(0, 1, 1), corner(1, 1, 1), u);
+        lerp(lerp(x00, x10, v), lerp(x01, x11, v), w) * SCALE_3D
+    }
+
+    fn sample4(&self, point: &Vector4d) -> f64 {
+        let p = &self.permutation;
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let (zi, z) = split(point.z);
+        let (wi, w) = split(point.w);
+        let fades = [fade(x), fade(y), fade(z), fade(w)];
+
+        // Collapse the sixteen corners one axis at a time, x first.
+        let mut values = [0.0; 16];
+        for (corner, value) in values.iter_mut().enumerate() {
+            let d = [
+                corner & 1,
+                (corner >> 1) & 1,
+                (corner >> 2) & 1,
+                (corner >> 3) & 1,
+            ]
+            .map(|v| v as i32);
+            let hash = p.hash4(xi + d[0], yi + d[1], zi + d[2], wi + d[3]);
+            *value = grad4(
+                hash,
+                x - d[0] as f64,
+                y - d[1] as f64,
+                z - d[2] as f64,
+                w - d[3] as f64,
+            );
+        }
+        let mut count = 16;
+        for t in fades {
+            count /= 2;
+            for i in 0..count {
+                values[i] = lerp(values[2 * i], values[2 * i + 1], t);
+            }
+        }
+        values[0] * SCALE_4D
+    }
+}
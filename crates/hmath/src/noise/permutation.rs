@@ -0,0 +1,64 @@
+use crate::sampling::rng::{Rng, SplitMix64};
+
+/// A seeded shuffle of `0..256` used to hash integer lattice coordinates.
+///
+/// The table is doubled so that nested lookups never need to wrap.
+#[derive(Clone)]
+pub struct PermutationTable {
+    values: [u8; 512],
+}
+
+impl PermutationTable {
+    pub fn new(seed: u64) -> Self {
+        let mut values = [0u8; 512];
+        for (i, value) in values.iter_mut().take(256).enumerate() {
+            *value = i as u8;
+        }
+
+        // Fisher-Yates driven by SplitMix64, which is trivially portable.
+        let mut rng = SplitMix64::new(seed);
+        for i in (1..256).rev() {
+            let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+            values.swap(i, j);
+        }
+
+        let (low, high) = values.split_at_mut(256);
+        high.copy_from_slice(low);
+        Self { values }
+    }
+
+    #[inline]
+    pub fn hash1(&self, x: i32) -> u8 {
+        self.values[(x & 255) as usize]
+    }
+
+    #[inline]
+    pub fn hash2(&self, x: i32, y: i32) -> u8 {
+        self.values[self.hash1(x) as usize + (y & 255) as usize]
+    }
+
+    #[inline]
+    pub fn hash3(&self, x: i32, y: i32, z: i32) -> u8 {
+        self.values[self.hash2(x, y) as usize + (z & 255) as usize]
+    }
+
+    #[inline]
+    pub fn hash4(&self, x: i32, y: i32, z: i32, w: i32) -> u8 {
+        self.values[self.hash3(x, y, z) as usize + (w & 255) as usize]
+    }
+
+    /// Maps a hash to a value in `[0, 1]` with 16 bits of resolution by
+    /// chaining two lookups.
+    #[inline]
+    pub(crate) fn unit(&self, hash: u8, salt: i32) -> f64 {
+        let high = self.hash2(hash as i32, salt) as u32;
+        let low = self.hash2(hash as i32 ^ 0x55, salt.wrapping_add(0x3b)) as u32;
+        ((high << 8) | low) as f64 / 65535.0
+    }
+}
+
+impl std::fmt::Debug for PermutationTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PermutationTable").finish_non_exhaustive()
+    }
+}
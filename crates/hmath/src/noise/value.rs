@@ -0,0 +1,82 @@
+use crate::noise::permutation::PermutationTable;
+use crate::noise::{fade, lerp, split, Noise};
+use crate::vector::{Vector2d, Vector3d, Vector4d};
+
+/// Smoothly interpolated random values on the integer lattice.
+///
+/// Cheaper and blockier than gradient noise; output lies in `[-1, 1]`.
+#[derive(Debug, Clone)]
+pub struct ValueNoise {
+    permutation: PermutationTable,
+}
+
+impl ValueNoise {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            permutation: PermutationTable::new(seed),
+        }
+    }
+}
+
+#[inline]
+fn to_signed(hash: u8) -> f64 {
+    hash as f64 / 127.5 - 1.0
+}
+
+impl Noise for ValueNoise {
+    fn sample2(&self, point: &Vector2d) -> f64 {
+        let p = &self.permutation;
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let (u, v) = (fade(x), fade(y));
+        let corner = |dx: i32, dy: i32| to_signed(p.hash2(xi + dx, yi + dy));
+        lerp(
+            lerp(corner(0, 0), corner(1, 0), u),
+            lerp(corner(0, 1), corner(1, 1), u),
+            v,
+        )
+    }
+
+    fn sample3(&self, point: &Vector3d) -> f64 {
+        let p = &self.permutation;
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let (zi, z) = split(point.z);
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+        let corner = |dx: i32, dy: i32, dz: i32| to_signed(p.hash3(xi + dx, yi + dy, zi + dz));
+
+        let x00 = lerp(corner(0, 0, 0), corner(1, 0, 0), u);
+        let x10 = lerp(corner(0, 1, 0), corner(1, 1, 0), u);
+        let x01 = lerp(corner(0, 0, 1), corner(1, 0, 1), u);
+        let x11 = lerp(corner(0, 1, 1), corner(1, 1, 1), u);
+        lerp(lerp(x00, x10, v), lerp(x01, x11, v), w)
+    }
+
+    fn sample4(&self, point: &Vector4d) -> f64 {
+        let p = &self.permutation;
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let (zi, z) = split(point.z);
+        let (wi, w) = split(point.w);
+
+        let mut values = [0.0; 16];
+        for (corner, value) in values.iter_mut().enumerate() {
+            let d = [
+                corner & 1,
+                (corner >> 1) & 1,
+                (corner >> 2) & 1,
+                (corner >> 3) & 1,
+            ]
+            .map(|v| v as i32);
+            *value = to_signed(p.hash4(xi + d[0], yi + d[1], zi + d[2], wi + d[3]));
+        }
+        let mut count = 16;
+        for t in [fade(x), fade(y), fade(z), fade(w)] {
+            count /= 2;
+            for i in 0..count {
+                values[i] = lerp(values[2 * i], values[2 * i + 1], t);
+            }
+        }
+        values[0]
+    }
+}
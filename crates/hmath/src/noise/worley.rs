@@ -0,0 +1,145 @@
+use crate::noise::permutation::PermutationTable;
+use crate::noise::{split, Noise};
+use crate::vector::{Vector2d, Vector3d, Vector4d};
+
+/// What a [`Worley`] sample reports about the nearby feature points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WorleyReturn {
+    /// Distance to the nearest feature point.
+    #[default]
+    F1,
+    /// Distance to the second nearest feature point.
+    F2,
+    /// `F2 - F1`, which is zero along cell borders.
+    F2MinusF1,
+    /// A random value in `[-1, 1]` that is constant across each cell.
+    CellValue,
+}
+
+/// Cellular noise built from one jittered feature point per lattice cell.
+///
+/// Distances are Euclidean and measured in lattice units, so they are not
+/// confined to `[-1, 1]` like the other generators.
+#[derive(Debug, Clone)]
+pub struct Worley {
+    permutation: PermutationTable,
+    pub return_type: WorleyReturn,
+}
+
+/// Running F1/F2 search state.
+struct Nearest {
+    f1: f64,
+    f2: f64,
+    cell_hash: u8,
+}
+
+impl Nearest {
+    fn new() -> Self {
+        Self {
+            f1: f64::INFINITY,
+            f2: f64::INFINITY,
+            cell_hash: 0,
+        }
+    }
+
+    fn insert(&mut self, distance_squared: f64, hash: u8) {
+        if distance_squared < self.f1 {
+            self.f2 = self.f1;
+            self.f1 = distance_squared;
+            self.cell_hash = hash;
+        } else if distance_squared < self.f2 {
+            self.f2 = distance_squared;
+        }
+    }
+}
+
+impl Worley {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            permutation: PermutationTable::new(seed),
+            return_type: WorleyReturn::default(),
+        }
+    }
+
+    pub fn with_return_type(seed: u64, return_type: WorleyReturn) -> Self {
+        Self {
+            permutation: PermutationTable::new(seed),
+            return_type,
+        }
+    }
+
+    fn finish(&self, nearest: Nearest) -> f64 {
+        match self.return_type {
+            WorleyReturn::F1 => nearest.f1.sqrt(),
+            WorleyReturn::F2 => nearest.f2.sqrt(),
+            WorleyReturn::F2MinusF1 => nearest.f2.sqrt() - nearest.f1.sqrt(),
+            WorleyReturn::CellValue => self.permutation.unit(nearest.cell_hash, 7) * 2.0 - 1.0,
+        }
+    }
+}
+
+impl Noise for Worley {
+    fn sample2(&self, point: &Vector2d) -> f64 {
+        let p = &self.permutation;
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let mut nearest = Nearest::new();
+
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let hash = p.hash2(xi + dx, yi + dy);
+                let fx = dx as f64 + p.unit(hash, 0) - x;
+                let fy = dy as f64 + p.unit(hash, 1) - y;
+                nearest.insert(fx * fx + fy * fy, hash);
+            }
+        }
+        self.finish(nearest)
+    }
+
+    fn sample3(&self, point: &Vector3d) -> f64 {
+        let p = &self.permutation;
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let (zi, z) = split(point.z);
+        let mut nearest = Nearest::new();
+
+        for dz in -1..=1 {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let hash = p.hash3(xi + dx, yi + dy, zi + dz);
+                    let fx = dx as f64 + p.unit(hash, 0) - x;
+                    let fy = dy as f64 + p.unit(hash, 1) - y;
+                    let fz = dz as f64 + p.unit(hash, 2) - z;
+                    nearest.insert(fx * fx + fy * fy + fz * fz, hash);
+                }
+            }
+        }
+        self.finish(nearest)
+    }
+
+    fn sample4(&self, point: &Vector4d) -> f64 {
+        let p = &self.permutation;
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let (zi, z) = split(point.z);
+        let (wi, w) = split(point.w);
+        let mut nearest = Nearest::new();
+
+        for dw in -1..=1 {
+            for dz in -1..=1 {
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        let hash = p.hash4(xi + dx, yi + dy, zi + dz, wi + dw);
+                        let fx = dx as f64 + p.unit(hash, 0) - x;
+                        let fy = dy as f64 + p.unit(hash, 1) - y;
+                        let fz = dz as f64 + p.unit(hash, 2) - z;
+                        let fw = dw as f64 + p.unit(hash, 3) - w;
+                        nearest.insert(fx * fx + fy * fy + fz * fz + fw * fw, hash);
+                    }
+                }
+            }
+        }
+        self.finish(nearest)
+    }
+}
@@ -1,9 +1,46 @@
+use crate::matrix::Matrix3;
 use crate::vector::Vector3;
 use num_traits::Float;
 use std::f64::consts::PI;
-use std::ops::Mul;
+use std::ops::{Mul, Neg};
 
-#[derive(Debug, Clone, Copy)]
+/// The order in which rotations about the three axes are applied.
+///
+/// `XYZ` rotates about the fixed X axis first, then Y, then Z, which is the
+/// same as rotating about the local Z, Y' and X'' axes in that order. A Y-up
+/// camera that yaws about world Y and pitches about its local X, like the
+/// engine's camera controller, uses `ZXY` with roll in `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    /// Axis indices in application order.
+    fn axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerOrder::XYZ => (0, 1, 2),
+            EulerOrder::XZY => (0, 2, 1),
+            EulerOrder::YXZ => (1, 0, 2),
+            EulerOrder::YZX => (1, 2, 0),
+            EulerOrder::ZXY => (2, 0, 1),
+            EulerOrder::ZYX => (2, 1, 0),
+        }
+    }
+
+    /// Whether the axes form an even permutation of X, Y, Z.
+    fn is_cyclic(self) -> bool {
+        matches!(self, EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Quaternion<T> {
     pub w: T,
     pub x: T,
@@ -40,6 +77,8 @@ impl<T: Float> Quaternion<T> {
         }
     }
 
+    /// Builds a rotation from roll (`x`), pitch (`y`) and yaw (`z`) applied in
+    /// that order, i.e. [`EulerOrder::XYZ`].
     pub fn from_euler(euler: &Vector3<T>) -> Self {
         let roll = euler.x;
         let pitch = euler.y;
@@ -61,31 +100,68 @@ impl<T: Float> Quaternion<T> {
         }
     }
 
+    /// Inverse of [`Quaternion::from_euler`], i.e. [`EulerOrder::XYZ`].
     pub fn to_euler(&self) -> Vector3<T> {
-        let two = T::one() + T::one();
-        let sinp = two * (self.w * self.x - self.y * self.z);
-        let pitch = if sinp > T::one() {
-            T::from(PI / 2.0).unwrap()
-        } else if sinp < -T::one() {
-            -T::from(PI / 2.0).unwrap()
+        self.to_euler_order(EulerOrder::XYZ)
+    }
+
+    /// Builds a rotation from angles about the X, Y and Z axes (stored in the
+    /// matching components of `angles`), applied in the given order.
+    pub fn from_euler_order(angles: &Vector3<T>, order: EulerOrder) -> Self {
+        let (i, j, k) = order.axes();
+        let axis_rotation = |axis: usize| {
+            let mut unit = Vector3::zero();
+            unit[axis] = T::one();
+            Self::from_axis_angle(unit, angles[axis])
+        };
+        axis_rotation(k) * axis_rotation(j) * axis_rotation(i)
+    }
+
+    /// Decomposes the rotation into angles about the X, Y and Z axes for the
+    /// given order, such that [`Quaternion::from_euler_order`] reproduces it.
+    ///
+    /// The middle rotation is returned in `[-pi/2, pi/2]` and the outer two in
+    /// `[-pi, pi]`. At gimbal lock, where the middle rotation is a quarter
+    /// turn, the first rotation is reported as zero and the whole remaining
+    /// twist is assigned to the last one.
+    pub fn to_euler_order(&self, order: EulerOrder) -> Vector3<T> {
+        let (i, j, k) = order.axes();
+        let s = if order.is_cyclic() {
+            T::one()
         } else {
-            sinp.asin()
+            -T::one()
         };
-        let yaw = (two * (self.w * self.y + self.x * self.z))
-            .atan2(T::one() - two * (self.x * self.x + self.y * self.y));
-        let roll = (two * (self.w * self.z + self.x * self.y))
-            .atan2(T::one() - two * (self.x * self.x + self.z * self.z));
-        Vector3::new(roll, pitch, yaw)
+        let m = Matrix3::from_rotation(&self.normalize());
+        // `r(a, b)` addresses the column-vector form of the rotation.
+        let r = |a: usize, b: usize| m.data[b * 3 + a];
+
+        let sin_middle = -s * r(k, i);
+        let mut angles = Vector3::zero();
+        if sin_middle.abs() >= T::one() - T::from(1e-6).unwrap() {
+            let half_pi = T::from(PI / 2.0).unwrap();
+            angles[j] = if sin_middle > T::zero() {
+                half_pi
+            } else {
+                -half_pi
+            };
+            angles[i] = T::zero();
+            angles[k] = (-s * r(i, j)).atan2(r(j, j));
+        } else {
+            angles[j] = sin_middle.asin();
+            angles[i] = (s * r(k, j)).atan2(r(k, k));
+            angles[k] = (s * r(j, i)).atan2(r(i, i));
+        }
+        angles
     }
 
     pub fn normalize(&self) -> Self {
         let length_sq = self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z;
         let length = length_sq.sqrt();
-        
+
         if length.is_zero() {
             return Self::identity();
         }
-        
+
         Self {
             w: self.w / length,
             x: self.x / length,
@@ -94,15 +170,235 @@ impl<T: Float> Quaternion<T> {
         }
     }
 
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[inline]
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Returns the multiplicative inverse. For unit quaternions this equals
+    /// the conjugate; a zero quaternion yields the identity.
+    pub fn inverse(&self) -> Self {
+        let norm_squared = self.norm_squared();
+        if norm_squared.is_zero() {
+            return Self::identity();
+        }
+        let conjugate = self.conjugate();
+        Self {
+            w: conjugate.w / norm_squared,
+            x: conjugate.x / norm_squared,
+            y: conjugate.y / norm_squared,
+            z: conjugate.z / norm_squared,
+        }
+    }
+
+    /// Returns the angle in radians of the shortest rotation taking `self`
+    /// to `other`. Both quaternions must be normalized.
+    pub fn angle_between(&self, other: &Self) -> T {
+        let two = T::one() + T::one();
+        two * self.dot(other).abs().min(T::one()).acos()
+    }
+
+    /// Normalized linear interpolation along the shortest path.
+    ///
+    /// Cheaper than [`Quaternion::slerp`] but does not move at constant
+    /// angular velocity.
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            -*other
+        } else {
+            *other
+        };
+        Self {
+            w: self.w + (other.w - self.w) * t,
+            x: self.x + (other.x - self.x) * t,
+            y: self.y + (other.y - self.y) * t,
+            z: self.z + (other.z - self.z) * t,
+        }
+        .normalize()
+    }
+
+    /// Spherical linear interpolation along the shortest path, moving at
+    /// constant angular velocity. Both quaternions must be normalized.
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let mut cos_theta = self.dot(other);
+        let other = if cos_theta < T::zero() {
+            cos_theta = -cos_theta;
+            -*other
+        } else {
+            *other
+        };
+
+        // Nearly parallel quaternions make sin(theta) vanish; nlerp is exact
+        // enough there.
+        if cos_theta > T::one() - T::from(1e-6).unwrap() {
+            return self.nlerp(&other, t);
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let a = ((T::one() - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        Self {
+            w: self.w * a + other.w * b,
+            x: self.x * a + other.x * b,
+            y: self.y * a + other.y * b,
+            z: self.z * a + other.z * b,
+        }
+    }
+
+    /// Logarithm of a unit quaternion: a pure quaternion whose vector part is
+    /// the rotation axis scaled by half the rotation angle.
+    pub fn log(&self) -> Self {
+        let v = Vector3::new(self.x, self.y, self.z);
+        let sin_half = v.length();
+        if sin_half < T::from(1e-12).unwrap() {
+            return Self::new(T::zero(), self.x, self.y, self.z);
+        }
+        let half_angle = sin_half.atan2(self.w);
+        let v = v * (half_angle / sin_half);
+        Self::new(T::zero(), v.x, v.y, v.z)
+    }
+
+    /// Exponential of a pure quaternion; the inverse of [`Quaternion::log`].
+    pub fn exp(&self) -> Self {
+        let v = Vector3::new(self.x, self.y, self.z);
+        let half_angle = v.length();
+        if half_angle < T::from(1e-12).unwrap() {
+            return Self::new(T::one(), self.x, self.y, self.z).normalize();
+        }
+        let v = v * (half_angle.sin() / half_angle);
+        Self::new(half_angle.cos(), v.x, v.y, v.z)
+    }
+
+    /// Spherical quadrangle interpolation between `self` and `other` with
+    /// inner control points `a` and `b` from [`Quaternion::squad_tangent`].
+    /// Gives C1-continuous rotation through a sequence of keys.
+    pub fn squad(&self, a: &Self, b: &Self, other: &Self, t: T) -> Self {
+        let two = T::one() + T::one();
+        let outer = self.slerp(other, t);
+        let inner = a.slerp(b, t);
+        outer.slerp(&inner, two * t * (T::one() - t))
+    }
+
+    /// Inner squad control point for key `self` between `previous` and
+    /// `next`. The neighbours are flipped into `self`'s hemisphere first, so
+    /// either sign of a key gives the same tangent.
+    pub fn squad_tangent(&self, previous: &Self, next: &Self) -> Self {
+        let align = |q: &Self| if self.dot(q) < T::zero() { -*q } else { *q };
+        let (previous, next) = (align(previous), align(next));
+        let inverse = self.conjugate();
+        let to_previous = (inverse * previous).log();
+        let to_next = (inverse * next).log();
+        let quarter = -T::one() / (T::one() + T::one() + T::one() + T::one());
+        let sum = Self::new(
+            T::zero(),
+            (to_previous.x + to_next.x) * quarter,
+            (to_previous.y + to_next.y) * quarter,
+            (to_previous.z + to_next.z) * quarter,
+        );
+        (*self * sum.exp()).normalize()
+    }
+
+    /// Returns the shortest rotation taking the unit vector `from` onto the
+    /// unit vector `to`.
+    pub fn from_rotation_arc(from: &Vector3<T>, to: &Vector3<T>) -> Self {
+        let d = from.dot(to);
+        if d < -T::one() + T::from(1e-6).unwrap() {
+            // Opposite vectors: rotate half a turn around any perpendicular axis.
+            let mut axis = Vector3::new(T::one(), T::zero(), T::zero()).cross(from);
+            if axis.norm_squared() < T::from(1e-6).unwrap() {
+                axis = Vector3::new(T::zero(), T::one(), T::zero()).cross(from);
+            }
+            return Self::from_axis_angle(axis.normalize(), T::from(PI).unwrap());
+        }
+
+        let c = from.cross(to);
+        Self::new(T::one() + d, c.x, c.y, c.z).normalize()
+    }
+
+    /// Returns the rotation that maps +Z onto `forward` and +Y as close to
+    /// `up` as possible, matching the engine's camera basis.
+    ///
+    /// Falls back to [`Quaternion::from_rotation_arc`] when `forward` and
+    /// `up` are parallel.
+    pub fn look_rotation(forward: &Vector3<T>, up: &Vector3<T>) -> Self {
+        let z = forward.normalize();
+        let x = up.cross(&z);
+        if x.norm_squared() < T::from(1e-12).unwrap() {
+            return Self::from_rotation_arc(&Vector3::new(T::zero(), T::zero(), T::one()), &z);
+        }
+        let x = x.normalize();
+        let y = z.cross(&x);
+
+        Self::from_rotation_matrix(&Matrix3::new([x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z]))
+    }
+
+    /// Extracts the rotation from an orthonormal matrix laid out like
+    /// [`Matrix3::from_rotation`].
+    pub fn from_rotation_matrix(matrix: &Matrix3<T>) -> Self {
+        // `r(i, j)` addresses the column-vector form of the rotation, i.e. the
+        // transpose of the row-vector storage.
+        let r = |i: usize, j: usize| matrix.data[j * 3 + i];
+        let one = T::one();
+        let two = one + one;
+        let quarter = one / (two * two);
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+
+        let q = if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Self::new(
+                quarter * s,
+                (r(2, 1) - r(1, 2)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+                (r(1, 0) - r(0, 1)) / s,
+            )
+        } else if r(0, 0) > r(1, 1) && r(0, 0) > r(2, 2) {
+            let s = (one + r(0, 0) - r(1, 1) - r(2, 2)).sqrt() * two;
+            Self::new(
+                (r(2, 1) - r(1, 2)) / s,
+                quarter * s,
+                (r(0, 1) + r(1, 0)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+            )
+        } else if r(1, 1) > r(2, 2) {
+            let s = (one + r(1, 1) - r(0, 0) - r(2, 2)).sqrt() * two;
+            Self::new(
+                (r(0, 2) - r(2, 0)) / s,
+                (r(0, 1) + r(1, 0)) / s,
+                quarter * s,
+                (r(1, 2) + r(2, 1)) / s,
+            )
+        } else {
+            let s = (one + r(2, 2) - r(0, 0) - r(1, 1)).sqrt() * two;
+            Self::new(
+                (r(1, 0) - r(0, 1)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+                (r(1, 2) + r(2, 1)) / s,
+                quarter * s,
+            )
+        };
+
+        q.normalize()
+    }
+
+    pub fn to_rotation_matrix(&self) -> Matrix3<T> {
+        Matrix3::from_rotation(self)
+    }
+
     pub fn rotate_vector(&self, v: &Vector3<T>) -> Vector3<T> {
         let qw = self.w;
         let qx = self.x;
         let qy = self.y;
         let qz = self.z;
-        
+
         let two = T::one() + T::one();
         let one = T::one();
-        
+
         let qxx = qx * qx;
         let qyy = qy * qy;
         let qzz = qz * qz;
@@ -112,7 +408,7 @@ impl<T: Float> Quaternion<T> {
         let qwx = qw * qx;
         let qwy = qw * qy;
         let qwz = qw * qz;
-        
+
         Vector3 {
             x: v.x * (one - two * (qyy + qzz)) + v.y * two * (qxy - qwz) + v.z * two * (qxz + qwy),
             y: v.x * two * (qxy + qwz) + v.y * (one - two * (qxx + qzz)) + v.z * two * (qyz - qwx),
@@ -120,6 +416,16 @@ impl<T: Float> Quaternion<T> {
         }
     }
 
+    /// Converts every component to another scalar type.
+    pub fn cast<U: Float>(&self) -> Quaternion<U> {
+        Quaternion {
+            w: U::from(self.w).unwrap(),
+            x: U::from(self.x).unwrap(),
+            y: U::from(self.y).unwrap(),
+            z: U::from(self.z).unwrap(),
+        }
+    }
+
     pub fn multiply(&self, other: &Self) -> Self {
         Self {
             w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
@@ -145,3 +451,12 @@ impl<T: Float> Mul for &Quaternion<T> {
         self.multiply(other)
     }
 }
+
+/// Negation flips every component. The result represents the same rotation.
+impl<T: Float> Neg for Quaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
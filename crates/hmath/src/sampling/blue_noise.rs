@@ -0,0 +1,224 @@
+use crate::sampling::rng::{Rng, Xoshiro256PlusPlus};
+use crate::vector::Vector2d;
+
+/// Golden ratio conjugate, used to decorrelate successive frames.
+const GOLDEN_RATIO_FRACTION: f64 = 0.618_033_988_749_894_8;
+
+/// A tileable single-channel blue noise texture with values in `[0, 1)`.
+///
+/// Tiles can be loaded from pre-computed textures or generated with
+/// [`BlueNoiseTile::generate`]; lookups wrap in both directions so the same
+/// tile can cover the whole screen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlueNoiseTile {
+    width: usize,
+    height: usize,
+    values: Vec<f32>,
+}
+
+impl BlueNoiseTile {
+    /// Wraps row-major `values`. Returns `None` if the size does not match
+    /// or the tile is empty.
+    pub fn new(width: usize, height: usize, values: Vec<f32>) -> Option<Self> {
+        if width == 0 || height == 0 || values.len() != width * height {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            values,
+        })
+    }
+
+    /// Loads an 8-bit texture, e.g. one channel of a published blue noise
+    /// PNG, mapping each byte to the centre of its bucket.
+    pub fn from_u8(width: usize, height: usize, bytes: &[u8]) -> Option<Self> {
+        let values = bytes.iter().map(|&b| (b as f32 + 0.5) / 256.0).collect();
+        Self::new(width, height, values)
+    }
+
+    /// Generates a `size` x `size` tile with the void-and-cluster method
+    /// (Ulichney 1993). Cost is quadratic in the pixel count, so this is
+    /// meant for small tiles built once at start-up. A given seed yields
+    /// the same tile on every platform.
+    ///
+    /// Panics if `size` is zero, since an empty tile cannot be sampled.
+    pub fn generate(size: usize, seed: u64) -> Self {
+        assert!(size > 0, "blue noise tile size must be positive");
+        let count = size * size;
+        let mut generator = VoidAndCluster::new(size);
+
+        // Initial pattern: roughly a tenth of the pixels chosen at random,
+        // then relaxed by moving the tightest cluster into the largest void
+        // until that move is a no-op.
+        let mut rng = Xoshiro256PlusPlus::new(seed);
+        let initial = (count / 10).max(1);
+        while generator.ones < initial {
+            let index = rng.next_bounded(count as u32) as usize;
+            if !generator.pattern[index] {
+                generator.set(index, true);
+            }
+        }
+        loop {
+            let cluster = generator.tightest_cluster();
+            generator.set(cluster, false);
+            let void = generator.largest_void();
+            generator.set(void, true);
+            if void == cluster {
+                break;
+            }
+        }
+
+        let mut ranks = vec![0usize; count];
+        let prototype = generator.clone();
+
+        // Ranks below the prototype: peel off clusters one at a time.
+        while generator.ones > 0 {
+            let cluster = generator.tightest_cluster();
+            generator.set(cluster, false);
+            ranks[cluster] = generator.ones;
+        }
+
+        // Ranks above it: keep filling the largest void.
+        let mut generator = prototype;
+        while generator.ones < count {
+            let void = generator.largest_void();
+            ranks[void] = generator.ones;
+            generator.set(void, true);
+        }
+
+        let values = ranks
+            .iter()
+            .map(|&rank| ((rank as f64 + 0.5) / count as f64) as f32)
+            .collect();
+        Self {
+            width: size,
+            height: size,
+            values,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Value at pixel `(x, y)`, wrapping outside the tile.
+    pub fn sample(&self, x: i32, y: i32) -> f32 {
+        let x = x.rem_euclid(self.width as i32) as usize;
+        let y = y.rem_euclid(self.height as i32) as usize;
+        self.values[y * self.width + x]
+    }
+
+    /// Value at pixel `(x, y)` for animation frame `frame`, advanced by the
+    /// golden ratio so each pixel is also well distributed over time.
+    pub fn sample_animated(&self, x: i32, y: i32, frame: u32) -> f64 {
+        let value = self.sample(x, y) as f64 + frame as f64 * GOLDEN_RATIO_FRACTION;
+        value.fract()
+    }
+
+    /// Cranley-Patterson rotation of a 2D sample by the tile value at pixel
+    /// `(x, y)`. The second component uses a pixel half a tile away so the
+    /// two offsets are uncorrelated.
+    pub fn rotate(&self, sample: &Vector2d, x: i32, y: i32) -> Vector2d {
+        let offset_x = self.sample(x, y) as f64;
+        let offset_y =
+            self.sample(x + (self.width / 2) as i32, y + (self.height / 2) as i32) as f64;
+        Vector2d::new((sample.x + offset_x).fract(), (sample.y + offset_y).fract())
+    }
+}
+
+/// Binary pattern with its Gaussian-filtered energy kept up to date.
+#[derive(Clone)]
+struct VoidAndCluster {
+    size: usize,
+    pattern: Vec<bool>,
+    energy: Vec<f64>,
+    /// Toroidal Gaussian weight for every offset within the tile.
+    kernel: Vec<f64>,
+    ones: usize,
+}
+
+/// The Gaussian falloff between neighbouring pixels, `exp(-1 / (2σ²))` for
+/// σ = 1.5. The kernel is built from it by multiplication alone, which IEEE
+/// 754 rounds the same everywhere, so unlike the platform's `exp` it gives
+/// bit-identical tiles for a seed on every machine.
+const FALLOFF: f64 = 0.800_737_402_916_808_1;
+
+impl VoidAndCluster {
+    fn new(size: usize) -> Self {
+        let count = size * size;
+        // FALLOFF^(w²) per axis, stepping w² up by the odd numbers.
+        let half = size / 2 + 1;
+        let mut axis = vec![1.0; half];
+        let mut step = FALLOFF;
+        for w in 1..half {
+            axis[w] = axis[w - 1] * step;
+            step *= FALLOFF * FALLOFF;
+        }
+        let mut kernel = vec![0.0; count];
+        for dy in 0..size {
+            for dx in 0..size {
+                kernel[dy * size + dx] = axis[dx.min(size - dx)] * axis[dy.min(size - dy)];
+            }
+        }
+        Self {
+            size,
+            pattern: vec![false; count],
+            energy: vec![0.0; count],
+            kernel,
+            ones: 0,
+        }
+    }
+
+    fn set(&mut self, index: usize, value: bool) {
+        if self.pattern[index] == value {
+            return;
+        }
+        self.pattern[index] = value;
+        let sign = if value { 1.0 } else { -1.0 };
+        if value {
+            self.ones += 1;
+        } else {
+            self.ones -= 1;
+        }
+
+        let (px, py) = (index % self.size, index / self.size);
+        for y in 0..self.size {
+            let dy = (y + self.size - py) % self.size;
+            for x in 0..self.size {
+                let dx = (x + self.size - px) % self.size;
+                self.energy[y * self.size + x] += sign * self.kernel[dy * self.size + dx];
+            }
+        }
+    }
+
+    fn tightest_cluster(&self) -> usize {
+        self.extreme(true, |candidate, best| candidate > best)
+    }
+
+    fn largest_void(&self) -> usize {
+        self.extreme(false, |candidate, best| candidate < best)
+    }
+
+    /// First pixel with the given pattern value whose energy wins `better`.
+    fn extreme(&self, value: bool, better: impl Fn(f64, f64) -> bool) -> usize {
+        let mut best: Option<usize> = None;
+        for (index, &set) in self.pattern.iter().enumerate() {
+            if set != value {
+                continue;
+            }
+            if best.is_none_or(|b| better(self.energy[index], self.energy[b])) {
+                best = Some(index);
+            }
+        }
+        best.unwrap_or(0)
+    }
+}
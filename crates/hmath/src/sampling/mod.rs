@@ -0,0 +1,4 @@
+pub mod blue_noise;
+pub mod rng;
+pub mod sequence;
+pub mod warp;
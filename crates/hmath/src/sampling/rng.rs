@@ -0,0 +1,191 @@
+//! Small seeded generators. They only use integer arithmetic from `core`, so
+//! the same state advances identically on the CPU, in `no_std` code and in a
+//! shader port.
+
+/// Common interface over the generators in this module.
+pub trait Rng {
+    fn next_u32(&mut self) -> u32;
+
+    fn next_u64(&mut self) -> u64 {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+
+    /// Uniform value in `[0, 1)` with 24 bits of resolution.
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Uniform value in `[0, 1)` with 53 bits of resolution.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniform integer in `[0, bound)` without modulo bias (Lemire's method).
+    /// Returns 0 when `bound` is 0.
+    fn next_bounded(&mut self, bound: u32) -> u32 {
+        if bound == 0 {
+            return 0;
+        }
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = self.next_u32() as u64 * bound as u64;
+            if (product as u32) >= threshold {
+                return (product >> 32) as u32;
+            }
+        }
+    }
+}
+
+/// SplitMix64, mainly used to expand a single seed into generator state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// PCG32 (XSH RR variant): 64 bits of state, 32-bit output and 2^63
+/// independent streams. Cheap enough to keep one per pixel on the GPU.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+const PCG_MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+impl Pcg32 {
+    /// Seeds the generator; `stream` selects one of the independent sequences.
+    pub fn new(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(PCG_MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+
+    /// Jumps `delta` steps ahead (or back, with wrapping) in O(log delta).
+    pub fn advance(&mut self, delta: u64) {
+        let mut multiplier = PCG_MULTIPLIER;
+        let mut increment = self.increment;
+        let mut total_multiplier = 1u64;
+        let mut total_increment = 0u64;
+        let mut delta = delta;
+        while delta > 0 {
+            if delta & 1 == 1 {
+                total_multiplier = total_multiplier.wrapping_mul(multiplier);
+                total_increment = total_increment
+                    .wrapping_mul(multiplier)
+                    .wrapping_add(increment);
+            }
+            increment = multiplier.wrapping_add(1).wrapping_mul(increment);
+            multiplier = multiplier.wrapping_mul(multiplier);
+            delta >>= 1;
+        }
+        self.state = total_multiplier
+            .wrapping_mul(self.state)
+            .wrapping_add(total_increment);
+    }
+}
+
+impl Rng for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+        let xor_shifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rotation = (old >> 59) as u32;
+        xor_shifted.rotate_right(rotation)
+    }
+}
+
+/// xoshiro256++: a fast general purpose generator with 256 bits of state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro256PlusPlus {
+    state: [u64; 4],
+}
+
+impl Xoshiro256PlusPlus {
+    /// Expands `seed` with SplitMix64 so that similar seeds give unrelated
+    /// states and the all-zero state is never produced.
+    pub fn new(seed: u64) -> Self {
+        let mut seeder = SplitMix64::new(seed);
+        Self {
+            state: [
+                seeder.next_u64(),
+                seeder.next_u64(),
+                seeder.next_u64(),
+                seeder.next_u64(),
+            ],
+        }
+    }
+
+    /// Advances by 2^128 steps, giving a non-overlapping sequence for
+    /// another thread.
+    pub fn jump(&mut self) {
+        const JUMP: [u64; 4] = [
+            0x180e_c6d3_3cfd_0aba,
+            0xd5a6_1266_f0c9_392c,
+            0xa958_2618_e03f_c9aa,
+            0x39ab_dc45_29b1_661c,
+        ];
+        let mut jumped = [0u64; 4];
+        for word in JUMP {
+            for bit in 0..64 {
+                if word & (1u64 << bit) != 0 {
+                    for (target, source) in jumped.iter_mut().zip(self.state.iter()) {
+                        *target ^= source;
+                    }
+                }
+                self.next_u64();
+            }
+        }
+        self.state = jumped;
+    }
+}
+
+impl Rng for Xoshiro256PlusPlus {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
@@ -0,0 +1,152 @@
+//! Low-discrepancy sequences.
+//!
+//! Sobol points are produced from 32-bit integer generator matrices exposed
+//! as [`SOBOL_MATRICES`], so a shader that uploads the same table produces
+//! bit-identical samples.
+
+use crate::vector::Vector2d;
+
+/// Bases used by [`halton`], one per dimension.
+pub const HALTON_PRIMES: [u32; 32] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131,
+];
+
+/// Number of dimensions [`sobol`] supports.
+pub const SOBOL_DIMENSIONS: usize = 16;
+
+/// Primitive polynomial degree, coefficients and initial direction numbers
+/// for dimensions 1.. (Joe & Kuo, `new-joe-kuo-6.21201`). Dimension 0 is the
+/// van der Corput sequence.
+const SOBOL_PARAMETERS: [(u32, u32, [u32; 6]); SOBOL_DIMENSIONS - 1] = [
+    (1, 0, [1, 0, 0, 0, 0, 0]),
+    (2, 1, [1, 3, 0, 0, 0, 0]),
+    (3, 1, [1, 3, 1, 0, 0, 0]),
+    (3, 2, [1, 1, 1, 0, 0, 0]),
+    (4, 1, [1, 1, 3, 3, 0, 0]),
+    (4, 4, [1, 3, 5, 13, 0, 0]),
+    (5, 2, [1, 1, 5, 5, 17, 0]),
+    (5, 4, [1, 1, 5, 5, 5, 0]),
+    (5, 7, [1, 1, 7, 11, 19, 0]),
+    (5, 11, [1, 1, 5, 1, 1, 0]),
+    (5, 13, [1, 1, 1, 3, 11, 0]),
+    (5, 14, [1, 3, 5, 5, 31, 0]),
+    (6, 1, [1, 3, 3, 9, 7, 49]),
+    (6, 13, [1, 1, 1, 15, 21, 21]),
+    (6, 16, [1, 3, 1, 13, 27, 49]),
+];
+
+/// Sobol generator matrices, one column per bit of the sample index.
+pub const SOBOL_MATRICES: [[u32; 32]; SOBOL_DIMENSIONS] = build_sobol_matrices();
+
+const fn build_sobol_matrices() -> [[u32; 32]; SOBOL_DIMENSIONS] {
+    let mut matrices = [[0u32; 32]; SOBOL_DIMENSIONS];
+    let mut bit = 0;
+    while bit < 32 {
+        matrices[0][bit] = 1 << (31 - bit);
+        bit += 1;
+    }
+
+    let mut dimension = 1;
+    while dimension < SOBOL_DIMENSIONS {
+        let (degree, coefficients, initial) = SOBOL_PARAMETERS[dimension - 1];
+        let degree = degree as usize;
+        let directions = &mut matrices[dimension];
+        let mut bit = 0;
+        while bit < 32 {
+            if bit < degree {
+                directions[bit] = initial[bit] << (31 - bit);
+            } else {
+                let mut value = directions[bit - degree] ^ (directions[bit - degree] >> degree);
+                let mut k = 1;
+                while k < degree {
+                    if (coefficients >> (degree - 1 - k)) & 1 == 1 {
+                        value ^= directions[bit - k];
+                    }
+                    k += 1;
+                }
+                directions[bit] = value;
+            }
+            bit += 1;
+        }
+        dimension += 1;
+    }
+    matrices
+}
+
+/// Converts a 32-bit fixed point fraction to `[0, 1)`.
+#[inline]
+fn to_unit(bits: u32) -> f64 {
+    bits as f64 * (1.0 / 4_294_967_296.0)
+}
+
+/// Mirrors the digits of `index` in `base` around the radix point.
+pub fn radical_inverse(base: u32, mut index: u64) -> f64 {
+    let base_u64 = base as u64;
+    let inverse_base = 1.0 / base as f64;
+    let mut reversed = 0u64;
+    let mut scale = 1.0;
+    while index > 0 {
+        reversed = reversed * base_u64 + index % base_u64;
+        scale *= inverse_base;
+        index /= base_u64;
+    }
+    (reversed as f64 * scale).min(1.0 - f64::EPSILON / 2.0)
+}
+
+/// Component `dimension` of Halton point `index`.
+///
+/// Panics if `dimension` is not below [`HALTON_PRIMES`]`.len()`.
+pub fn halton(index: u64, dimension: usize) -> f64 {
+    radical_inverse(HALTON_PRIMES[dimension], index)
+}
+
+/// The 2D Halton point (bases 2 and 3).
+pub fn halton2(index: u64) -> Vector2d {
+    Vector2d::new(halton(index, 0), halton(index, 1))
+}
+
+/// Component `dimension` of Sobol point `index` as a 32-bit fraction,
+/// XOR-scrambled by `scramble` (pass 0 for the plain sequence).
+///
+/// Panics if `dimension` is not below [`SOBOL_DIMENSIONS`].
+pub fn sobol_bits(index: u32, dimension: usize, scramble: u32) -> u32 {
+    let matrix = &SOBOL_MATRICES[dimension];
+    let mut result = scramble;
+    let mut index = index;
+    let mut bit = 0;
+    while index != 0 {
+        if index & 1 == 1 {
+            result ^= matrix[bit];
+        }
+        index >>= 1;
+        bit += 1;
+    }
+    result
+}
+
+/// Component `dimension` of Sobol point `index` in `[0, 1)`.
+pub fn sobol(index: u32, dimension: usize) -> f64 {
+    to_unit(sobol_bits(index, dimension, 0))
+}
+
+/// The first two Sobol dimensions, which form a (0, 2)-sequence: every
+/// aligned power-of-two block of points stratifies all elementary intervals.
+pub fn sobol2(index: u32) -> Vector2d {
+    Vector2d::new(sobol(index, 0), sobol(index, 1))
+}
+
+/// Reciprocal of the plastic number, the generator of the R2 sequence.
+const R2_ALPHA: [f64; 2] = [0.754_877_666_246_692_7, 0.569_840_290_998_053_3];
+
+/// Roberts' R2 sequence: an additive recurrence with very even coverage that
+/// needs no tables and can be extended indefinitely.
+pub fn r2(index: u64) -> Vector2d {
+    // Multiply in 64-bit fixed point so large indices do not lose precision.
+    let step = |alpha: f64| {
+        let fixed = (alpha * 18_446_744_073_709_551_616.0) as u64;
+        let bits = (1u64 << 63).wrapping_add(fixed.wrapping_mul(index));
+        (bits >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    };
+    Vector2d::new(step(R2_ALPHA[0]), step(R2_ALPHA[1]))
+}
@@ -0,0 +1,101 @@
+//! Maps uniform samples in `[0, 1)^2` to directions and points.
+//!
+//! Directions are produced in a local frame around +Z; use
+//! [`orthonormal_basis`] or [`to_world`] to orient them about a normal.
+
+use crate::vector::{Vector2, Vector3};
+use num_traits::Float;
+use std::f64::consts::PI;
+
+fn constant<T: Float>(value: f64) -> T {
+    T::from(value).unwrap()
+}
+
+/// Uniform point on the unit disk using Shirley's concentric mapping, which
+/// keeps neighbouring samples close together.
+pub fn uniform_disk<T: Float>(u: &Vector2<T>) -> Vector2<T> {
+    let two = constant::<T>(2.0);
+    let ox = u.x * two - T::one();
+    let oy = u.y * two - T::one();
+    if ox == T::zero() && oy == T::zero() {
+        return Vector2::new(T::zero(), T::zero());
+    }
+
+    let quarter_pi = constant::<T>(PI / 4.0);
+    let (radius, theta) = if ox.abs() > oy.abs() {
+        (ox, quarter_pi * (oy / ox))
+    } else {
+        (oy, constant::<T>(PI / 2.0) - quarter_pi * (ox / oy))
+    };
+    Vector2::new(radius * theta.cos(), radius * theta.sin())
+}
+
+/// Cosine-weighted direction on the +Z hemisphere (Malley's method).
+pub fn cosine_hemisphere<T: Float>(u: &Vector2<T>) -> Vector3<T> {
+    let d = uniform_disk(u);
+    let z = (T::one() - d.x * d.x - d.y * d.y).max(T::zero()).sqrt();
+    Vector3::new(d.x, d.y, z)
+}
+
+pub fn cosine_hemisphere_pdf<T: Float>(cos_theta: T) -> T {
+    cos_theta.max(T::zero()) * constant(1.0 / PI)
+}
+
+/// Uniform direction on the +Z hemisphere.
+pub fn uniform_hemisphere<T: Float>(u: &Vector2<T>) -> Vector3<T> {
+    let z = u.x;
+    let r = (T::one() - z * z).max(T::zero()).sqrt();
+    let phi = constant::<T>(2.0 * PI) * u.y;
+    Vector3::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+pub fn uniform_hemisphere_pdf<T: Float>() -> T {
+    constant(1.0 / (2.0 * PI))
+}
+
+/// Uniform direction on the unit sphere.
+pub fn uniform_sphere<T: Float>(u: &Vector2<T>) -> Vector3<T> {
+    let z = T::one() - constant::<T>(2.0) * u.x;
+    let r = (T::one() - z * z).max(T::zero()).sqrt();
+    let phi = constant::<T>(2.0 * PI) * u.y;
+    Vector3::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+pub fn uniform_sphere_pdf<T: Float>() -> T {
+    constant(1.0 / (4.0 * PI))
+}
+
+/// Uniform direction inside the cone around +Z whose half-angle has cosine
+/// `cos_theta_max`, e.g. for sampling a spherical light.
+pub fn uniform_cone<T: Float>(u: &Vector2<T>, cos_theta_max: T) -> Vector3<T> {
+    let z = T::one() - u.x * (T::one() - cos_theta_max);
+    let r = (T::one() - z * z).max(T::zero()).sqrt();
+    let phi = constant::<T>(2.0 * PI) * u.y;
+    Vector3::new(r * phi.cos(), r * phi.sin(), z)
+}
+
+pub fn uniform_cone_pdf<T: Float>(cos_theta_max: T) -> T {
+    T::one() / (constant::<T>(2.0 * PI) * (T::one() - cos_theta_max))
+}
+
+/// Tangent and bitangent completing a right-handed frame around the unit
+/// vector `normal` (Duff et al. 2017, branchless and continuous except at
+/// the sign flip of `normal.z`).
+pub fn orthonormal_basis<T: Float>(normal: &Vector3<T>) -> (Vector3<T>, Vector3<T>) {
+    let sign = T::one().copysign(normal.z);
+    let a = -T::one() / (sign + normal.z);
+    let b = normal.x * normal.y * a;
+    let tangent = Vector3::new(
+        T::one() + sign * normal.x * normal.x * a,
+        sign * b,
+        -sign * normal.x,
+    );
+    let bitangent = Vector3::new(b, sign + normal.y * normal.y * a, -normal.y);
+    (tangent, bitangent)
+}
+
+/// Rotates a direction sampled around +Z so that +Z maps to `normal`.
+pub fn to_world<T: Float>(local: &Vector3<T>, normal: &Vector3<T>) -> Vector3<T> {
+    let (tangent, bitangent) = orthonormal_basis(normal);
+    tangent * local.x + bitangent * local.y + *normal * local.z
+}
@@ -0,0 +1,273 @@
+//! `Serialize` and `Deserialize` for hmath's value types.
+//!
+//! Binary formats (`is_human_readable() == false`, e.g. bincode) get a bare
+//! tuple of the fields in declaration order, so a `Vector3<f32>` is twelve
+//! bytes. Human-readable formats get a struct with named fields, such as
+//! `{"x":1.0,"y":2.0,"z":3.0}`; arrays are accepted there as well. Matrices
+//! keep their flat `data` array in both.
+//!
+//! Types with internal invariants (splines, noise tables, generators) are not
+//! serialized; store the inputs they were built from instead.
+
+use crate::color::hsv::{Hsla, Hsva};
+use crate::color::linear::LinearRgba;
+use crate::color::oklab::Oklab;
+use crate::color::srgb::Srgba;
+use crate::curve::bezier::{CubicBezier, QuadraticBezier};
+use crate::curve::hermite::Hermite;
+use crate::fixed::Fixed64;
+use crate::geometry::aabb::Aabb;
+use crate::geometry::frustum::Frustum;
+use crate::geometry::obb::Obb;
+use crate::geometry::plane::Plane;
+use crate::geometry::ray::Ray;
+use crate::geometry::sphere::Sphere;
+use crate::geometry::triangle::Triangle;
+use crate::geometry::voxel_traversal::VoxelHit;
+use crate::matrix::{Matrix2, Matrix3, Matrix4};
+use crate::noise::fractal::FractalConfig;
+use crate::quaternion::Quaternion;
+use crate::transform::Transform;
+use crate::vector::{Vector2, Vector3, Vector4};
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, SerializeTuple};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Implements both traits for a struct with public fields, optionally
+/// generic over a single scalar type.
+macro_rules! impl_serde {
+    ($name:ident $(<$t:ident>)?, [$($field:ident),+ $(,)?]) => {
+        impl$(<$t: Serialize>)? Serialize for $name$(<$t>)? {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),+];
+                if serializer.is_human_readable() {
+                    let mut state = serializer.serialize_struct(stringify!($name), FIELDS.len())?;
+                    $(state.serialize_field(stringify!($field), &self.$field)?;)+
+                    state.end()
+                } else {
+                    let mut state = serializer.serialize_tuple(FIELDS.len())?;
+                    $(state.serialize_element(&self.$field)?;)+
+                    state.end()
+                }
+            }
+        }
+
+        impl<'de, $($t: Deserialize<'de>)?> Deserialize<'de> for $name$(<$t>)? {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),+];
+
+                struct FieldVisitor$(<$t>)?(PhantomData<fn() -> $name$(<$t>)?>);
+
+                impl<'de, $($t: Deserialize<'de>)?> Visitor<'de> for FieldVisitor$(<$t>)? {
+                    type Value = $name$(<$t>)?;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(formatter, "struct {}", stringify!($name))
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                        $(
+                            let $field = seq.next_element()?.ok_or_else(|| {
+                                let read = FIELDS.iter().position(|name| *name == stringify!($field));
+                                de::Error::invalid_length(read.unwrap(), &self)
+                            })?;
+                        )+
+                        Ok($name { $($field),+ })
+                    }
+
+                    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                        $(let mut $field = None;)+
+                        while let Some(key) = map.next_key::<String>()? {
+                            match key.as_str() {
+                                $(
+                                    stringify!($field) => {
+                                        if $field.is_some() {
+                                            return Err(de::Error::duplicate_field(stringify!($field)));
+                                        }
+                                        $field = Some(map.next_value()?);
+                                    }
+                                )+
+                                other => return Err(de::Error::unknown_field(other, FIELDS)),
+                            }
+                        }
+                        $(
+                            let $field = $field
+                                .ok_or_else(|| de::Error::missing_field(stringify!($field)))?;
+                        )+
+                        Ok($name { $($field),+ })
+                    }
+                }
+
+                let visitor = FieldVisitor(PhantomData);
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_struct(stringify!($name), FIELDS, visitor)
+                } else {
+                    deserializer.deserialize_tuple(FIELDS.len(), visitor)
+                }
+            }
+        }
+    };
+}
+
+impl_serde!(Vector2<T>, [x, y]);
+impl_serde!(Vector3<T>, [x, y, z]);
+impl_serde!(Vector4<T>, [x, y, z, w]);
+impl_serde!(Matrix2<T>, [data]);
+impl_serde!(Matrix3<T>, [data]);
+impl_serde!(Matrix4<T>, [data]);
+impl_serde!(Quaternion<T>, [w, x, y, z]);
+impl_serde!(Transform<T>, [translation, rotation, scale]);
+
+impl_serde!(Aabb<T>, [min, max]);
+impl_serde!(Frustum<T>, [planes]);
+impl_serde!(Obb<T>, [center, half_extents, rotation]);
+impl_serde!(Plane<T>, [normal, d]);
+impl_serde!(Ray<T>, [origin, direction]);
+impl_serde!(Sphere<T>, [center, radius]);
+impl_serde!(Triangle<T>, [a, b, c]);
+impl_serde!(VoxelHit<T>, [cell, distance, normal]);
+
+impl_serde!(QuadraticBezier<T>, [p0, p1, p2]);
+impl_serde!(CubicBezier<T>, [p0, p1, p2, p3]);
+impl_serde!(Hermite<T>, [p0, m0, p1, m1]);
+
+impl_serde!(LinearRgba, [r, g, b, a]);
+impl_serde!(Srgba, [r, g, b, a]);
+impl_serde!(Hsva, [h, s, v, a]);
+impl_serde!(Hsla, [h, s, l, a]);
+impl_serde!(Oklab, [l, a, b, alpha]);
+
+impl_serde!(FractalConfig, [octaves, frequency, lacunarity, gain]);
+
+/// `5^32`: one unit of `2^-32` is exactly `5^32 / 10^32`, so the fraction of
+/// a [`Fixed64`] always has an exact 32-digit decimal expansion.
+const FIVE_POW_32: u128 = 23_283_064_365_386_962_890_625;
+
+/// Exact decimal text for a fixed-point value, without trailing zeros.
+fn fixed_to_decimal(value: Fixed64) -> String {
+    let bits = value.to_bits();
+    let magnitude = bits.unsigned_abs();
+    let whole = magnitude >> 32;
+    let fraction = (magnitude & 0xffff_ffff) as u128 * FIVE_POW_32;
+    let sign = if bits < 0 { "-" } else { "" };
+    let digits = format!("{:032}", fraction);
+    let digits = digits.trim_end_matches('0');
+    if digits.is_empty() {
+        format!("{}{}", sign, whole)
+    } else {
+        format!("{}{}.{}", sign, whole, digits)
+    }
+}
+
+/// Parses decimal text, rounding to the nearest representable value.
+/// Returns `None` for malformed or out-of-range input.
+fn fixed_from_decimal(text: &str) -> Option<Fixed64> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (whole_text, fraction_text) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if whole_text.is_empty() && fraction_text.is_empty() {
+        return None;
+    }
+    if !is_digits(whole_text) || !is_digits(fraction_text) {
+        return None;
+    }
+
+    let whole: u128 = if whole_text.is_empty() {
+        0
+    } else {
+        whole_text.parse().ok()?
+    };
+    // Digits beyond the 33rd cannot change the rounded result.
+    let mut scaled: u128 = 0;
+    for position in 0..33 {
+        let digit = fraction_text
+            .as_bytes()
+            .get(position)
+            .map_or(0, |b| b - b'0');
+        scaled = scaled * 10 + digit as u128;
+    }
+    // `scaled / 10^33 * 2^32` simplifies to `scaled / (10 * 5^32)`.
+    let divisor = 10 * FIVE_POW_32;
+    let fraction = (scaled + divisor / 2) / divisor;
+
+    let magnitude = whole.checked_mul(1 << 32)?.checked_add(fraction)?;
+    let bits = if negative {
+        if magnitude > 1 << 63 {
+            return None;
+        }
+        (magnitude as i128).wrapping_neg() as i64
+    } else {
+        i64::try_from(magnitude).ok()?
+    };
+    Some(Fixed64::from_bits(bits))
+}
+
+/// Binary formats store the raw bits. Human-readable formats write a plain
+/// number when `f64` holds the value exactly, and otherwise its exact decimal
+/// expansion as a string, so a round trip never changes a single bit.
+impl Serialize for Fixed64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_i64(self.to_bits());
+        }
+        // Saturation in `from_f64` would hide rounding near the limits, so
+        // count significant bits instead of round-tripping.
+        let magnitude = self.to_bits().unsigned_abs();
+        if magnitude == 0 || magnitude >> magnitude.trailing_zeros() < 1 << f64::MANTISSA_DIGITS {
+            serializer.serialize_f64(Fixed64::to_f64(*self))
+        } else {
+            serializer.serialize_str(&fixed_to_decimal(*self))
+        }
+    }
+}
+
+struct Fixed64Visitor;
+
+impl Visitor<'_> for Fixed64Visitor {
+    type Value = Fixed64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a Q32.32 fixed-point number")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Fixed64, E> {
+        i32::try_from(value)
+            .map(Fixed64::from_int)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Fixed64, E> {
+        i32::try_from(value)
+            .map(Fixed64::from_int)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Fixed64, E> {
+        if (-2_147_483_648.0..2_147_483_648.0).contains(&value) {
+            Ok(Fixed64::from_f64(value))
+        } else {
+            Err(E::invalid_value(de::Unexpected::Float(value), &self))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Fixed64, E> {
+        fixed_from_decimal(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Reads the raw bits from binary formats; human-readable ones accept
+/// integers, floats and decimal strings.
+impl<'de> Deserialize<'de> for Fixed64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(Fixed64Visitor)
+        } else {
+            i64::deserialize(deserializer).map(Fixed64::from_bits)
+        }
+    }
+}
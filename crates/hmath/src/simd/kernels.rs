@@ -0,0 +1,216 @@
+use crate::matrix::Matrix4x4;
+use crate::vector::Vector3f;
+
+/// Four `f32` lanes. Every kernel is written once against this trait and
+/// instantiated per instruction set, so all backends execute the same IEEE
+/// operations in the same order and agree bit for bit.
+pub(super) trait Lane4: Copy {
+    fn from_array(values: [f32; 4]) -> Self;
+    fn to_array(self) -> [f32; 4];
+    fn splat(value: f32) -> Self;
+    fn add(self, other: Self) -> Self;
+    fn sub(self, other: Self) -> Self;
+    fn mul(self, other: Self) -> Self;
+    fn div(self, other: Self) -> Self;
+
+    /// `[a[x], a[y], b[z], b[w]]` for `MASK = shuffle_mask(x, y, z, w)`,
+    /// matching `_mm_shuffle_ps`.
+    #[inline(always)]
+    fn shuffle<const MASK: i32>(a: Self, b: Self) -> Self {
+        let (a, b) = (a.to_array(), b.to_array());
+        let lane = |shift: i32| ((MASK >> shift) & 3) as usize;
+        Self::from_array([a[lane(0)], a[lane(2)], b[lane(4)], b[lane(6)]])
+    }
+}
+
+pub(super) const fn shuffle_mask(x: i32, y: i32, z: i32, w: i32) -> i32 {
+    x | (y << 2) | (z << 4) | (w << 6)
+}
+
+/// The portable fallback.
+#[derive(Clone, Copy)]
+pub(super) struct Scalar([f32; 4]);
+
+impl Lane4 for Scalar {
+    #[inline(always)]
+    fn from_array(values: [f32; 4]) -> Self {
+        Scalar(values)
+    }
+
+    #[inline(always)]
+    fn to_array(self) -> [f32; 4] {
+        self.0
+    }
+
+    #[inline(always)]
+    fn splat(value: f32) -> Self {
+        Scalar([value; 4])
+    }
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        Scalar(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        Scalar(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+
+    #[inline(always)]
+    fn mul(self, other: Self) -> Self {
+        Scalar(std::array::from_fn(|i| self.0[i] * other.0[i]))
+    }
+
+    #[inline(always)]
+    fn div(self, other: Self) -> Self {
+        Scalar(std::array::from_fn(|i| self.0[i] / other.0[i]))
+    }
+}
+
+#[inline(always)]
+pub(super) fn row<V: Lane4>(m: &Matrix4x4, i: usize) -> V {
+    let d = &m.data;
+    V::from_array([d[i * 4], d[i * 4 + 1], d[i * 4 + 2], d[i * 4 + 3]])
+}
+
+#[inline(always)]
+fn swizzle<V: Lane4, const MASK: i32>(v: V) -> V {
+    V::shuffle::<MASK>(v, v)
+}
+
+#[inline(always)]
+pub(super) fn multiply<V: Lane4>(a: &Matrix4x4, b: &Matrix4x4) -> Matrix4x4 {
+    let rows: [V; 4] = std::array::from_fn(|k| row(b, k));
+    let mut data = [0.0; 16];
+    for i in 0..4 {
+        let a_row = &a.data[i * 4..i * 4 + 4];
+        let mut sum = V::splat(a_row[0]).mul(rows[0]);
+        for k in 1..4 {
+            sum = sum.add(V::splat(a_row[k]).mul(rows[k]));
+        }
+        data[i * 4..i * 4 + 4].copy_from_slice(&sum.to_array());
+    }
+    Matrix4x4 { data }
+}
+
+/// `a * b` for 2x2 matrices stored row-major in four lanes.
+#[inline(always)]
+fn mat2_mul<V: Lane4>(a: V, b: V) -> V {
+    let first = a.mul(swizzle::<V, { shuffle_mask(0, 3, 0, 3) }>(b));
+    let second = swizzle::<V, { shuffle_mask(1, 0, 3, 2) }>(a)
+        .mul(swizzle::<V, { shuffle_mask(2, 1, 2, 1) }>(b));
+    first.add(second)
+}
+
+/// `adj(a) * b` for 2x2 matrices.
+#[inline(always)]
+fn mat2_adj_mul<V: Lane4>(a: V, b: V) -> V {
+    let first = swizzle::<V, { shuffle_mask(3, 3, 0, 0) }>(a).mul(b);
+    let second = swizzle::<V, { shuffle_mask(1, 1, 2, 2) }>(a)
+        .mul(swizzle::<V, { shuffle_mask(2, 3, 0, 1) }>(b));
+    first.sub(second)
+}
+
+/// `a * adj(b)` for 2x2 matrices.
+#[inline(always)]
+fn mat2_mul_adj<V: Lane4>(a: V, b: V) -> V {
+    let first = a.mul(swizzle::<V, { shuffle_mask(3, 0, 3, 0) }>(b));
+    let second = swizzle::<V, { shuffle_mask(1, 0, 3, 2) }>(a)
+        .mul(swizzle::<V, { shuffle_mask(2, 1, 2, 1) }>(b));
+    first.sub(second)
+}
+
+/// General inverse by splitting the matrix into 2x2 blocks
+/// `[[A, B], [C, D]]` and applying the block inversion formula with
+/// adjugates, which needs a single division.
+#[inline(always)]
+pub(super) fn inverse<V: Lane4>(m: &Matrix4x4) -> Option<Matrix4x4> {
+    let rows: [V; 4] = std::array::from_fn(|i| row(m, i));
+    let a = V::shuffle::<{ shuffle_mask(0, 1, 0, 1) }>(rows[0], rows[1]);
+    let b = V::shuffle::<{ shuffle_mask(2, 3, 2, 3) }>(rows[0], rows[1]);
+    let c = V::shuffle::<{ shuffle_mask(0, 1, 0, 1) }>(rows[2], rows[3]);
+    let d = V::shuffle::<{ shuffle_mask(2, 3, 2, 3) }>(rows[2], rows[3]);
+
+    // (|A|, |B|, |C|, |D|)
+    let even_top = V::shuffle::<{ shuffle_mask(0, 2, 0, 2) }>(rows[0], rows[2]);
+    let odd_top = V::shuffle::<{ shuffle_mask(1, 3, 1, 3) }>(rows[0], rows[2]);
+    let even_bottom = V::shuffle::<{ shuffle_mask(0, 2, 0, 2) }>(rows[1], rows[3]);
+    let odd_bottom = V::shuffle::<{ shuffle_mask(1, 3, 1, 3) }>(rows[1], rows[3]);
+    let determinants = even_top.mul(odd_bottom).sub(odd_top.mul(even_bottom));
+    let det_a = swizzle::<V, { shuffle_mask(0, 0, 0, 0) }>(determinants);
+    let det_b = swizzle::<V, { shuffle_mask(1, 1, 1, 1) }>(determinants);
+    let det_c = swizzle::<V, { shuffle_mask(2, 2, 2, 2) }>(determinants);
+    let det_d = swizzle::<V, { shuffle_mask(3, 3, 3, 3) }>(determinants);
+
+    let d_c = mat2_adj_mul(d, c);
+    let a_b = mat2_adj_mul(a, b);
+    // Adjugates of the result blocks, scaled by |M|.
+    let x = det_d.mul(a).sub(mat2_mul(b, d_c));
+    let w = det_a.mul(d).sub(mat2_mul(c, a_b));
+    let y = det_b.mul(c).sub(mat2_mul_adj(d, a_b));
+    let z = det_c.mul(b).sub(mat2_mul_adj(a, d_c));
+
+    // |M| = |A||D| + |B||C| - tr(adj(A)B adj(D)C)
+    let trace = a_b.mul(swizzle::<V, { shuffle_mask(0, 2, 1, 3) }>(d_c));
+    let trace = trace.add(swizzle::<V, { shuffle_mask(2, 3, 0, 1) }>(trace));
+    let trace = trace.add(swizzle::<V, { shuffle_mask(1, 0, 3, 2) }>(trace));
+    let det = det_a.mul(det_d).add(det_b.mul(det_c)).sub(trace);
+    if det.to_array()[0] == 0.0 {
+        return None;
+    }
+
+    let reciprocal = V::from_array([1.0, -1.0, -1.0, 1.0]).div(det);
+    let (x, y, z, w) = (
+        x.mul(reciprocal),
+        y.mul(reciprocal),
+        z.mul(reciprocal),
+        w.mul(reciprocal),
+    );
+
+    // Undo the adjugates and interleave the blocks back into rows.
+    let result = [
+        V::shuffle::<{ shuffle_mask(3, 1, 3, 1) }>(x, y),
+        V::shuffle::<{ shuffle_mask(2, 0, 2, 0) }>(x, y),
+        V::shuffle::<{ shuffle_mask(3, 1, 3, 1) }>(z, w),
+        V::shuffle::<{ shuffle_mask(2, 0, 2, 0) }>(z, w),
+    ];
+    let mut data = [0.0; 16];
+    for (i, r) in result.iter().enumerate() {
+        data[i * 4..i * 4 + 4].copy_from_slice(&r.to_array());
+    }
+    Some(Matrix4x4 { data })
+}
+
+/// Same operation order as [`Matrix4x4::transform_point`], so the results
+/// match it exactly.
+#[inline(always)]
+pub(super) fn transform_points<V: Lane4>(m: &Matrix4x4, points: &[Vector3f], out: &mut [Vector3f]) {
+    let rows: [V; 4] = std::array::from_fn(|i| row(m, i));
+    for (point, result) in points.iter().zip(out.iter_mut()) {
+        let v = V::splat(point.x)
+            .mul(rows[0])
+            .add(V::splat(point.y).mul(rows[1]))
+            .add(V::splat(point.z).mul(rows[2]))
+            .add(rows[3])
+            .to_array();
+        *result = Vector3f::new(v[0], v[1], v[2]);
+    }
+}
+
+#[inline(always)]
+pub(super) fn transform_vectors<V: Lane4>(
+    m: &Matrix4x4,
+    vectors: &[Vector3f],
+    out: &mut [Vector3f],
+) {
+    let rows: [V; 3] = std::array::from_fn(|i| row(m, i));
+    for (vector, result) in vectors.iter().zip(out.iter_mut()) {
+        let v = V::splat(vector.x)
+            .mul(rows[0])
+            .add(V::splat(vector.y).mul(rows[1]))
+            .add(V::splat(vector.z).mul(rows[2]))
+            .to_array();
+        *result = Vector3f::new(v[0], v[1], v[2]);
+    }
+}
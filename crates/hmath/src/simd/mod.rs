@@ -0,0 +1,176 @@
+//! SIMD kernels for single-precision matrices and batches of vectors.
+//!
+//! The free functions run on the fastest [`Backend`] the CPU supports,
+//! detected once at first use. Every backend produces bit-identical results,
+//! and the batch transforms match [`Matrix4x4::transform_point`] and
+//! [`Matrix4x4::transform_vector`] exactly.
+
+mod kernels;
+#[cfg(target_arch = "aarch64")]
+mod neon;
+#[cfg(target_arch = "x86_64")]
+mod x86;
+
+use crate::matrix::Matrix4x4;
+use crate::vector::Vector3f;
+use kernels::Scalar;
+use std::sync::OnceLock;
+
+/// An instruction set the kernels can run on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// Portable code for any target.
+    Scalar,
+    /// x86-64 baseline.
+    Sse2,
+    /// 256-bit x86-64 extension, detected at runtime.
+    Avx,
+    /// AArch64 baseline.
+    Neon,
+}
+
+impl Backend {
+    pub const ALL: [Backend; 4] = [Backend::Scalar, Backend::Sse2, Backend::Avx, Backend::Neon];
+
+    /// The fastest backend supported by the running CPU.
+    pub fn detect() -> Self {
+        static DETECTED: OnceLock<Backend> = OnceLock::new();
+        *DETECTED.get_or_init(|| {
+            [Backend::Avx, Backend::Sse2, Backend::Neon]
+                .into_iter()
+                .find(|backend| backend.is_supported())
+                .unwrap_or(Backend::Scalar)
+        })
+    }
+
+    pub fn is_supported(self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            Backend::Sse2 => cfg!(target_arch = "x86_64"),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx => std::arch::is_x86_feature_detected!("avx"),
+            #[cfg(not(target_arch = "x86_64"))]
+            Backend::Avx => false,
+            Backend::Neon => cfg!(target_arch = "aarch64"),
+        }
+    }
+
+    /// Unsupported backends fall back to [`Backend::Scalar`].
+    fn resolve(self) -> Self {
+        if self.is_supported() {
+            self
+        } else {
+            Backend::Scalar
+        }
+    }
+
+    /// `a * b`, the same product as [`Matrix4x4::multiply`].
+    pub fn multiply(self, a: &Matrix4x4, b: &Matrix4x4) -> Matrix4x4 {
+        match self.resolve() {
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => kernels::multiply::<x86::Sse>(a, b),
+            // SAFETY: `resolve` checked that the CPU supports AVX.
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx => unsafe { x86::multiply_avx(a, b) },
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => kernels::multiply::<neon::Neon>(a, b),
+            _ => kernels::multiply::<Scalar>(a, b),
+        }
+    }
+
+    /// Multiplies corresponding elements, e.g. local transforms by their
+    /// parents' world transforms.
+    ///
+    /// Panics if the slices differ in length.
+    pub fn multiply_batch(self, a: &[Matrix4x4], b: &[Matrix4x4], out: &mut [Matrix4x4]) {
+        assert!(
+            a.len() == b.len() && a.len() == out.len(),
+            "slice lengths differ"
+        );
+        match self.resolve() {
+            // SAFETY: `resolve` checked that the CPU supports AVX.
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx => unsafe { x86::multiply_batch_avx(a, b, out) },
+            backend => {
+                for ((a, b), result) in a.iter().zip(b).zip(out.iter_mut()) {
+                    *result = backend.multiply(a, b);
+                }
+            }
+        }
+    }
+
+    /// The general inverse, or `None` if the matrix is singular. Rounding
+    /// differs slightly from [`Matrix4x4::inverse`], which uses another
+    /// formula.
+    pub fn inverse(self, m: &Matrix4x4) -> Option<Matrix4x4> {
+        match self.resolve() {
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => kernels::inverse::<x86::Sse>(m),
+            // SAFETY: `resolve` checked that the CPU supports AVX.
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx => unsafe { x86::inverse_avx(m) },
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => kernels::inverse::<neon::Neon>(m),
+            _ => kernels::inverse::<Scalar>(m),
+        }
+    }
+
+    /// [`Matrix4x4::transform_point`] over a slice.
+    ///
+    /// Panics if the slices differ in length.
+    pub fn transform_points(self, m: &Matrix4x4, points: &[Vector3f], out: &mut [Vector3f]) {
+        assert_eq!(points.len(), out.len(), "slice lengths differ");
+        match self.resolve() {
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => kernels::transform_points::<x86::Sse>(m, points, out),
+            // SAFETY: `resolve` checked that the CPU supports AVX.
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx => unsafe { x86::transform_points_avx(m, points, out) },
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => kernels::transform_points::<neon::Neon>(m, points, out),
+            _ => kernels::transform_points::<Scalar>(m, points, out),
+        }
+    }
+
+    /// [`Matrix4x4::transform_vector`] over a slice.
+    ///
+    /// Panics if the slices differ in length.
+    pub fn transform_vectors(self, m: &Matrix4x4, vectors: &[Vector3f], out: &mut [Vector3f]) {
+        assert_eq!(vectors.len(), out.len(), "slice lengths differ");
+        match self.resolve() {
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse2 => kernels::transform_vectors::<x86::Sse>(m, vectors, out),
+            // SAFETY: `resolve` checked that the CPU supports AVX.
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx => unsafe { x86::transform_vectors_avx(m, vectors, out) },
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => kernels::transform_vectors::<neon::Neon>(m, vectors, out),
+            _ => kernels::transform_vectors::<Scalar>(m, vectors, out),
+        }
+    }
+}
+
+/// [`Backend::multiply`] on the detected backend.
+pub fn multiply(a: &Matrix4x4, b: &Matrix4x4) -> Matrix4x4 {
+    Backend::detect().multiply(a, b)
+}
+
+/// [`Backend::multiply_batch`] on the detected backend.
+pub fn multiply_batch(a: &[Matrix4x4], b: &[Matrix4x4], out: &mut [Matrix4x4]) {
+    Backend::detect().multiply_batch(a, b, out)
+}
+
+/// [`Backend::inverse`] on the detected backend.
+pub fn inverse(m: &Matrix4x4) -> Option<Matrix4x4> {
+    Backend::detect().inverse(m)
+}
+
+/// [`Backend::transform_points`] on the detected backend.
+pub fn transform_points(m: &Matrix4x4, points: &[Vector3f], out: &mut [Vector3f]) {
+    Backend::detect().transform_points(m, points, out)
+}
+
+/// [`Backend::transform_vectors`] on the detected backend.
+pub fn transform_vectors(m: &Matrix4x4, vectors: &[Vector3f], out: &mut [Vector3f]) {
+    Backend::detect().transform_vectors(m, vectors, out)
+}
@@ -0,0 +1,56 @@
+use super::kernels::Lane4;
+use std::arch::aarch64::*;
+
+/// NEON, which every AArch64 CPU has; that is what makes the intrinsic
+/// calls below sound. Shuffles use the trait's portable default, which the
+/// compiler lowers to lane moves.
+#[derive(Clone, Copy)]
+pub(super) struct Neon(float32x4_t);
+
+impl Lane4 for Neon {
+    #[inline(always)]
+    fn from_array(values: [f32; 4]) -> Self {
+        // SAFETY: reads four floats from a four-element array.
+        Neon(unsafe { vld1q_f32(values.as_ptr()) })
+    }
+
+    #[inline(always)]
+    fn to_array(self) -> [f32; 4] {
+        let mut values = [0.0; 4];
+        // SAFETY: writes four floats into a four-element array.
+        unsafe { vst1q_f32(values.as_mut_ptr(), self.0) };
+        values
+    }
+
+    #[inline(always)]
+    fn splat(value: f32) -> Self {
+        // SAFETY: NEON is always available on AArch64, the only target this module builds for.
+        Neon(unsafe { vdupq_n_f32(value) })
+    }
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        // SAFETY: NEON is always available on AArch64, the only target this module builds for.
+        Neon(unsafe { vaddq_f32(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        // SAFETY: NEON is always available on AArch64, the only target this module builds for.
+        Neon(unsafe { vsubq_f32(self.0, other.0) })
+    }
+
+    /// A plain multiply rather than `vmlaq`/`vfmaq`, so results stay
+    /// identical to the other backends.
+    #[inline(always)]
+    fn mul(self, other: Self) -> Self {
+        // SAFETY: NEON is always available on AArch64, the only target this module builds for.
+        Neon(unsafe { vmulq_f32(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn div(self, other: Self) -> Self {
+        // SAFETY: NEON is always available on AArch64, the only target this module builds for.
+        Neon(unsafe { vdivq_f32(self.0, other.0) })
+    }
+}
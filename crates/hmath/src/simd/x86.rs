@@ -0,0 +1,137 @@
+use super::kernels::{self, Lane4};
+use crate::matrix::Matrix4x4;
+use crate::vector::Vector3f;
+use std::arch::x86_64::*;
+
+/// SSE2, which every x86-64 CPU has; that is what makes the intrinsic calls
+/// below sound.
+#[derive(Clone, Copy)]
+pub(super) struct Sse(__m128);
+
+impl Lane4 for Sse {
+    #[inline(always)]
+    fn from_array(values: [f32; 4]) -> Self {
+        // SAFETY: reads four floats from a four-element array.
+        Sse(unsafe { _mm_loadu_ps(values.as_ptr()) })
+    }
+
+    #[inline(always)]
+    fn to_array(self) -> [f32; 4] {
+        let mut values = [0.0; 4];
+        // SAFETY: writes four floats into a four-element array.
+        unsafe { _mm_storeu_ps(values.as_mut_ptr(), self.0) };
+        values
+    }
+
+    #[inline(always)]
+    fn splat(value: f32) -> Self {
+        // SAFETY: SSE2 is always available on x86-64, the only target this module builds for.
+        Sse(unsafe { _mm_set1_ps(value) })
+    }
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        // SAFETY: SSE2 is always available on x86-64, the only target this module builds for.
+        Sse(unsafe { _mm_add_ps(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        // SAFETY: SSE2 is always available on x86-64, the only target this module builds for.
+        Sse(unsafe { _mm_sub_ps(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn mul(self, other: Self) -> Self {
+        // SAFETY: SSE2 is always available on x86-64, the only target this module builds for.
+        Sse(unsafe { _mm_mul_ps(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn div(self, other: Self) -> Self {
+        // SAFETY: SSE2 is always available on x86-64, the only target this module builds for.
+        Sse(unsafe { _mm_div_ps(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    fn shuffle<const MASK: i32>(a: Self, b: Self) -> Self {
+        // SAFETY: SSE2 is always available on x86-64, the only target this module builds for.
+        Sse(unsafe { _mm_shuffle_ps::<MASK>(a.0, b.0) })
+    }
+}
+
+/// Computes two result rows per iteration with 256-bit registers. The sums
+/// are accumulated in the same order as the four-lane kernel.
+#[target_feature(enable = "avx")]
+pub(super) fn multiply_avx(a: &Matrix4x4, b: &Matrix4x4) -> Matrix4x4 {
+    let rows: [__m128; 4] = std::array::from_fn(|k| kernels::row::<Sse>(b, k).0);
+    let rows = rows.map(|r| _mm256_broadcast_ps(&r));
+    let mut data = [0.0; 16];
+    for pair in 0..2 {
+        let first = &a.data[pair * 8..pair * 8 + 4];
+        let second = &a.data[pair * 8 + 4..pair * 8 + 8];
+        let coefficient =
+            |k: usize| _mm256_setr_m128(_mm_set1_ps(first[k]), _mm_set1_ps(second[k]));
+        let mut sum = _mm256_mul_ps(coefficient(0), rows[0]);
+        for (k, row) in rows.iter().enumerate().skip(1) {
+            sum = _mm256_add_ps(sum, _mm256_mul_ps(coefficient(k), *row));
+        }
+        // SAFETY: writes eight floats starting at index 0 or 8 of a
+        // sixteen-element array.
+        unsafe { _mm256_storeu_ps(data.as_mut_ptr().add(pair * 8), sum) };
+    }
+    Matrix4x4 { data }
+}
+
+#[target_feature(enable = "avx")]
+pub(super) fn multiply_batch_avx(a: &[Matrix4x4], b: &[Matrix4x4], out: &mut [Matrix4x4]) {
+    for ((a, b), result) in a.iter().zip(b).zip(out.iter_mut()) {
+        *result = multiply_avx(a, b);
+    }
+}
+
+/// Lets the four-lane kernels be compiled with VEX encoding.
+#[target_feature(enable = "avx")]
+pub(super) fn inverse_avx(m: &Matrix4x4) -> Option<Matrix4x4> {
+    kernels::inverse::<Sse>(m)
+}
+
+/// Transforms two vectors per iteration, adding the translation row for
+/// points.
+#[target_feature(enable = "avx")]
+fn transform_avx(m: &Matrix4x4, translate: bool, input: &[Vector3f], out: &mut [Vector3f]) {
+    let rows: [__m128; 4] = std::array::from_fn(|i| kernels::row::<Sse>(m, i).0);
+    let rows = rows.map(|r| _mm256_broadcast_ps(&r));
+    let pairs = input.chunks_exact(2).zip(out.chunks_exact_mut(2));
+    for (pair, result) in pairs {
+        let lanes = |a: f32, b: f32| _mm256_setr_m128(_mm_set1_ps(a), _mm_set1_ps(b));
+        let mut sum = _mm256_mul_ps(lanes(pair[0].x, pair[1].x), rows[0]);
+        sum = _mm256_add_ps(sum, _mm256_mul_ps(lanes(pair[0].y, pair[1].y), rows[1]));
+        sum = _mm256_add_ps(sum, _mm256_mul_ps(lanes(pair[0].z, pair[1].z), rows[2]));
+        if translate {
+            sum = _mm256_add_ps(sum, rows[3]);
+        }
+        let mut values = [0.0f32; 8];
+        // SAFETY: writes eight floats into an eight-element array.
+        unsafe { _mm256_storeu_ps(values.as_mut_ptr(), sum) };
+        result[0] = Vector3f::new(values[0], values[1], values[2]);
+        result[1] = Vector3f::new(values[4], values[5], values[6]);
+    }
+
+    let done = input.len().min(out.len()) & !1;
+    if translate {
+        kernels::transform_points::<Sse>(m, &input[done..], &mut out[done..]);
+    } else {
+        kernels::transform_vectors::<Sse>(m, &input[done..], &mut out[done..]);
+    }
+}
+
+#[target_feature(enable = "avx")]
+pub(super) fn transform_points_avx(m: &Matrix4x4, points: &[Vector3f], out: &mut [Vector3f]) {
+    transform_avx(m, true, points, out);
+}
+
+#[target_feature(enable = "avx")]
+pub(super) fn transform_vectors_avx(m: &Matrix4x4, vectors: &[Vector3f], out: &mut [Vector3f]) {
+    transform_avx(m, false, vectors, out);
+}
@@ -0,0 +1,2 @@
+pub mod hilbert;
+pub mod morton;
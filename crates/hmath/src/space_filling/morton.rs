@@ -0,0 +1,230 @@
+//! Morton (Z-order) codes: the bits of each coordinate interleaved, x in
+//! the least significant position. Sorting by code visits space in a
+//! recursive Z pattern, and each group of `D` high bits addresses one level
+//! of a quadtree or octree.
+//!
+//! Coordinates wider than a key's bit budget are truncated: 16 bits per
+//! axis for 2D `u32` keys, 32 for 2D `u64`, 10 for 3D `u32` and 21 for 3D
+//! `u64`.
+
+#[inline]
+fn part_1by1_32(x: u32) -> u32 {
+    let mut x = x & 0x0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333;
+    (x | (x << 1)) & 0x5555_5555
+}
+
+#[inline]
+fn compact_1by1_32(x: u32) -> u32 {
+    let mut x = x & 0x5555_5555;
+    x = (x | (x >> 1)) & 0x3333_3333;
+    x = (x | (x >> 2)) & 0x0f0f_0f0f;
+    x = (x | (x >> 4)) & 0x00ff_00ff;
+    (x | (x >> 8)) & 0x0000_ffff
+}
+
+#[inline]
+fn part_1by1_64(x: u64) -> u64 {
+    let mut x = x & 0x0000_0000_ffff_ffff;
+    x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+#[inline]
+fn compact_1by1_64(x: u64) -> u64 {
+    let mut x = x & 0x5555_5555_5555_5555;
+    x = (x | (x >> 1)) & 0x3333_3333_3333_3333;
+    x = (x | (x >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x >> 4)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x >> 8)) & 0x0000_ffff_0000_ffff;
+    (x | (x >> 16)) & 0x0000_0000_ffff_ffff
+}
+
+#[inline]
+fn part_1by2_32(x: u32) -> u32 {
+    let mut x = x & 0x0000_03ff;
+    x = (x | (x << 16)) & 0xff00_00ff;
+    x = (x | (x << 8)) & 0x0300_f00f;
+    x = (x | (x << 4)) & 0x030c_30c3;
+    (x | (x << 2)) & 0x0924_9249
+}
+
+#[inline]
+fn compact_1by2_32(x: u32) -> u32 {
+    let mut x = x & 0x0924_9249;
+    x = (x | (x >> 2)) & 0x030c_30c3;
+    x = (x | (x >> 4)) & 0x0300_f00f;
+    x = (x | (x >> 8)) & 0xff00_00ff;
+    (x | (x >> 16)) & 0x0000_03ff
+}
+
+#[inline]
+fn part_1by2_64(x: u64) -> u64 {
+    let mut x = x & 0x001f_ffff;
+    x = (x | (x << 32)) & 0x001f_0000_0000_ffff;
+    x = (x | (x << 16)) & 0x001f_0000_ff00_00ff;
+    x = (x | (x << 8)) & 0x100f_00f0_0f00_f00f;
+    x = (x | (x << 4)) & 0x10c3_0c30_c30c_30c3;
+    (x | (x << 2)) & 0x1249_2492_4924_9249
+}
+
+#[inline]
+fn compact_1by2_64(x: u64) -> u64 {
+    let mut x = x & 0x1249_2492_4924_9249;
+    x = (x | (x >> 2)) & 0x10c3_0c30_c30c_30c3;
+    x = (x | (x >> 4)) & 0x100f_00f0_0f00_f00f;
+    x = (x | (x >> 8)) & 0x001f_0000_ff00_00ff;
+    x = (x | (x >> 16)) & 0x001f_0000_0000_ffff;
+    (x | (x >> 32)) & 0x001f_ffff
+}
+
+pub fn encode2_32(x: u32, y: u32) -> u32 {
+    part_1by1_32(x) | (part_1by1_32(y) << 1)
+}
+
+pub fn decode2_32(code: u32) -> (u32, u32) {
+    (compact_1by1_32(code), compact_1by1_32(code >> 1))
+}
+
+pub fn encode2_64(x: u32, y: u32) -> u64 {
+    part_1by1_64(x as u64) | (part_1by1_64(y as u64) << 1)
+}
+
+pub fn decode2_64(code: u64) -> (u32, u32) {
+    (
+        compact_1by1_64(code) as u32,
+        compact_1by1_64(code >> 1) as u32,
+    )
+}
+
+pub fn encode3_32(x: u32, y: u32, z: u32) -> u32 {
+    part_1by2_32(x) | (part_1by2_32(y) << 1) | (part_1by2_32(z) << 2)
+}
+
+pub fn decode3_32(code: u32) -> (u32, u32, u32) {
+    (
+        compact_1by2_32(code),
+        compact_1by2_32(code >> 1),
+        compact_1by2_32(code >> 2),
+    )
+}
+
+pub fn encode3_64(x: u32, y: u32, z: u32) -> u64 {
+    let (x, y, z) = (x as u64, y as u64, z as u64);
+    part_1by2_64(x) | (part_1by2_64(y) << 1) | (part_1by2_64(z) << 2)
+}
+
+pub fn decode3_64(code: u64) -> (u32, u32, u32) {
+    (
+        compact_1by2_64(code) as u32,
+        compact_1by2_64(code >> 1) as u32,
+        compact_1by2_64(code >> 2) as u32,
+    )
+}
+
+/// Offset that maps signed 21-bit coordinates onto `0..2^21` without
+/// changing their order.
+const SIGNED_BIAS_3_64: i32 = 1 << 20;
+
+/// Encodes signed coordinates in `[-2^20, 2^20)`, e.g. chunk positions,
+/// preserving Z-order across the origin.
+pub fn encode3_64_signed(x: i32, y: i32, z: i32) -> u64 {
+    let bias = |v: i32| v.wrapping_add(SIGNED_BIAS_3_64) as u32;
+    encode3_64(bias(x), bias(y), bias(z))
+}
+
+pub fn decode3_64_signed(code: u64) -> (i32, i32, i32) {
+    let (x, y, z) = decode3_64(code);
+    let unbias = |v: u32| v as i32 - SIGNED_BIAS_3_64;
+    (unbias(x), unbias(y), unbias(z))
+}
+
+/// Componentwise arithmetic directly on codes. Filling the bits of the
+/// other axes with ones lets carries ripple through to the next bit of the
+/// same axis. Each component wraps within its bit budget.
+macro_rules! impl_morton_arithmetic {
+    ($key:ty, $masks:expr, $encode:ident, $add:ident, $sub:ident, $step:ident) => {
+        /// Adds two codes component by component.
+        pub fn $add(a: $key, b: $key) -> $key {
+            let mut result = 0;
+            for mask in $masks {
+                result |= ((a | !mask).wrapping_add(b & mask)) & mask;
+            }
+            result
+        }
+
+        /// Subtracts `b` from `a` component by component.
+        pub fn $sub(a: $key, b: $key) -> $key {
+            let mut result = 0;
+            for mask in $masks {
+                result |= ((a & mask).wrapping_sub(b & mask)) & mask;
+            }
+            result
+        }
+
+        /// Moves `delta` cells along `axis` without decoding. Panics if
+        /// `axis` is out of range.
+        pub fn $step(code: $key, axis: usize, delta: i32) -> $key {
+            let mask = $masks[axis];
+            let offset = ($encode(delta.unsigned_abs()) << axis) & mask;
+            if delta >= 0 {
+                ((code | !mask).wrapping_add(offset) & mask) | (code & !mask)
+            } else {
+                ((code & mask).wrapping_sub(offset) & mask) | (code & !mask)
+            }
+        }
+    };
+}
+
+const MASKS_2_32: [u32; 2] = [0x5555_5555, 0xaaaa_aaaa];
+const MASKS_2_64: [u64; 2] = [0x5555_5555_5555_5555, 0xaaaa_aaaa_aaaa_aaaa];
+const MASKS_3_32: [u32; 3] = [0x0924_9249, 0x1249_2492, 0x2492_4924];
+const MASKS_3_64: [u64; 3] = [
+    0x1249_2492_4924_9249,
+    0x2492_4924_9249_2492,
+    0x4924_9249_2492_4924,
+];
+
+impl_morton_arithmetic!(u32, MASKS_2_32, part_1by1_32, add2_32, sub2_32, step2_32);
+impl_morton_arithmetic!(
+    u64,
+    MASKS_2_64,
+    part_1by1_64_u32,
+    add2_64,
+    sub2_64,
+    step2_64
+);
+impl_morton_arithmetic!(u32, MASKS_3_32, part_1by2_32, add3_32, sub3_32, step3_32);
+impl_morton_arithmetic!(
+    u64,
+    MASKS_3_64,
+    part_1by2_64_u32,
+    add3_64,
+    sub3_64,
+    step3_64
+);
+
+#[inline]
+fn part_1by1_64_u32(x: u32) -> u64 {
+    part_1by1_64(x as u64)
+}
+
+#[inline]
+fn part_1by2_64_u32(x: u32) -> u64 {
+    part_1by2_64(x as u64)
+}
+
+/// The octree parent of a 3D code: one level up in every axis.
+pub fn parent3_64(code: u64) -> u64 {
+    code >> 3
+}
+
+/// Child `octant` (bit 0 = +x, bit 1 = +y, bit 2 = +z) one level down.
+pub fn child3_64(code: u64, octant: u8) -> u64 {
+    (code << 3) | (octant as u64 & 7)
+}
@@ -0,0 +1,173 @@
+use crate::matrix::Matrix4;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector3;
+use num_traits::Float;
+use std::ops::Mul;
+
+/// Translation, rotation and non-uniform scale, applied to points in the
+/// order scale, rotate, translate — the same as [`Matrix4::from_trs`].
+///
+/// Composition follows the matrix convention: `a * b` applies `a` first, so
+/// a child's world transform is `local * parent_world`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform<T> {
+    pub translation: Vector3<T>,
+    pub rotation: Quaternion<T>,
+    pub scale: Vector3<T>,
+}
+
+pub type Transformf = Transform<f32>;
+pub type Transformd = Transform<f64>;
+
+impl<T: Float> Default for Transform<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Float> Transform<T> {
+    pub fn new(translation: Vector3<T>, rotation: Quaternion<T>, scale: Vector3<T>) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self {
+            translation: Vector3::new(T::zero(), T::zero(), T::zero()),
+            rotation: Quaternion::identity(),
+            scale: Vector3::new(T::one(), T::one(), T::one()),
+        }
+    }
+
+    pub fn from_translation(translation: Vector3<T>) -> Self {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        Self {
+            rotation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_scale(scale: Vector3<T>) -> Self {
+        Self {
+            scale,
+            ..Self::identity()
+        }
+    }
+
+    pub fn transform_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        self.rotation.rotate_vector(&(*point * self.scale)) + self.translation
+    }
+
+    /// Transforms a direction or offset, ignoring translation.
+    pub fn transform_vector(&self, vector: &Vector3<T>) -> Vector3<T> {
+        self.rotation.rotate_vector(&(*vector * self.scale))
+    }
+
+    /// Maps a world-space point back into this transform's local space.
+    /// Exact even with non-uniform scale, unlike `inverse().transform_point`.
+    pub fn inverse_transform_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        self.rotation
+            .conjugate()
+            .rotate_vector(&(*point - self.translation))
+            / self.scale
+    }
+
+    /// Applies `self` and then `other`.
+    ///
+    /// The result is exact when `other` has uniform scale. Otherwise a
+    /// non-uniform scale acting on a rotated child would introduce shear,
+    /// which a `Transform` cannot hold; the shear is dropped, as in most
+    /// engines. Multiply the matrices from [`Transform::to_matrix`] when the
+    /// exact result is needed.
+    pub fn mul(&self, other: &Self) -> Self {
+        Self {
+            translation: other.transform_point(&self.translation),
+            rotation: (other.rotation * self.rotation).normalize(),
+            scale: self.scale * other.scale,
+        }
+    }
+
+    /// Returns the transform that undoes `self`, or `None` if a scale
+    /// component is zero. Like [`Transform::mul`] this is exact only for
+    /// uniform scale.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.scale.x.is_zero() || self.scale.y.is_zero() || self.scale.z.is_zero() {
+            return None;
+        }
+        let one = Vector3::new(T::one(), T::one(), T::one());
+        let rotation = self.rotation.conjugate();
+        let scale = one / self.scale;
+        let translation = rotation.rotate_vector(&-self.translation) * scale;
+        Some(Self {
+            translation,
+            rotation,
+            scale,
+        })
+    }
+
+    /// Interpolates translation and scale linearly and rotation along the
+    /// shortest arc.
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        Self {
+            translation: self.translation.lerp(&other.translation, t),
+            rotation: self.rotation.slerp(&other.rotation, t),
+            scale: self.scale.lerp(&other.scale, t),
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix4<T> {
+        Matrix4::from_trs(&self.translation, &self.rotation, &self.scale)
+    }
+
+    /// Decomposes an affine matrix. Shear is discarded; see
+    /// [`Matrix4::to_trs`].
+    pub fn from_matrix(matrix: &Matrix4<T>) -> Self {
+        let (translation, rotation, scale) = matrix.to_trs();
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Converts every component to another scalar type, e.g. `f64`
+    /// simulation transforms to `f32` for rendering.
+    pub fn cast<U: Float>(&self) -> Transform<U> {
+        Transform {
+            translation: self.translation.cast(),
+            rotation: self.rotation.cast(),
+            scale: self.scale.cast(),
+        }
+    }
+}
+
+impl From<Transform<f32>> for Transform<f64> {
+    fn from(transform: Transform<f32>) -> Self {
+        transform.cast()
+    }
+}
+
+impl<T: Float> Mul for Transform<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Transform::mul(&self, &other)
+    }
+}
+
+impl<T: Float> Mul for &Transform<T> {
+    type Output = Transform<T>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Transform::mul(self, other)
+    }
+}
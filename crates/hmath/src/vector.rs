@@ -1,29 +1,131 @@
-use num_traits::Float;
-use std::ops::{Add, AddAssign, Div, Mul, Sub};
+use num_traits::{Euclid, Float, Num, NumCast, Zero};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 #[repr(C)]
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Vector3<T> {
     pub x: T,
     pub y: T,
     pub z: T,
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector4<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+pub type Vector2d = Vector2<f64>;
+pub type Vector2f = Vector2<f32>;
+pub type Vector2i = Vector2<i32>;
+pub type Vector2u = Vector2<u32>;
+
 pub type Vector3d = Vector3<f64>;
 pub type Vector3f = Vector3<f32>;
+pub type Vector3i = Vector3<i32>;
+pub type Vector3u = Vector3<u32>;
 
-impl<T> Vector3<T>
-where
-    T: Float,
-{
+pub type Vector4d = Vector4<f64>;
+pub type Vector4f = Vector4<f32>;
+pub type Vector4i = Vector4<i32>;
+pub type Vector4u = Vector4<u32>;
+
+impl<T> Vector2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Vector2 { x, y }
+    }
+
+    pub fn extend(self, z: T) -> Vector3<T> {
+        Vector3::new(self.x, self.y, z)
+    }
+}
+
+impl<T: Zero> Vector2<T> {
+    pub fn zero() -> Self {
+        Self {
+            x: T::zero(),
+            y: T::zero(),
+        }
+    }
+}
+
+impl<T: Num + Copy> Vector2<T> {
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z component of the cross product of the two vectors lifted into 3D.
+    #[inline]
+    pub fn perp_dot(&self, other: &Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    #[inline]
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Float> Vector2<T> {
+    #[inline]
+    pub fn length(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == T::zero() {
+            *self
+        } else {
+            *self / length
+        }
+    }
+
+    #[inline]
+    pub fn distance(&self, other: &Self) -> T {
+        (*self - *other).length()
+    }
+}
+
+impl<T> Vector3<T> {
     pub fn new(x: T, y: T, z: T) -> Self {
         Vector3 { x, y, z }
     }
 
+    pub fn extend(self, w: T) -> Vector4<T> {
+        Vector4::new(self.x, self.y, self.z, w)
+    }
+
+    pub fn truncate(self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
+    }
+}
+
+impl<T: Zero> Vector3<T> {
     pub fn zero() -> Self {
-        Self { x: T::zero(), y: T::zero(), z: T::zero() }
+        Self {
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+        }
     }
+}
 
+impl<T: Num + Copy> Vector3<T> {
     #[inline]
     pub fn dot(&self, other: &Self) -> T {
         self.x * other.x + self.y * other.y + self.z * other.z
@@ -42,7 +144,12 @@ where
     pub fn norm_squared(&self) -> T {
         self.dot(self)
     }
+}
 
+impl<T> Vector3<T>
+where
+    T: Float,
+{
     #[inline]
     pub fn length(&self) -> T {
         self.norm_squared().sqrt()
@@ -67,47 +174,318 @@ where
     }
 }
 
-impl<T: Float> Add for Vector3<T> {
-    type Output = Self;
+impl<T> Vector4<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Vector4 { x, y, z, w }
+    }
 
-    #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    pub fn truncate(self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
     }
 }
 
-impl<T: Float + AddAssign<T>> AddAssign for Vector3<T> {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
+impl<T: Zero> Vector4<T> {
+    pub fn zero() -> Self {
+        Self {
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+            w: T::zero(),
+        }
     }
 }
 
-impl<T: Float> Sub for Vector3<T> {
-    type Output = Self;
+impl<T: Num + Copy> Vector4<T> {
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
 
     #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
     }
 }
 
-impl<T: Float> Mul<T> for Vector3<T> {
-    type Output = Self;
-
+impl<T: Float> Vector4<T> {
     #[inline]
-    fn mul(self, scalar: T) -> Self::Output {
-        Self::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    pub fn length(&self) -> T {
+        self.norm_squared().sqrt()
     }
-}
 
-impl<T: Float> Div<T> for Vector3<T> {
-    type Output = Self;
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == T::zero() {
+            *self
+        } else {
+            *self / length
+        }
+    }
 
     #[inline]
-    fn div(self, scalar: T) -> Self::Output {
-        Self::new(self.x / scalar, self.y / scalar, self.z / scalar)
+    pub fn distance(&self, other: &Self) -> T {
+        (*self - *other).length()
     }
 }
+
+/// Operators, conversions and component-wise helpers shared by every vector
+/// size.
+macro_rules! impl_vector_common {
+    ($name:ident, $len:literal, { $($field:ident: $index:literal),+ }) => {
+        unsafe impl<T: bytemuck::Pod> bytemuck::Pod for $name<T> {}
+        unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for $name<T> {}
+
+        impl<T: Copy + NumCast> $name<T> {
+            /// Converts every component to another scalar type.
+            ///
+            /// Float-to-integer conversion truncates toward zero; use
+            /// [`Self::floor_cast`] and friends for other rounding modes.
+            /// Panics if a component is not representable in `U`.
+            pub fn cast<U: NumCast>(&self) -> $name<U> {
+                $name {
+                    $($field: U::from(self.$field).expect("vector component out of range")),+
+                }
+            }
+        }
+
+        impl<T: Float> $name<T> {
+            #[inline]
+            pub fn floor(&self) -> Self {
+                $name { $($field: self.$field.floor()),+ }
+            }
+
+            #[inline]
+            pub fn ceil(&self) -> Self {
+                $name { $($field: self.$field.ceil()),+ }
+            }
+
+            #[inline]
+            pub fn round(&self) -> Self {
+                $name { $($field: self.$field.round()),+ }
+            }
+
+            /// Rounds every component toward negative infinity, then converts.
+            pub fn floor_cast<U: NumCast>(&self) -> $name<U> {
+                self.floor().cast()
+            }
+
+            /// Rounds every component toward positive infinity, then converts.
+            pub fn ceil_cast<U: NumCast>(&self) -> $name<U> {
+                self.ceil().cast()
+            }
+
+            /// Rounds every component half away from zero, then converts.
+            pub fn round_cast<U: NumCast>(&self) -> $name<U> {
+                self.round().cast()
+            }
+
+            /// Linearly interpolates towards `other`; `t = 0` yields `self`.
+            #[inline]
+            pub fn lerp(&self, other: &Self, t: T) -> Self {
+                $name { $($field: self.$field + (other.$field - self.$field) * t),+ }
+            }
+        }
+
+        impl<T: PartialOrd + Copy> $name<T> {
+            /// Component-wise minimum.
+            #[inline]
+            pub fn min(&self, other: &Self) -> Self {
+                $name { $($field: if other.$field < self.$field { other.$field } else { self.$field }),+ }
+            }
+
+            /// Component-wise maximum.
+            #[inline]
+            pub fn max(&self, other: &Self) -> Self {
+                $name { $($field: if other.$field > self.$field { other.$field } else { self.$field }),+ }
+            }
+
+            /// Clamps every component between the matching components of `min`
+            /// and `max`.
+            #[inline]
+            pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+                self.max(min).min(max)
+            }
+        }
+
+        impl<T: Copy + PartialOrd + Zero + Neg<Output = T>> $name<T> {
+            #[inline]
+            pub fn abs(&self) -> Self {
+                $name { $($field: if self.$field < T::zero() { -self.$field } else { self.$field }),+ }
+            }
+        }
+
+        impl<T: Euclid + Copy> $name<T> {
+            /// Component-wise Euclidean division, rounding toward negative
+            /// infinity for positive divisors.
+            ///
+            /// This maps a world block coordinate to the coordinate of the
+            /// chunk containing it, including for negative coordinates.
+            #[inline]
+            pub fn div_euclid(&self, rhs: T) -> Self {
+                $name { $($field: self.$field.div_euclid(&rhs)),+ }
+            }
+
+            /// Component-wise Euclidean remainder, always non-negative.
+            ///
+            /// This maps a world block coordinate to its local offset inside
+            /// the containing chunk.
+            #[inline]
+            pub fn rem_euclid(&self, rhs: T) -> Self {
+                $name { $($field: self.$field.rem_euclid(&rhs)),+ }
+            }
+        }
+
+        impl From<$name<i32>> for $name<f64> {
+            fn from(v: $name<i32>) -> Self {
+                $name { $($field: v.$field as f64),+ }
+            }
+        }
+
+        impl From<$name<u32>> for $name<f64> {
+            fn from(v: $name<u32>) -> Self {
+                $name { $($field: v.$field as f64),+ }
+            }
+        }
+
+        impl From<$name<f32>> for $name<f64> {
+            fn from(v: $name<f32>) -> Self {
+                $name { $($field: v.$field as f64),+ }
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                $name { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $name<T> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                $name { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, scalar: T) -> Self::Output {
+                $name { $($field: self.$field * scalar),+ }
+            }
+        }
+
+        impl<T: Copy + Div<Output = T>> Div<T> for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, scalar: T) -> Self::Output {
+                $name { $($field: self.$field / scalar),+ }
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $name<T> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                $name { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T>> Mul for $name<T> {
+            type Output = Self;
+
+            /// Component-wise (Hadamard) product.
+            #[inline]
+            fn mul(self, rhs: Self) -> Self::Output {
+                $name { $($field: self.$field * rhs.$field),+ }
+            }
+        }
+
+        impl<T: Div<Output = T>> Div for $name<T> {
+            type Output = Self;
+
+            /// Component-wise division.
+            #[inline]
+            fn div(self, rhs: Self) -> Self::Output {
+                $name { $($field: self.$field / rhs.$field),+ }
+            }
+        }
+
+        impl<T: Copy + MulAssign> MulAssign<T> for $name<T> {
+            #[inline]
+            fn mul_assign(&mut self, scalar: T) {
+                $(self.$field *= scalar;)+
+            }
+        }
+
+        impl<T: Copy + DivAssign> DivAssign<T> for $name<T> {
+            #[inline]
+            fn div_assign(&mut self, scalar: T) {
+                $(self.$field /= scalar;)+
+            }
+        }
+
+        impl<T> Index<usize> for $name<T> {
+            type Output = T;
+
+            #[inline]
+            fn index(&self, index: usize) -> &T {
+                match index {
+                    $($index => &self.$field,)+
+                    _ => panic!("index {} out of range for {}", index, stringify!($name)),
+                }
+            }
+        }
+
+        impl<T> IndexMut<usize> for $name<T> {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut T {
+                match index {
+                    $($index => &mut self.$field,)+
+                    _ => panic!("index {} out of range for {}", index, stringify!($name)),
+                }
+            }
+        }
+
+        impl<T> From<[T; $len]> for $name<T> {
+            #[inline]
+            fn from(array: [T; $len]) -> Self {
+                let [$($field),+] = array;
+                $name { $($field),+ }
+            }
+        }
+
+        impl<T> From<$name<T>> for [T; $len] {
+            #[inline]
+            fn from(v: $name<T>) -> Self {
+                [$(v.$field),+]
+            }
+        }
+    };
+}
+
+impl_vector_common!(Vector2, 2, { x: 0, y: 1 });
+impl_vector_common!(Vector3, 3, { x: 0, y: 1, z: 2 });
+impl_vector_common!(Vector4, 4, { x: 0, y: 1, z: 2, w: 3 });
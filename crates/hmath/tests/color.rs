@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use hmath::color::hsv::{Hsla, Hsva};
+    use hmath::color::linear::LinearRgba;
+    use hmath::color::oklab::Oklab;
+    use hmath::color::pack::{f16_to_f32, f32_to_f16, pack_rgb9e5, unpack_rgb9e5, RGB9E5_MAX};
+    use hmath::color::srgb::{decode_srgb, encode_srgb, Srgba};
+
+    fn assert_color_eq(a: &LinearRgba, b: &LinearRgba, tolerance: f32) {
+        let (a, b): ([f32; 4], [f32; 4]) = ((*a).into(), (*b).into());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() <= tolerance, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_srgb_transfer() {
+        assert_eq!(decode_srgb(0.0), 0.0);
+        assert_eq!(decode_srgb(1.0), 1.0);
+        assert!((decode_srgb(0.5) - 0.214_041_14).abs() < 1e-6);
+        for i in 0..=1000 {
+            let v = i as f32 / 1000.0;
+            assert!((encode_srgb(decode_srgb(v)) - v).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_rgba8_round_trip() {
+        // Every 8-bit value survives decoding to linear and re-encoding.
+        for byte in 0..=255u8 {
+            let color = Srgba::from_bytes(byte, byte, byte, byte);
+            let linear = color.to_linear();
+            assert_eq!(
+                linear.to_rgba8_srgb(),
+                Srgba::from_rgba8(color.to_rgba8()).to_rgba8()
+            );
+            assert_eq!(linear.to_rgba8_srgb() & 0xff, byte as u32);
+        }
+        assert_eq!(Srgba::from_hex(0xff8000ff).to_rgba8(), 0xff0080ff);
+    }
+
+    #[test]
+    fn test_premultiply() {
+        let color = LinearRgba::new(0.8, 0.4, 0.2, 0.5);
+        assert_color_eq(
+            &color.premultiply(),
+            &LinearRgba::new(0.4, 0.2, 0.1, 0.5),
+            1e-7,
+        );
+        assert_color_eq(&color.premultiply().unpremultiply(), &color, 1e-7);
+        assert_eq!(
+            LinearRgba::new(1.0, 1.0, 1.0, 0.0).unpremultiply(),
+            LinearRgba::TRANSPARENT
+        );
+    }
+
+    #[test]
+    fn test_hsv_hsl() {
+        let orange = Srgba::rgb(1.0, 0.5, 0.0);
+        let hsv = Hsva::from(orange);
+        assert!((hsv.h - 30.0).abs() < 1e-4 && hsv.s == 1.0 && hsv.v == 1.0);
+        let hsl = Hsla::from(orange);
+        assert!((hsl.h - 30.0).abs() < 1e-4 && hsl.s == 1.0 && hsl.l == 0.5);
+
+        for i in 0..64 {
+            let channel = |n: i32| n as f32 / 3.0;
+            let color = Srgba::rgb(channel(i % 4), channel(i / 4 % 4), channel(i / 16));
+            for round_trip in [Hsva::from(color).to_srgba(), Hsla::from(color).to_srgba()] {
+                for (a, b) in [
+                    (round_trip.r, color.r),
+                    (round_trip.g, color.g),
+                    (round_trip.b, color.b),
+                ] {
+                    assert!((a - b).abs() < 1e-5, "{:?} != {:?}", round_trip, color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_oklab() {
+        let white = Oklab::from(LinearRgba::WHITE);
+        assert!((white.l - 1.0).abs() < 1e-4 && white.chroma() < 1e-4);
+        assert!(Oklab::from(LinearRgba::BLACK).l.abs() < 1e-6);
+
+        let red = Oklab::from(LinearRgba::rgb(1.0, 0.0, 0.0));
+        assert!((red.l - 0.627_955).abs() < 1e-4);
+        assert!((red.a - 0.224_863).abs() < 1e-4);
+        assert!((red.b - 0.125_846).abs() < 1e-4);
+
+        let color = LinearRgba::new(0.2, 0.7, 0.4, 0.3);
+        assert_color_eq(&LinearRgba::from(Oklab::from(color)), &color, 1e-5);
+    }
+
+    #[test]
+    fn test_rgb10a2() {
+        let color = LinearRgba::new(1.0, 0.5, 0.0, 1.0);
+        let packed = color.to_rgb10a2();
+        assert_eq!(packed & 0x3ff, 1023);
+        assert_eq!((packed >> 10) & 0x3ff, 512);
+        assert_eq!(packed >> 30, 3);
+        assert_color_eq(&LinearRgba::from_rgb10a2(packed), &color, 1.0 / 1023.0);
+        assert_eq!(
+            LinearRgba::new(f32::NAN, -1.0, 2.0, 0.0).to_rgb10a2(),
+            0x3ff << 20
+        );
+    }
+
+    #[test]
+    fn test_rgb9e5() {
+        assert_eq!(pack_rgb9e5(0.0, 0.0, 0.0), 0);
+        assert_eq!(unpack_rgb9e5(pack_rgb9e5(1.0, 0.5, 0.25)), (1.0, 0.5, 0.25));
+        assert_eq!(
+            unpack_rgb9e5(pack_rgb9e5(RGB9E5_MAX, 0.0, 0.0)).0,
+            RGB9E5_MAX
+        );
+        assert_eq!(unpack_rgb9e5(pack_rgb9e5(1e9, 0.0, 0.0)).0, RGB9E5_MAX);
+
+        // Rounding up into the next exponent must not overflow the mantissa.
+        let (r, _, _) = unpack_rgb9e5(pack_rgb9e5(0.999_99, 0.0, 0.0));
+        assert_eq!(r, 1.0);
+
+        let color = LinearRgba::rgb(12.5, 0.3, 0.001);
+        let round_trip = LinearRgba::from_rgb9e5(color.to_rgb9e5());
+        assert!((round_trip.r - 12.5).abs() < 12.5 / 256.0);
+        assert!((round_trip.g - 0.3).abs() < 12.5 / 256.0);
+    }
+
+    #[test]
+    fn test_half_float() {
+        assert_eq!(f32_to_f16(1.0), 0x3c00);
+        assert_eq!(f32_to_f16(-2.0), 0xc000);
+        assert_eq!(f32_to_f16(65504.0), 0x7bff);
+        assert_eq!(f32_to_f16(65520.0), 0x7c00);
+        assert_eq!(f32_to_f16(f32::INFINITY), 0x7c00);
+        assert!(f16_to_f32(f32_to_f16(f32::NAN)).is_nan());
+        assert_eq!(f32_to_f16(5.960_464_5e-8), 0x0001);
+        assert_eq!(f16_to_f32(0x0001), 5.960_464_5e-8);
+        // 1 + 2^-11 is halfway between two halves and rounds to even.
+        assert_eq!(f32_to_f16(1.0 + 1.0 / 2048.0), 0x3c00);
+        assert_eq!(f32_to_f16(1.0 + 3.0 / 2048.0), 0x3c02);
+
+        for bits in 0..0x7c00u16 {
+            assert_eq!(f32_to_f16(f16_to_f32(bits)), bits);
+        }
+        let color = LinearRgba::new(0.25, 4.0, -1.5, 1.0);
+        assert_eq!(LinearRgba::from_rgba16f(color.to_rgba16f()), color);
+    }
+}
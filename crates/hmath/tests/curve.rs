@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod tests {
+    use hmath::curve::arc_length::{arc_length, ArcLengthCurve};
+    use hmath::curve::bezier::{CubicBezier, QuadraticBezier};
+    use hmath::curve::bspline::BSpline;
+    use hmath::curve::catmull_rom::CatmullRom;
+    use hmath::curve::hermite::Hermite;
+    use hmath::curve::squad::QuaternionSpline;
+    use hmath::curve::Curve;
+    use hmath::quaternion::Quaternion;
+    use hmath::vector::{Vector3, Vector3d};
+
+    fn assert_vector_eq(a: &Vector3d, b: &Vector3d, tolerance: f64) {
+        assert!((*a - *b).length() < tolerance, "{:?} != {:?}", a, b);
+    }
+
+    /// Compares the analytic derivative against central differences.
+    fn assert_derivative<C: Curve<f64>>(curve: &C) {
+        let h = 1e-6;
+        for i in 1..20 {
+            let t = i as f64 / 20.0 + 0.013;
+            let numeric = (curve.position(t + h) - curve.position(t - h)) / (2.0 * h);
+            assert_vector_eq(&curve.derivative(t), &numeric, 1e-4);
+        }
+    }
+
+    fn path() -> Vec<Vector3d> {
+        vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(4.0, 1.0, 0.0),
+            Vector3::new(5.0, 3.0, 2.0),
+            Vector3::new(2.0, 6.0, 1.0),
+            Vector3::new(-1.0, 5.0, -2.0),
+        ]
+    }
+
+    #[test]
+    fn test_bezier() {
+        let p = path();
+        let quadratic = QuadraticBezier::new(p[0], p[1], p[2]);
+        assert_eq!(quadratic.position(0.0), p[0]);
+        assert_eq!(quadratic.position(1.0), p[2]);
+        assert_derivative(&quadratic);
+
+        let cubic = CubicBezier::new(p[0], p[1], p[2], p[3]);
+        assert_eq!(cubic.position(1.0), p[3]);
+        assert_vector_eq(&cubic.derivative(0.0), &((p[1] - p[0]) * 3.0), 1e-12);
+        assert_derivative(&cubic);
+
+        let (left, right) = cubic.split(0.3);
+        assert_vector_eq(&left.position(0.5), &cubic.position(0.15), 1e-12);
+        assert_vector_eq(&right.position(0.5), &cubic.position(0.65), 1e-12);
+    }
+
+    #[test]
+    fn test_hermite() {
+        let p = path();
+        let hermite = Hermite::new(p[0], p[1], p[2], p[3]);
+        assert_eq!(hermite.position(0.0), p[0]);
+        assert_eq!(hermite.position(1.0), p[2]);
+        assert_vector_eq(&hermite.derivative(0.0), &p[1], 1e-12);
+        assert_vector_eq(&hermite.derivative(1.0), &p[3], 1e-12);
+        assert_derivative(&hermite);
+    }
+
+    #[test]
+    fn test_catmull_rom_interpolates() {
+        let points = path();
+        for alpha in [0.0, 0.5, 1.0] {
+            let spline = CatmullRom::new(points.clone(), alpha).unwrap();
+            for (i, point) in points.iter().enumerate() {
+                let t = i as f64 / (points.len() - 1) as f64;
+                assert_vector_eq(&spline.position(t), point, 1e-9);
+            }
+            assert_derivative(&spline);
+        }
+
+        let closed = CatmullRom::closed(points.clone(), 0.5).unwrap();
+        assert_vector_eq(&closed.position(1.0), &points[0], 1e-9);
+        assert!(CatmullRom::new(vec![Vector3d::zero()], 0.5).is_none());
+    }
+
+    #[test]
+    fn test_catmull_rom_tangent_continuity() {
+        let uniform = CatmullRom::new(path(), 0.0).unwrap();
+        let centripetal = CatmullRom::centripetal(path()).unwrap();
+        for i in 1..4 {
+            let left = uniform.segment(i - 1).derivative(1.0);
+            let right = uniform.segment(i).derivative(0.0);
+            assert_vector_eq(&left, &right, 1e-9);
+
+            // Non-uniform knots keep the direction but not the speed across
+            // segments of different length.
+            let left = centripetal.segment(i - 1).derivative(1.0).normalize();
+            let right = centripetal.segment(i).derivative(0.0).normalize();
+            assert_vector_eq(&left, &right, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_bspline() {
+        let spline = BSpline::new(path()).unwrap();
+        assert_eq!(spline.segment_count(), 2);
+        assert_derivative(&spline);
+
+        let mut clamped = vec![Vector3::new(1.0, 2.0, 3.0); 3];
+        clamped.extend(path());
+        let clamped = BSpline::new(clamped).unwrap();
+        assert_vector_eq(&clamped.position(0.0), &Vector3::new(1.0, 2.0, 3.0), 1e-12);
+        assert!(BSpline::new(path()[..3].to_vec()).is_none());
+    }
+
+    #[test]
+    fn test_arc_length() {
+        let line = QuadraticBezier::<f64>::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(10.0, 0.0, 0.0),
+        );
+        assert!((arc_length(&line, 0.0, 1.0) - 10.0).abs() < 1e-9);
+
+        // The control points bunch the parameter towards the start, but
+        // distance lookups still land at the requested distance.
+        let table = ArcLengthCurve::new(line, 16);
+        assert!((table.length() - 10.0).abs() < 1e-9);
+        for i in 0..=10 {
+            let position = table.position_at(i as f64);
+            assert!((position.x - i as f64).abs() < 1e-6);
+        }
+        assert_eq!(table.parameter_at(-1.0), 0.0);
+        assert_eq!(table.parameter_at(20.0), 1.0);
+        assert_vector_eq(&table.direction_at(5.0), &Vector3::new(1.0, 0.0, 0.0), 1e-9);
+    }
+
+    #[test]
+    fn test_constant_speed_on_spline() {
+        let spline = ArcLengthCurve::new(CatmullRom::centripetal(path()).unwrap(), 256);
+        let steps = 50;
+        let step = spline.length() / steps as f64;
+        for i in 0..steps {
+            let a = spline.position_at(i as f64 * step);
+            let b = spline.position_at((i + 1) as f64 * step);
+            // Chords are slightly shorter than arcs, never longer.
+            let chord = (b - a).length();
+            assert!(chord <= step + 1e-6 && chord > step * 0.95);
+        }
+    }
+
+    #[test]
+    fn test_quaternion_log_exp() {
+        let q = Quaternion::<f64>::from_axis_angle(Vector3::new(1.0, 2.0, 2.0).normalize(), 1.3);
+        let round_trip = q.log().exp();
+        assert!((round_trip.dot(&q) - 1.0).abs() < 1e-12);
+        assert!((q.log().w).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_squad() {
+        let axis = Vector3::new(0.0, 1.0, 0.0);
+        let keys: Vec<_> = [0.0, 0.5, 1.5, 2.0]
+            .iter()
+            .map(|&angle| Quaternion::<f64>::from_axis_angle(axis, angle))
+            .collect();
+        let spline = QuaternionSpline::new(keys.clone()).unwrap();
+        for (i, key) in keys.iter().enumerate() {
+            let rotation = spline.rotation(i as f64 / 3.0);
+            assert!((rotation.dot(key).abs() - 1.0).abs() < 1e-9);
+        }
+
+        // Rotations about a single axis stay on that axis.
+        let mid = spline.rotation(0.4);
+        assert!(mid.x.abs() < 1e-9 && mid.z.abs() < 1e-9);
+
+        // A flipped key does not send the spline the long way round.
+        let flipped = QuaternionSpline::new(vec![keys[0], -keys[1]]).unwrap();
+        assert!(flipped.rotation(0.5).angle_between(&keys[0]) < 0.3);
+    }
+
+    #[test]
+    fn test_squad_sign_flips_between_keys() {
+        let keys = [
+            Quaternion::<f64>::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.3),
+            Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.9),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), 1.7),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 2.4),
+        ];
+        let (previous, key, next) = (keys[0], keys[1], keys[2]);
+        let tangent = key.squad_tangent(&previous, &next);
+        for flipped in [
+            key.squad_tangent(&-previous, &next),
+            key.squad_tangent(&previous, &-next),
+        ] {
+            assert!((flipped.dot(&tangent) - 1.0).abs() < 1e-12);
+        }
+
+        let spline = QuaternionSpline::new(keys.to_vec()).unwrap();
+        let alternating =
+            QuaternionSpline::new(vec![keys[0], -keys[1], keys[2], -keys[3]]).unwrap();
+        for i in 0..=30 {
+            let t = i as f64 / 30.0;
+            assert!((spline.rotation(t).dot(&alternating.rotation(t)).abs() - 1.0).abs() < 1e-9);
+        }
+
+        // C1 at the interior keys: the angular velocity matches on both sides.
+        let velocity = |from: f64, to: f64| {
+            let (a, b) = (alternating.rotation(from), alternating.rotation(to));
+            let b = if a.dot(&b) < 0.0 { -b } else { b };
+            let log = (a.conjugate() * b).log();
+            Vector3::new(log.x, log.y, log.z) * (2.0 / (to - from))
+        };
+        let h = 1e-5;
+        for key in [1.0 / 3.0, 2.0 / 3.0] {
+            let before = velocity(key - h, key);
+            let after = velocity(key, key + h);
+            assert!((before - after).length() < 1e-3 * before.length());
+        }
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+    use hmath::fixed::Fixed64;
+    use hmath::quaternion::Quaternion;
+    use hmath::vector::Vector3;
+    use num_traits::{Float, NumCast};
+
+    fn fx(value: f64) -> Fixed64 {
+        Fixed64::from_f64(value)
+    }
+
+    fn assert_close(actual: Fixed64, expected: f64, tolerance: f64) {
+        let error = (actual.to_f64() - expected).abs();
+        assert!(
+            error <= tolerance,
+            "{} != {} (error {})",
+            actual,
+            expected,
+            error
+        );
+    }
+
+    /// Sample points spread over a range, avoiding exact integers.
+    fn samples(low: f64, high: f64) -> impl Iterator<Item = f64> {
+        (0..=500).map(move |i| low + (high - low) * (i as f64 + 0.37) / 501.0)
+    }
+
+    #[test]
+    fn test_fixed_arithmetic() {
+        assert_eq!(fx(1.5) + fx(2.25), fx(3.75));
+        assert_eq!(fx(1.5) * fx(-2.0), fx(-3.0));
+        assert_eq!(fx(7.0) / fx(2.0), fx(3.5));
+        assert_eq!(fx(7.5) % fx(2.0), fx(1.5));
+        assert_eq!(fx(-7.5) % fx(2.0), fx(-1.5));
+        assert_eq!(Fixed64::ONE.to_bits(), 1 << 32);
+        assert_eq!(Fixed64::from_int(-3), fx(-3.0));
+        assert_eq!(fx(2.0).mul_add(fx(3.0), fx(1.0)), fx(7.0));
+    }
+
+    #[test]
+    fn test_fixed_saturation() {
+        assert_eq!(Fixed64::MAX + Fixed64::ONE, Fixed64::MAX);
+        assert_eq!(Fixed64::MIN - Fixed64::ONE, Fixed64::MIN);
+        assert_eq!(fx(1e6) * fx(1e6), Fixed64::MAX);
+        assert_eq!(Fixed64::ONE / Fixed64::ZERO, Fixed64::MAX);
+        assert_eq!(-Fixed64::ONE / Fixed64::ZERO, Fixed64::MIN);
+        assert_eq!(Fixed64::ZERO / Fixed64::ZERO, Fixed64::ZERO);
+        assert_eq!(-Fixed64::MIN, Fixed64::MAX);
+        assert_eq!(<Fixed64 as NumCast>::from(1e10), None);
+        assert_eq!(<Fixed64 as NumCast>::from(0.5f32), Some(fx(0.5)));
+    }
+
+    #[test]
+    fn test_fixed_rounding() {
+        for (value, floor, ceil, round, trunc) in [
+            (2.5, 2.0, 3.0, 3.0, 2.0),
+            (-2.5, -3.0, -2.0, -3.0, -2.0),
+            (-0.25, -1.0, 0.0, 0.0, 0.0),
+            (4.0, 4.0, 4.0, 4.0, 4.0),
+        ] {
+            let x = fx(value);
+            assert_eq!(x.floor(), fx(floor));
+            assert_eq!(x.ceil(), fx(ceil));
+            assert_eq!(x.round(), fx(round));
+            assert_eq!(x.trunc(), fx(trunc));
+            assert_eq!(x.fract(), fx(value - trunc));
+        }
+    }
+
+    #[test]
+    fn test_fixed_sqrt() {
+        assert_eq!(fx(4.0).sqrt(), fx(2.0));
+        assert_eq!(fx(-4.0).sqrt(), Fixed64::ZERO);
+        for x in samples(0.0, 1e6) {
+            assert_close(fx(x).sqrt(), x.sqrt(), 1e-9);
+        }
+        assert_close(fx(3.0).hypot(fx(4.0)), 5.0, 1e-9);
+        assert_close(Fixed64::MAX.hypot(Fixed64::MAX), Fixed64::MAX.to_f64(), 1.0);
+    }
+
+    #[test]
+    fn test_fixed_trigonometry() {
+        for x in samples(-100.0, 100.0) {
+            let (sin, cos) = fx(x).sin_cos();
+            assert_close(sin, x.sin(), 1e-8);
+            assert_close(cos, x.cos(), 1e-8);
+        }
+        assert_eq!(Fixed64::ZERO.sin(), Fixed64::ZERO);
+        assert_eq!(Fixed64::ZERO.cos(), Fixed64::ONE);
+        for x in samples(-1.3, 1.3) {
+            assert_close(fx(x).tan(), x.tan(), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_fixed_inverse_trigonometry() {
+        for x in samples(-50.0, 50.0) {
+            assert_close(fx(x).atan(), x.atan(), 1e-9);
+        }
+        for x in samples(-1.0, 1.0) {
+            assert_close(fx(x).asin(), x.asin(), 1e-8);
+            assert_close(fx(x).acos(), x.acos(), 1e-8);
+        }
+        for (y, x) in [
+            (1.0, 1.0),
+            (1.0, -1.0),
+            (-1.0, -1.0),
+            (-2.0, 0.5),
+            (0.0, -3.0),
+            (3.0, 0.0),
+        ] {
+            assert_close(fx(y).atan2(fx(x)), f64::atan2(y, x), 1e-9);
+        }
+        assert_eq!(Fixed64::ZERO.atan2(Fixed64::ZERO), Fixed64::ZERO);
+        assert_close(fx(90.0).to_radians(), std::f64::consts::FRAC_PI_2, 1e-8);
+    }
+
+    #[test]
+    fn test_fixed_exp_ln() {
+        for x in samples(-20.0, 20.0) {
+            let expected = x.exp();
+            assert_close(fx(x).exp(), expected, expected * 1e-8 + 1e-9);
+        }
+        for x in samples(1e-3, 1e5) {
+            assert_close(fx(x).ln(), x.ln(), 1e-8);
+        }
+        assert_eq!(Fixed64::ONE.ln(), Fixed64::ZERO);
+        assert_eq!(Fixed64::ZERO.ln(), Fixed64::MIN);
+        assert_eq!(fx(30.0).exp(), Fixed64::MAX);
+        assert_close(fx(2.0).powf(fx(10.0)), 1024.0, 1e-5);
+        assert_eq!(fx(2.0).powi(10), fx(1024.0));
+        assert_eq!(fx(2.0).powi(-2), fx(0.25));
+        assert_close(fx(-27.0).cbrt(), -3.0, 1e-7);
+        assert_close(fx(0.5).tanh(), 0.5f64.tanh(), 1e-9);
+        assert_close(fx(-2.0).asinh(), (-2.0f64).asinh(), 1e-8);
+    }
+
+    #[test]
+    fn test_fixed_is_deterministic() {
+        // Golden bit patterns: any platform must reproduce these exactly.
+        assert_eq!(fx(1.0).sin().to_bits(), 3_614_090_360);
+        assert_eq!(fx(0.5).sqrt().to_bits(), 3_037_000_499);
+        assert_eq!(fx(1.0).exp().to_bits(), Fixed64::E.to_bits());
+    }
+
+    #[test]
+    fn test_fixed_in_generic_types() {
+        let axis = Vector3::new(Fixed64::ZERO, Fixed64::ONE, Fixed64::ZERO);
+        let rotation = Quaternion::from_axis_angle(axis, Fixed64::FRAC_PI_2);
+        let rotated =
+            rotation.rotate_vector(&Vector3::new(Fixed64::ONE, Fixed64::ZERO, Fixed64::ZERO));
+        assert_close(rotated.x, 0.0, 1e-8);
+        assert_close(rotated.z, -1.0, 1e-8);
+
+        let v = Vector3::new(fx(3.0), fx(4.0), fx(12.0));
+        assert_eq!(v.length(), fx(13.0));
+        assert_close(v.normalize().length(), 1.0, 1e-8);
+    }
+}
@@ -0,0 +1,419 @@
+#[cfg(test)]
+mod tests {
+    use hmath::geometry::aabb::Aabb;
+    use hmath::geometry::frustum::{Containment, Frustum};
+    use hmath::geometry::obb::Obb;
+    use hmath::geometry::plane::Plane;
+    use hmath::geometry::ray::Ray;
+    use hmath::geometry::sphere::Sphere;
+    use hmath::geometry::triangle::Triangle;
+    use hmath::geometry::voxel_traversal::VoxelTraversal;
+    use hmath::matrix::Matrix4;
+    use hmath::quaternion::Quaternion;
+    use hmath::vector::{Vector3, Vector3i};
+    use std::f64::consts::FRAC_PI_4;
+
+    fn unit_box() -> Aabb<f64> {
+        Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0))
+    }
+
+    fn assert_vector_eq(a: &Vector3<f64>, b: &Vector3<f64>) {
+        assert!((*a - *b).length() < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_ray_aabb() {
+        let ray = Ray::new(Vector3::new(-5.0, 0.5, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(ray.intersect_aabb(&unit_box()), Some(4.0));
+        assert_eq!(ray.aabb_interval(&unit_box()), Some((4.0, 6.0)));
+
+        let inside = Ray::new(Vector3::zero(), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(inside.intersect_aabb(&unit_box()), Some(0.0));
+
+        let behind = Ray::new(Vector3::new(5.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(behind.intersect_aabb(&unit_box()), None);
+
+        let parallel = Ray::new(Vector3::new(-5.0, 2.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(parallel.intersect_aabb(&unit_box()), None);
+    }
+
+    #[test]
+    fn test_ray_sphere() {
+        let sphere = Sphere::new(Vector3::new(0.0, 0.0, 10.0), 2.0);
+        let ray = Ray::new(Vector3::zero(), Vector3::new(0.0, 0.0, 2.0));
+        assert_eq!(ray.intersect_sphere(&sphere), Some(4.0));
+        let miss = Ray::new(Vector3::new(3.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(miss.intersect_sphere(&sphere), None);
+    }
+
+    #[test]
+    fn test_ray_plane() {
+        let plane =
+            Plane::from_point_normal(&Vector3::new(0.0, 2.0, 0.0), &Vector3::new(0.0, 1.0, 0.0));
+        let ray = Ray::new(Vector3::new(1.0, 5.0, 1.0), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(ray.intersect_plane(&plane), Some(3.0));
+        let away = Ray::new(Vector3::new(1.0, 5.0, 1.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(away.intersect_plane(&plane), None);
+    }
+
+    #[test]
+    fn test_ray_triangle() {
+        let triangle = Triangle::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        let hit = Ray::new(Vector3::new(0.25, 0.25, 3.0), Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(hit.intersect_triangle(&triangle), Some(3.0));
+        let back_face = Ray::new(Vector3::new(0.25, 0.25, -3.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(back_face.intersect_triangle(&triangle), Some(3.0));
+        let miss = Ray::new(Vector3::new(0.75, 0.75, 3.0), Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(miss.intersect_triangle(&triangle), None);
+
+        // The parallel test is relative, so tiny triangles still get hit and
+        // huge ones still reject grazing rays.
+        let tiny: Triangle<f64> = Triangle::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1e-9, 0.0, 0.0),
+            Vector3::new(0.0, 1e-9, 0.0),
+        );
+        let hit = Ray::new(
+            Vector3::new(2.5e-10, 2.5e-10, 1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+        );
+        assert!((hit.intersect_triangle(&tiny).unwrap() - 1.0).abs() < 1e-12);
+        let huge = Triangle::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1e9, 0.0, 0.0),
+            Vector3::new(0.0, 1e9, 0.0),
+        );
+        let grazing = Ray::new(
+            Vector3::new(1.0, 1.0, 1e-12),
+            Vector3::new(1.0, 0.0, -1e-20).normalize(),
+        );
+        assert_eq!(grazing.intersect_triangle(&huge), None);
+    }
+
+    #[test]
+    fn test_ray_obb() {
+        let obb = Obb::new(
+            Vector3::new(10.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 1.0),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_4),
+        );
+        let ray = Ray::new(Vector3::zero(), Vector3::new(1.0, 0.0, 0.0));
+        let t = ray.intersect_obb(&obb).unwrap();
+        assert!((t - (10.0 - 2.0f64.sqrt())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aabb_union_expand() {
+        let a = unit_box();
+        let b = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(3.0, 2.0, 1.0));
+        let union = a.union(&b);
+        assert_eq!(union.min, Vector3::new(-1.0, -1.0, -1.0));
+        assert_eq!(union.max, Vector3::new(3.0, 2.0, 1.0));
+        assert_eq!(a.expand(1.0).max, Vector3::new(2.0, 2.0, 2.0));
+        assert!(Aabb::<f64>::empty().is_empty());
+        assert_eq!(Aabb::empty().union(&a), a);
+
+        let points = [Vector3::new(1.0, -2.0, 0.0), Vector3::new(-1.0, 4.0, 2.0)];
+        let bounds = Aabb::from_points(&points);
+        assert_eq!(bounds.min, Vector3::new(-1.0, -2.0, 0.0));
+        assert_eq!(bounds.max, Vector3::new(1.0, 4.0, 2.0));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let a = unit_box();
+        let b = Aabb::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(2.0, 2.0, 2.0));
+        let c = Aabb::new(Vector3::new(1.5, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+        assert!(a.intersects_aabb(&b));
+        assert!(!a.intersects_aabb(&c));
+
+        let near = Sphere::new(Vector3::new(2.0, 0.0, 0.0), 1.0);
+        let far = Sphere::new(Vector3::new(2.0, 2.0, 0.0), 1.0);
+        assert!(a.intersects_sphere(&near));
+        assert!(!a.intersects_sphere(&far));
+        assert!(near.intersects_sphere(&far));
+
+        let plane =
+            Plane::from_point_normal(&Vector3::new(0.0, 0.9, 0.0), &Vector3::new(0.0, 1.0, 0.0));
+        assert!(a.intersects_plane(&plane));
+        assert!(!b
+            .expand(-0.1)
+            .intersects_plane(&Plane::new(Vector3::new(0.0, 1.0, 0.0), 0.0)));
+    }
+
+    #[test]
+    fn test_obb_overlap() {
+        let rotated = Obb::new(
+            Vector3::new(2.2, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 1.0),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_4),
+        );
+        // The rotated corner reaches to x = 2.2 - sqrt(2) < 1.
+        assert!(rotated.intersects_aabb(&unit_box()));
+        let moved = Obb {
+            center: Vector3::new(2.5, 0.0, 0.0),
+            ..rotated
+        };
+        assert!(!moved.intersects_aabb(&unit_box()));
+        assert!(rotated.intersects_obb(&moved));
+
+        let bounds = rotated.bounding_aabb();
+        assert!((bounds.max.x - (2.2 + 2.0f64.sqrt())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_closest_points() {
+        let p = Vector3::new(3.0, 0.5, -4.0);
+        assert_vector_eq(&unit_box().closest_point(&p), &Vector3::new(1.0, 0.5, -1.0));
+
+        let sphere = Sphere::new(Vector3::zero(), 2.0);
+        assert_vector_eq(
+            &sphere.closest_point(&Vector3::new(0.0, 0.0, 5.0)),
+            &Vector3::new(0.0, 0.0, 2.0),
+        );
+
+        let plane = Plane::new(Vector3::new(0.0, 0.0, 1.0), -1.0);
+        assert_vector_eq(&plane.closest_point(&p), &Vector3::new(3.0, 0.5, 1.0));
+
+        let triangle = Triangle::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(0.0, 2.0, 0.0),
+        );
+        assert_vector_eq(
+            &triangle.closest_point(&Vector3::new(0.5, 0.5, 3.0)),
+            &Vector3::new(0.5, 0.5, 0.0),
+        );
+        assert_vector_eq(
+            &triangle.closest_point(&Vector3::new(-1.0, -1.0, 0.0)),
+            &Vector3::zero(),
+        );
+        assert_vector_eq(
+            &triangle.closest_point(&Vector3::new(2.0, 2.0, 0.0)),
+            &Vector3::new(1.0, 1.0, 0.0),
+        );
+        assert_vector_eq(
+            &triangle.barycentric(&Vector3::new(0.5, 0.5, 0.0)).unwrap(),
+            &Vector3::new(0.5, 0.25, 0.25),
+        );
+        let flat = Triangle::new(
+            Vector3::zero(),
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(2.0, 2.0, 0.0),
+        );
+        assert_eq!(flat.barycentric(&Vector3::zero()), None);
+    }
+
+    fn camera_frustum() -> Frustum<f64> {
+        // 90 degree camera at the origin looking down -Z, near 1, far 100.
+        let (near, far) = (1.0, 100.0);
+        let nf = 1.0 / (near - far);
+        let projection = Matrix4::new([
+            1.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            (far + near) * nf,
+            -1.0,
+            0.0,
+            0.0,
+            2.0 * far * near * nf,
+            0.0,
+        ]);
+        Frustum::from_matrix(&projection)
+    }
+
+    #[test]
+    fn test_frustum_planes() {
+        let frustum = camera_frustum();
+        assert!(frustum.contains_point(&Vector3::new(0.0, 0.0, -10.0)));
+        assert!(frustum.contains_point(&Vector3::new(9.0, -9.0, -10.0)));
+        assert!(!frustum.contains_point(&Vector3::new(11.0, 0.0, -10.0)));
+        assert!(!frustum.contains_point(&Vector3::new(0.0, 0.0, -0.5)));
+        assert!(!frustum.contains_point(&Vector3::new(0.0, 0.0, -101.0)));
+        assert!(!frustum.contains_point(&Vector3::new(0.0, 0.0, 10.0)));
+    }
+
+    #[test]
+    fn test_frustum_containment() {
+        let frustum = camera_frustum();
+        let inside = Aabb::from_center_half_extents(
+            Vector3::new(0.0, 0.0, -10.0),
+            Vector3::new(1.0, 1.0, 1.0),
+        );
+        let straddling = Aabb::from_center_half_extents(
+            Vector3::new(10.0, 0.0, -10.0),
+            Vector3::new(1.0, 1.0, 1.0),
+        );
+        let behind = Aabb::from_center_half_extents(
+            Vector3::new(0.0, 0.0, 10.0),
+            Vector3::new(1.0, 1.0, 1.0),
+        );
+        assert_eq!(frustum.test_aabb(&inside), Containment::Inside);
+        assert_eq!(frustum.test_aabb(&straddling), Containment::Intersecting);
+        assert_eq!(frustum.test_aabb(&behind), Containment::Outside);
+
+        assert_eq!(
+            frustum.test_sphere(&Sphere::new(Vector3::new(0.0, 0.0, -50.0), 5.0)),
+            Containment::Inside
+        );
+        assert_eq!(
+            frustum.test_sphere(&Sphere::new(Vector3::new(0.0, 0.0, -100.0), 5.0)),
+            Containment::Intersecting
+        );
+        assert_eq!(
+            frustum.test_sphere(&Sphere::new(Vector3::new(-30.0, 0.0, -10.0), 5.0)),
+            Containment::Outside
+        );
+    }
+
+    #[test]
+    fn test_frustum_batch_cull() {
+        let frustum = camera_frustum();
+        let chunks: Vec<_> = (-4..4)
+            .flat_map(|x| (-4..4).map(move |z| (x, z)))
+            .map(|(x, z)| {
+                let min = Vector3::new(x as f64 * 32.0, -16.0, z as f64 * 32.0);
+                Aabb::new(min, min + Vector3::new(32.0, 32.0, 32.0))
+            })
+            .collect();
+
+        let mut visible = Vec::new();
+        frustum.cull_aabbs(&chunks, &mut visible);
+        let expected: Vec<usize> = (0..chunks.len())
+            .filter(|&i| frustum.intersects_aabb(&chunks[i]))
+            .collect();
+        assert_eq!(visible, expected);
+        assert!(!visible.is_empty() && visible.len() < chunks.len());
+    }
+
+    #[test]
+    fn test_voxel_traversal_axis_aligned() {
+        let ray = Ray::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(1.0, 0.0, 0.0));
+        let hits: Vec<_> = VoxelTraversal::new(&ray, 1.0, 3.0).collect();
+        let cells: Vec<_> = hits.iter().map(|hit| hit.cell).collect();
+        assert_eq!(
+            cells,
+            vec![
+                Vector3i::new(0, 0, 0),
+                Vector3i::new(1, 0, 0),
+                Vector3i::new(2, 0, 0),
+                Vector3i::new(3, 0, 0),
+            ]
+        );
+        assert_eq!(hits[0].distance, 0.0);
+        assert_eq!(hits[0].normal, Vector3i::zero());
+        assert_eq!(hits[2].distance, 1.5);
+        assert_eq!(hits[2].normal, Vector3i::new(-1, 0, 0));
+    }
+
+    #[test]
+    fn test_voxel_traversal_negative_direction() {
+        let ray = Ray::new(Vector3::new(1.0, 2.5, 0.5), Vector3::new(-1.0, -1.0, 0.0));
+        let cells: Vec<_> = ray
+            .voxel_traversal(2.0, 10.0)
+            .take(4)
+            .map(|hit| (hit.cell, hit.normal))
+            .collect();
+        assert_eq!(
+            cells,
+            vec![
+                (Vector3i::new(0, 1, 0), Vector3i::zero()),
+                (Vector3i::new(0, 0, 0), Vector3i::new(0, 1, 0)),
+                (Vector3i::new(-1, 0, 0), Vector3i::new(1, 0, 0)),
+                (Vector3i::new(-1, -1, 0), Vector3i::new(0, 1, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_voxel_traversal_is_connected() {
+        let ray = Ray::new(
+            Vector3::new(0.3, -7.1, 2.9),
+            Vector3::new(0.37, 0.81, -0.46),
+        );
+        let hits: Vec<_> = ray.voxel_traversal(1.0, 50.0).collect();
+        assert!(hits.len() > 50);
+        for pair in hits.windows(2) {
+            let delta = pair[1].cell - pair[0].cell;
+            assert_eq!(delta.abs().x + delta.abs().y + delta.abs().z, 1);
+            assert_eq!(delta, -pair[1].normal);
+            assert!(pair[1].distance >= pair[0].distance);
+            // The entry point lies on the shared face.
+            let entry = ray.at(pair[1].distance);
+            let axis = (0..3).find(|&axis| pair[1].normal[axis] != 0).unwrap();
+            let face = pair[1].cell[axis] + if pair[1].normal[axis] < 0 { 0 } else { 1 };
+            assert!((entry[axis] - face as f64).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_voxel_traversal_rejects_bad_rays() {
+        let direction = Vector3::new(1.0, 0.0, 0.0);
+        for origin in [
+            Vector3::new(f64::NAN, 0.0, 0.0),
+            Vector3::new(0.0, f64::INFINITY, 0.0),
+            Vector3::new(0.0, 0.0, 1e30),
+        ] {
+            assert_eq!(
+                Ray::new(origin, direction)
+                    .voxel_traversal(1.0, 10.0)
+                    .count(),
+                0
+            );
+        }
+        let ray = Ray::new(
+            Vector3::new(0.5, 0.5, 0.5),
+            Vector3::new(f64::NAN, 0.0, 0.0),
+        );
+        assert_eq!(ray.voxel_traversal(1.0, 10.0).count(), 0);
+        let ray = Ray::new(Vector3::new(0.5, 0.5, 0.5), direction);
+        assert_eq!(ray.voxel_traversal(1.0, f64::NAN).count(), 0);
+        assert_eq!(ray.voxel_traversal(0.0, 10.0).count(), 0);
+
+        // 2^31 is one past the last cell, though i32::MAX rounds to it in
+        // f32.
+        let ray = Ray::new(
+            Vector3::new(2147483648.0f32, 0.5, 0.5),
+            Vector3::new(1.0, 0.0, 0.0),
+        );
+        assert_eq!(ray.voxel_traversal(1.0, 10.0).count(), 0);
+
+        // Walks stop at the last cell instead of overflowing.
+        let ray = Ray::new(Vector3::new(2147483646.5, 0.5, 0.5), direction);
+        let cells: Vec<i32> = ray
+            .voxel_traversal(1.0, 10.0)
+            .map(|hit| hit.cell.x)
+            .collect();
+        assert_eq!(cells, [i32::MAX - 1, i32::MAX]);
+        let ray = Ray::new(Vector3::new(-2147483646.5, 0.5, 0.5), -direction);
+        let cells: Vec<i32> = ray
+            .voxel_traversal(1.0, 10.0)
+            .map(|hit| hit.cell.x)
+            .collect();
+        assert_eq!(cells, [i32::MIN + 1, i32::MIN]);
+    }
+
+    #[test]
+    fn test_voxel_traversal_early_exit() {
+        let ray = Ray::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(0.0, 0.0, -1.0));
+        let solid = Vector3i::new(0, 0, -5);
+        let hit = ray
+            .voxel_traversal(1.0, 100.0)
+            .find(|hit| hit.cell == solid)
+            .unwrap();
+        assert_eq!(hit.distance, 4.5);
+        assert_eq!(hit.normal, Vector3i::new(0, 0, 1));
+        assert_eq!(ray.voxel_traversal(1.0, 3.0).count(), 4);
+    }
+}
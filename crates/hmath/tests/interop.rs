@@ -0,0 +1,130 @@
+#[cfg(all(test, any(feature = "glam", feature = "nalgebra", feature = "mint")))]
+mod tests {
+    use hmath::matrix::Matrix4;
+    use hmath::quaternion::Quaternion;
+    use hmath::vector::Vector3;
+
+    fn sample_matrices() -> (Matrix4<f64>, Matrix4<f64>) {
+        let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), 1.1);
+        let a = Matrix4::from_trs(
+            &Vector3::new(1.0, -2.0, 3.0),
+            &rotation,
+            &Vector3::new(2.0, 1.0, 0.5),
+        );
+        let b = Matrix4::from_translation(&Vector3::new(-4.0, 5.0, 6.0));
+        (a, b)
+    }
+
+    #[cfg(any(feature = "glam", feature = "nalgebra"))]
+    fn assert_close(a: Vector3<f64>, b: Vector3<f64>) {
+        assert!((a - b).length() < 1e-12, "{:?} != {:?}", a, b);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam() {
+        use glam::{DMat4, DQuat, DVec3, IVec2, Mat2, Vec4};
+        use hmath::matrix::Matrix2;
+        use hmath::vector::{Vector2i, Vector4f};
+
+        let v = Vector4f::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(Vec4::from(v), Vec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Vector4f::from(Vec4::from(v)), v);
+        assert_eq!(Vector2i::from(IVec2::new(-1, 7)), Vector2i::new(-1, 7));
+
+        let q = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.5);
+        let g = DQuat::from(q);
+        assert!(g.abs_diff_eq(DQuat::from_rotation_y(0.5), 1e-15));
+        assert_eq!(Quaternion::from(g), q);
+
+        let (a, b) = sample_matrices();
+        let point = Vector3::new(0.3, -0.7, 1.9);
+        let ga = DMat4::from(a);
+        assert_eq!(ga.w_axis.truncate(), DVec3::new(1.0, -2.0, 3.0));
+        assert_close(
+            ga.transform_point3(point.into()).into(),
+            a.transform_point(&point),
+        );
+        assert_close(
+            DMat4::from(a * b).transform_point3(point.into()).into(),
+            (DMat4::from(b) * ga).transform_point3(point.into()).into(),
+        );
+        assert_eq!(Matrix4::from(ga), a);
+
+        let rotation = Matrix2::from_angle(0.5f32);
+        let x = hmath::vector::Vector2f::new(1.0, 0.0);
+        let rotated = Mat2::from(rotation) * glam::Vec2::from(x);
+        assert!((rotated - glam::Vec2::from(rotation.transform_vector(&x))).length() < 1e-6);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra() {
+        use hmath::vector::Vector4d;
+
+        let v = Vector4d::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(
+            nalgebra::Vector4::from(v),
+            nalgebra::Vector4::new(1.0, 2.0, 3.0, 4.0)
+        );
+        assert_eq!(Vector4d::from(nalgebra::Vector4::from(v)), v);
+        let p = nalgebra::Point3::from(Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(p), Vector3::new(1.0, 2.0, 3.0));
+
+        let q = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.5);
+        let n = nalgebra::UnitQuaternion::from(q);
+        let expected = nalgebra::UnitQuaternion::from_axis_angle(&nalgebra::Vector3::x_axis(), 0.5);
+        assert!(n.angle_to(&expected) < 1e-12);
+        assert_eq!(nalgebra::Quaternion::from(q).w, q.w);
+        assert_eq!(Quaternion::from(nalgebra::Quaternion::from(q)), q);
+
+        let (a, b) = sample_matrices();
+        let point = Vector3::new(0.3, -0.7, 1.9);
+        let na = nalgebra::Matrix4::from(a);
+        assert_eq!(na[(0, 3)], 1.0);
+        assert_close(
+            na.transform_point(&point.into()).into(),
+            a.transform_point(&point),
+        );
+        assert_close(
+            nalgebra::Matrix4::from(a * b)
+                .transform_point(&point.into())
+                .into(),
+            (nalgebra::Matrix4::from(b) * na)
+                .transform_point(&point.into())
+                .into(),
+        );
+        assert_eq!(Matrix4::from(na), a);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn test_mint() {
+        use mint::IntoMint;
+
+        let v = Vector3::new(1.0f32, 2.0, 3.0);
+        let m: <Vector3<f32> as IntoMint>::MintType = v.into();
+        assert_eq!(
+            m,
+            mint::Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0
+            }
+        );
+        assert_eq!(Vector3::from(m), v);
+
+        let q = Quaternion::new(0.5, -0.5, 0.5, -0.5);
+        let mq = mint::Quaternion::from(q);
+        assert_eq!((mq.s, mq.v.x), (0.5, -0.5));
+        assert_eq!(Quaternion::from(mq), q);
+
+        let (a, _) = sample_matrices();
+        let column = mint::ColumnMatrix4::from(a);
+        assert_eq!((column.w.x, column.w.y, column.w.z), (1.0, -2.0, 3.0));
+        let row = mint::RowMatrix4::from(a);
+        assert_eq!((row.x.w, row.y.w, row.z.w), (1.0, -2.0, 3.0));
+        assert_eq!(Matrix4::from(column), a);
+        assert_eq!(Matrix4::from(row), a);
+    }
+}
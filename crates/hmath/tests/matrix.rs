@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use hmath::matrix::{Matrix2, Matrix3, Matrix4, Matrix4d, Matrix4x4};
+    use hmath::quaternion::Quaternion;
+    use hmath::vector::Vector3;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn assert_matrix_eq(a: &Matrix4x4, b: &Matrix4x4) {
+        for i in 0..16 {
+            assert!(
+                (a.data[i] - b.data[i]).abs() < 1e-5,
+                "element {}: {} != {}",
+                i,
+                a.data[i],
+                b.data[i]
+            );
+        }
+    }
+
+    fn assert_vector_eq(a: &Vector3<f32>, b: &Vector3<f32>) {
+        assert!((a.x - b.x).abs() < 1e-5);
+        assert!((a.y - b.y).abs() < 1e-5);
+        assert!((a.z - b.z).abs() < 1e-5);
+    }
+
+    fn sample_trs() -> Matrix4x4 {
+        let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.7);
+        Matrix4x4::from_trs(
+            &Vector3::new(1.0, -2.0, 3.0),
+            &rotation,
+            &Vector3::new(2.0, 0.5, 1.5),
+        )
+    }
+
+    #[test]
+    fn test_matrix4x4_determinant() {
+        assert_eq!(Matrix4x4::identity().determinant(), 1.0);
+        let scale = Matrix4x4::from_scale(&Vector3::new(2.0, 3.0, 4.0));
+        assert_eq!(scale.determinant(), 24.0);
+        assert!((sample_trs().determinant() - 1.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_matrix4x4_inverse() {
+        let m = sample_trs();
+        let inverse = m.inverse().unwrap();
+        assert_matrix_eq(&(m * inverse), &Matrix4x4::identity());
+        assert_matrix_eq(&m.inverse_affine().unwrap(), &inverse);
+    }
+
+    #[test]
+    fn test_matrix4x4_singular_inverse() {
+        let m = Matrix4x4::from_scale(&Vector3::new(1.0, 0.0, 1.0));
+        assert!(m.inverse().is_none());
+        assert!(m.inverse_affine().is_none());
+    }
+
+    #[test]
+    fn test_matrix4x4_transform_point() {
+        let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+        let m = Matrix4x4::from_rotation(&rotation)
+            * Matrix4x4::from_translation(&Vector3::new(10.0, 0.0, 0.0));
+        let point = m.transform_point(&Vector3::new(1.0, 0.0, 0.0));
+        assert_vector_eq(&point, &Vector3::new(10.0, 1.0, 0.0));
+        let vector = m.transform_vector(&Vector3::new(1.0, 0.0, 0.0));
+        assert_vector_eq(&vector, &Vector3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_matrix4x4_rotation_matches_quaternion() {
+        let rotation = Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), 1.1);
+        let v = Vector3::new(0.3, -1.2, 2.5);
+        let expected = rotation.rotate_vector(&v);
+        let actual = Matrix4x4::from_rotation(&rotation).transform_vector(&v);
+        assert_vector_eq(&actual, &expected);
+    }
+
+    #[test]
+    fn test_matrix4x4_trs_round_trip() {
+        let m = sample_trs();
+        let (translation, rotation, scale) = m.to_trs();
+        assert_vector_eq(&translation, &Vector3::new(1.0, -2.0, 3.0));
+        assert_vector_eq(&scale, &Vector3::new(2.0, 0.5, 1.5));
+        assert_matrix_eq(&Matrix4x4::from_trs(&translation, &rotation, &scale), &m);
+    }
+
+    #[test]
+    fn test_matrix4x4_project_point() {
+        let mut m = Matrix4x4::identity();
+        m.data[15] = 2.0;
+        let point = m.project_point(&Vector3::new(2.0, 4.0, 6.0));
+        assert_vector_eq(&point, &Vector3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_matrix2_inverse() {
+        let m = Matrix2::<f64>::new([4.0, 7.0, 2.0, 6.0]);
+        assert_eq!(m.determinant(), 10.0);
+        let product = m * m.inverse().unwrap();
+        for (value, expected) in product
+            .data
+            .iter()
+            .zip(Matrix2::<f64>::identity().data.iter())
+        {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_matrix3_inverse() {
+        let m = Matrix3::<f64>::new([2.0, 0.0, 1.0, 1.0, 3.0, 0.0, 0.0, 1.0, 4.0]);
+        let product = m * m.inverse().unwrap();
+        for (value, expected) in product
+            .data
+            .iter()
+            .zip(Matrix3::<f64>::identity().data.iter())
+        {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        assert!(Matrix3::<f64>::zero().inverse().is_none());
+    }
+
+    #[test]
+    fn test_matrix4_precision_conversion() {
+        let m = sample_trs();
+        let wide: Matrix4d = m.into();
+        assert_eq!(wide.cast::<f32>(), m);
+
+        let translation = Matrix4::from_translation(&Vector3::new(1.0e9 + 0.25, 0.0, 0.0));
+        let inverse = translation.inverse_affine().unwrap();
+        assert_eq!(inverse.data[12], -(1.0e9 + 0.25));
+    }
+}
@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod tests {
+    use hmath::noise::fractal::{Billow, DomainWarp, Fbm, FractalConfig, Ridged};
+    use hmath::noise::open_simplex::OpenSimplex2;
+    use hmath::noise::perlin::Perlin;
+    use hmath::noise::value::ValueNoise;
+    use hmath::noise::worley::{Worley, WorleyReturn};
+    use hmath::noise::Noise;
+    use hmath::vector::{Vector2d, Vector3d, Vector4d};
+
+    /// A deterministic spread of sample points that avoids lattice corners.
+    fn sample_points() -> impl Iterator<Item = Vector4d> {
+        (0..2000).map(|i| {
+            let t = i as f64;
+            Vector4d::new(
+                t * 0.137 - 50.0,
+                t * 0.291 + 3.3,
+                t * -0.073 + 7.1,
+                t * 0.053 - 1.7,
+            )
+        })
+    }
+
+    fn assert_in_range<N: Noise>(noise: &N) {
+        for p in sample_points() {
+            let values = [
+                noise.sample2(&Vector2d::new(p.x, p.y)),
+                noise.sample3(&Vector3d::new(p.x, p.y, p.z)),
+                noise.sample4(&p),
+            ];
+            for value in values {
+                assert!(
+                    (-1.0..=1.0).contains(&value),
+                    "{} out of range at {:?}",
+                    value,
+                    p
+                );
+            }
+        }
+    }
+
+    fn assert_deterministic<N: Noise>(make: impl Fn(u64) -> N) {
+        let (a, b, c) = (make(7), make(7), make(8));
+        let mut differs = false;
+        for p in sample_points().take(100) {
+            let p3 = Vector3d::new(p.x, p.y, p.z);
+            assert_eq!(a.sample3(&p3).to_bits(), b.sample3(&p3).to_bits());
+            assert_eq!(a.sample4(&p).to_bits(), b.sample4(&p).to_bits());
+            differs |= a.sample3(&p3) != c.sample3(&p3);
+        }
+        assert!(differs, "different seeds produced identical noise");
+    }
+
+    #[test]
+    fn test_noise_deterministic_per_seed() {
+        assert_deterministic(Perlin::new);
+        assert_deterministic(OpenSimplex2::new);
+        assert_deterministic(ValueNoise::new);
+        assert_deterministic(Worley::new);
+    }
+
+    /// Pins exact outputs so that changes to any generator show up.
+    #[test]
+    fn test_noise_golden_values() {
+        fn samples<N: Noise>(noise: N) -> [f64; 3] {
+            let p = Vector4d::new(1.25, -3.5, 7.75, 0.625);
+            [
+                noise.sample2(&Vector2d::new(p.x, p.y)),
+                noise.sample3(&Vector3d::new(p.x, p.y, p.z)),
+                noise.sample4(&p),
+            ]
+        }
+        assert_eq!(
+            samples(Perlin::new(42)),
+            [0.17236328125, 0.341322283744812, -0.03469058209113428]
+        );
+        assert_eq!(
+            samples(OpenSimplex2::new(42)),
+            [
+                -0.12397898919593275,
+                0.07765931823673261,
+                -0.006346866420214257
+            ]
+        );
+        assert_eq!(
+            samples(ValueNoise::new(42)),
+            [
+                -0.5464001225490196,
+                -0.2826610490387561,
+                -0.17043701257812333
+            ]
+        );
+        assert_eq!(
+            samples(Worley::new(42)),
+            [0.7354601355184821, 0.468237137912496, 0.5215595468935852]
+        );
+    }
+
+    #[test]
+    fn test_noise_accepts_nan() {
+        fn sample_all<N: Noise>(noise: N) {
+            let nan = Vector4d::new(f64::NAN, 0.5, f64::NAN, 1.5);
+            noise.sample2(&Vector2d::new(nan.x, nan.y));
+            noise.sample3(&Vector3d::new(nan.x, nan.y, nan.z));
+            noise.sample4(&nan);
+        }
+        sample_all(Perlin::new(3));
+        sample_all(OpenSimplex2::new(3));
+        sample_all(ValueNoise::new(3));
+        sample_all(Worley::new(3));
+    }
+
+    #[test]
+    fn test_noise_accepts_huge_coordinates() {
+        fn sample_all<N: Noise>(noise: N) {
+            for far in [3e9, -3e9] {
+                let point = Vector4d::new(far + 0.25, -far + 0.5, far - 0.75, far);
+                assert!(noise.sample2(&Vector2d::new(point.x, point.y)).is_finite());
+                assert!(noise
+                    .sample3(&Vector3d::new(point.x, point.y, point.z))
+                    .is_finite());
+                assert!(noise.sample4(&point).is_finite());
+            }
+        }
+        sample_all(Perlin::new(3));
+        sample_all(OpenSimplex2::new(3));
+        sample_all(ValueNoise::new(3));
+        sample_all(Worley::new(3));
+
+        // The lattice repeats every 256 cells, and 3e9 is a multiple of 256.
+        let perlin = Perlin::new(3);
+        let near = Vector3d::new(0.25, 0.5, -0.75);
+        let far = Vector3d::new(3e9 + 0.25, 0.5, -3e9 - 0.75);
+        assert_eq!(perlin.sample3(&far), perlin.sample3(&near));
+    }
+
+    #[test]
+    fn test_noise_ranges() {
+        assert_in_range(&Perlin::new(1));
+        assert_in_range(&OpenSimplex2::new(1));
+        assert_in_range(&ValueNoise::new(1));
+        assert_in_range(&Worley::with_return_type(1, WorleyReturn::CellValue));
+    }
+
+    #[test]
+    fn test_perlin_zero_on_lattice() {
+        let perlin = Perlin::new(3);
+        assert_eq!(perlin.sample2(&Vector2d::new(4.0, -2.0)), 0.0);
+        assert_eq!(perlin.sample3(&Vector3d::new(1.0, 2.0, -3.0)), 0.0);
+    }
+
+    #[test]
+    fn test_noise_is_continuous() {
+        let simplex = OpenSimplex2::new(5);
+        for p in sample_points().take(200) {
+            let q = p + Vector4d::new(1e-7, 1e-7, 1e-7, 1e-7);
+            assert!((simplex.sample4(&p) - simplex.sample4(&q)).abs() < 1e-4);
+            let (p3, q3) = (Vector3d::new(p.x, p.y, p.z), Vector3d::new(q.x, q.y, q.z));
+            assert!((simplex.sample3(&p3) - simplex.sample3(&q3)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_worley_distances() {
+        let f1 = Worley::new(2);
+        let f2 = Worley::with_return_type(2, WorleyReturn::F2);
+        let edge = Worley::with_return_type(2, WorleyReturn::F2MinusF1);
+        for p in sample_points().take(200) {
+            let p3 = Vector3d::new(p.x, p.y, p.z);
+            let (a, b) = (f1.sample3(&p3), f2.sample3(&p3));
+            assert!(a >= 0.0 && a <= b);
+            assert!((edge.sample3(&p3) - (b - a)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_fractal_combinators() {
+        let config = FractalConfig {
+            octaves: 4,
+            ..FractalConfig::default()
+        };
+        assert_in_range(&Fbm::new(Perlin::new(1), config));
+        assert_in_range(&Ridged::new(OpenSimplex2::new(1), config));
+        assert_in_range(&Billow::new(ValueNoise::new(1), config));
+
+        let single = Fbm::new(
+            Perlin::new(1),
+            FractalConfig {
+                octaves: 1,
+                ..config
+            },
+        );
+        let p = Vector3d::new(0.3, 1.7, -2.2);
+        assert_eq!(single.sample3(&p), Perlin::new(1).sample3(&p));
+
+        let base = Perlin::new(1);
+        let warped = DomainWarp::new(&base, OpenSimplex2::new(2), 0.0);
+        assert_eq!(warped.sample3(&p), base.sample3(&p));
+        let warped = DomainWarp::new(&base, OpenSimplex2::new(2), 4.0);
+        assert_ne!(warped.sample3(&p), base.sample3(&p));
+    }
+}
@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+    use hmath::matrix::Matrix3;
+    use hmath::quaternion::{EulerOrder, Quaternion};
+    use hmath::vector::Vector3;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_quaternion_eq(a: &Quaternion<f64>, b: &Quaternion<f64>) {
+        // q and -q describe the same rotation.
+        assert!((a.dot(b).abs() - 1.0).abs() < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    fn assert_vector_eq(a: &Vector3<f64>, b: &Vector3<f64>) {
+        assert!((*a - *b).length() < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_quaternion_inverse() {
+        let q = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.8);
+        assert_quaternion_eq(&(q * q.inverse()), &Quaternion::identity());
+        let scaled = Quaternion::new(2.0, 0.0, 0.0, 0.0);
+        assert_eq!(scaled.inverse(), Quaternion::new(0.5, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_quaternion_angle_between() {
+        let a = Quaternion::<f64>::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.3);
+        let b = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 1.3);
+        assert!((a.angle_between(&b) - 1.0).abs() < 1e-9);
+        assert!(a.angle_between(&-a).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_quaternion_slerp() {
+        let axis = Vector3::new(1.0, 0.0, 0.0);
+        let a = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(axis, FRAC_PI_2);
+        assert_quaternion_eq(&a.slerp(&b, 0.0), &a);
+        assert_quaternion_eq(&a.slerp(&b, 1.0), &b);
+        let halfway = Quaternion::from_axis_angle(axis, FRAC_PI_2 / 2.0);
+        assert_quaternion_eq(&a.slerp(&b, 0.5), &halfway);
+        // Takes the short way round even when the inputs straddle the hemisphere.
+        assert_quaternion_eq(&a.slerp(&-b, 0.5), &halfway);
+    }
+
+    #[test]
+    fn test_quaternion_nlerp() {
+        let axis = Vector3::new(0.0, 1.0, 0.0);
+        let a = Quaternion::from_axis_angle(axis, -0.5);
+        let b = Quaternion::from_axis_angle(axis, 0.5);
+        assert_quaternion_eq(&a.nlerp(&b, 0.5), &Quaternion::identity());
+    }
+
+    #[test]
+    fn test_quaternion_rotation_arc() {
+        let from = Vector3::new(1.0, 0.0, 0.0);
+        let to = Vector3::new(0.0, 0.6, 0.8);
+        let q = Quaternion::from_rotation_arc(&from, &to);
+        assert_vector_eq(&q.rotate_vector(&from), &to);
+
+        let opposite = Quaternion::from_rotation_arc(&from, &-from);
+        assert_vector_eq(&opposite.rotate_vector(&from), &-from);
+        assert!((opposite.angle_between(&Quaternion::identity()) - PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_quaternion_look_rotation() {
+        let forward = Vector3::new(1.0, 0.0, 1.0).normalize();
+        let up = Vector3::new(0.0, 1.0, 0.0);
+        let q = Quaternion::look_rotation(&forward, &up);
+        assert_vector_eq(&q.rotate_vector(&Vector3::new(0.0, 0.0, 1.0)), &forward);
+        assert_vector_eq(&q.rotate_vector(&Vector3::new(0.0, 1.0, 0.0)), &up);
+
+        let straight_up = Quaternion::look_rotation(&up, &up);
+        assert_vector_eq(
+            &straight_up.rotate_vector(&Vector3::new(0.0, 0.0, 1.0)),
+            &up,
+        );
+    }
+
+    #[test]
+    fn test_quaternion_rotation_matrix_round_trip() {
+        for angle in [0.0, 0.5, 2.0, 3.1, PI] {
+            let axis = Vector3::new(-0.3, 0.7, 0.2).normalize();
+            let q = Quaternion::from_axis_angle(axis, angle);
+            let matrix: Matrix3<f64> = q.to_rotation_matrix();
+            assert_quaternion_eq(&Quaternion::from_rotation_matrix(&matrix), &q);
+        }
+    }
+
+    const ORDERS: [EulerOrder; 6] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ];
+
+    #[test]
+    fn test_quaternion_euler_order_round_trip() {
+        let angles = Vector3::new(0.3, -0.7, 1.2);
+        for order in ORDERS {
+            let q = Quaternion::from_euler_order(&angles, order);
+            let back = q.to_euler_order(order);
+            assert_vector_eq(&back, &angles);
+        }
+    }
+
+    #[test]
+    fn test_quaternion_euler_order_application() {
+        // ZXY applies roll, then pitch about X, then yaw about Y.
+        let angles = Vector3::new(0.4, 1.1, 0.2);
+        let expected = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 1.1)
+            * Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.4)
+            * Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.2);
+        assert_quaternion_eq(
+            &Quaternion::from_euler_order(&angles, EulerOrder::ZXY),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn test_quaternion_euler_gimbal_lock() {
+        for order in ORDERS {
+            let mut angles = Vector3::new(0.5, 0.5, 0.5);
+            let middle = match order {
+                EulerOrder::XYZ | EulerOrder::ZYX => 1,
+                EulerOrder::YXZ | EulerOrder::ZXY => 0,
+                EulerOrder::XZY | EulerOrder::YZX => 2,
+            };
+            angles[middle] = -FRAC_PI_2;
+            let q = Quaternion::from_euler_order(&angles, order);
+            let back = q.to_euler_order(order);
+            assert!((back[middle] + FRAC_PI_2).abs() < 1e-6);
+            assert_quaternion_eq(&Quaternion::from_euler_order(&back, order), &q);
+        }
+    }
+
+    #[test]
+    fn test_quaternion_legacy_euler_is_xyz() {
+        let angles = Vector3::new(0.3, 0.2, 0.5);
+        let q = Quaternion::from_euler(&angles);
+        assert_quaternion_eq(&q, &Quaternion::from_euler_order(&angles, EulerOrder::XYZ));
+        assert_vector_eq(&q.to_euler(), &angles);
+    }
+}
@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use hmath::sampling::blue_noise::BlueNoiseTile;
+    use hmath::sampling::rng::{Pcg32, Rng, SplitMix64, Xoshiro256PlusPlus};
+    use hmath::sampling::sequence::{halton, halton2, r2, radical_inverse, sobol, sobol2};
+    use hmath::sampling::warp::{
+        cosine_hemisphere, orthonormal_basis, to_world, uniform_cone, uniform_disk, uniform_sphere,
+    };
+    use hmath::vector::{Vector2, Vector2d, Vector3};
+
+    #[test]
+    fn test_pcg32_reference_output() {
+        // First outputs of the reference `pcg32-demo` seeded with (42, 54).
+        let mut rng = Pcg32::new(42, 54);
+        let expected = [
+            0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e,
+        ];
+        for value in expected {
+            assert_eq!(rng.next_u32(), value);
+        }
+    }
+
+    #[test]
+    fn test_pcg32_advance() {
+        let mut stepped = Pcg32::new(7, 3);
+        for _ in 0..1000 {
+            stepped.next_u32();
+        }
+        let mut jumped = Pcg32::new(7, 3);
+        jumped.advance(1000);
+        assert_eq!(stepped, jumped);
+    }
+
+    #[test]
+    fn test_rng_ranges() {
+        let mut xoshiro = Xoshiro256PlusPlus::new(1);
+        let mut split = SplitMix64::new(1);
+        for _ in 0..10_000 {
+            let f = xoshiro.next_f64();
+            assert!((0.0..1.0).contains(&f));
+            let f = split.next_f32();
+            assert!((0.0..1.0).contains(&f));
+            assert!(xoshiro.next_bounded(10) < 10);
+        }
+
+        let mut jumped = Xoshiro256PlusPlus::new(1);
+        jumped.jump();
+        assert_ne!(jumped.next_u64(), Xoshiro256PlusPlus::new(1).next_u64());
+    }
+
+    #[test]
+    fn test_halton() {
+        assert_eq!(radical_inverse(2, 0), 0.0);
+        assert_eq!(radical_inverse(2, 1), 0.5);
+        assert_eq!(radical_inverse(2, 6), 0.375);
+        assert!((halton(5, 1) - 7.0 / 9.0).abs() < 1e-12);
+        assert_eq!(halton2(1), Vector2::new(0.5, 1.0 / 3.0));
+    }
+
+    /// Every one of the `2^m` elementary intervals of each shape must hold
+    /// exactly one of the first `2^m` points.
+    fn assert_zero_two_net(points: &[Vector2d]) {
+        let m = points.len().trailing_zeros();
+        for x_bits in 0..=m {
+            let (nx, ny) = (1usize << x_bits, 1usize << (m - x_bits));
+            let mut counts = vec![0; points.len()];
+            for p in points {
+                let cell = (p.y * ny as f64) as usize * nx + (p.x * nx as f64) as usize;
+                counts[cell] += 1;
+            }
+            assert!(
+                counts.iter().all(|&c| c == 1),
+                "not stratified at {}x{}",
+                nx,
+                ny
+            );
+        }
+    }
+
+    #[test]
+    fn test_sobol() {
+        let expected = [0.0, 0.5, 0.75, 0.25];
+        for (i, value) in expected.iter().enumerate() {
+            assert_eq!(sobol(i as u32, 1), *value);
+        }
+
+        let points: Vec<_> = (0..256).map(sobol2).collect();
+        assert_zero_two_net(&points);
+        let points: Vec<_> = (256..512).map(sobol2).collect();
+        assert_zero_two_net(&points);
+
+        // Every dimension on its own stratifies power-of-two prefixes.
+        for dimension in 0..16 {
+            let mut seen = [false; 64];
+            for i in 0..64 {
+                let cell = (sobol(i, dimension) * 64.0) as usize;
+                assert!(!seen[cell], "dimension {} repeats a stratum", dimension);
+                seen[cell] = true;
+            }
+        }
+    }
+
+    #[test]
+    fn test_r2() {
+        assert_eq!(r2(0), Vector2::new(0.5, 0.5));
+        for i in 0..1000 {
+            let p = r2(i);
+            assert!((0.0..1.0).contains(&p.x) && (0.0..1.0).contains(&p.y));
+        }
+        let p = r2(1);
+        assert!((p.x - 0.254_877_666_246_692_7).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_blue_noise_tile() {
+        let tile = BlueNoiseTile::generate(32, 3);
+        assert_eq!(tile, BlueNoiseTile::generate(32, 3));
+
+        // Every rank is used exactly once.
+        let mut ranks: Vec<_> = tile
+            .values()
+            .iter()
+            .map(|v| (v * 1024.0) as usize)
+            .collect();
+        ranks.sort_unstable();
+        assert!(ranks.iter().enumerate().all(|(i, &r)| i == r));
+
+        // Blue noise has little low-frequency energy, so neighbours differ
+        // more than the 1/3 expected from white noise.
+        let mut difference = 0.0;
+        for y in 0..32 {
+            for x in 0..32 {
+                difference += (tile.sample(x, y) - tile.sample(x + 1, y)).abs();
+                difference += (tile.sample(x, y) - tile.sample(x, y + 1)).abs();
+            }
+        }
+        assert!(difference / 2048.0 > 0.4);
+
+        assert_eq!(tile.sample(-1, 0), tile.sample(31, 0));
+        let rotated = tile.rotate(&Vector2::new(0.9, 0.1), 5, 7);
+        assert!((0.0..1.0).contains(&rotated.x) && (0.0..1.0).contains(&rotated.y));
+        assert!(BlueNoiseTile::from_u8(2, 2, &[0, 64, 128]).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "tile size must be positive")]
+    fn test_blue_noise_tile_rejects_empty() {
+        BlueNoiseTile::generate(0, 3);
+    }
+
+    #[test]
+    fn test_warps() {
+        for i in 0..256 {
+            let u = sobol2(i);
+            let d = uniform_disk(&u);
+            assert!(d.x * d.x + d.y * d.y <= 1.0 + 1e-12);
+
+            let h = cosine_hemisphere(&u);
+            assert!((h.length() - 1.0).abs() < 1e-9 && h.z >= 0.0);
+
+            let s = uniform_sphere(&u);
+            assert!((s.length() - 1.0).abs() < 1e-9);
+
+            let c = uniform_cone(&u, 0.9);
+            assert!(c.z >= 0.9 - 1e-12 && (c.length() - 1.0).abs() < 1e-9);
+        }
+
+        // The sphere samples have no preferred direction.
+        let mean = (0..1024)
+            .map(|i| uniform_sphere(&sobol2(i)))
+            .fold(Vector3::zero(), |a, b| a + b);
+        assert!(mean.length() / 1024.0 < 1e-2);
+    }
+
+    #[test]
+    fn test_orthonormal_basis() {
+        let normals = [
+            Vector3::<f64>::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(1.0, 2.0, -3.0).normalize(),
+            Vector3::new(0.0, 1.0, 0.0),
+        ];
+        for n in normals {
+            let (t, b) = orthonormal_basis(&n);
+            assert!(t.dot(&n).abs() < 1e-12 && b.dot(&n).abs() < 1e-12 && t.dot(&b).abs() < 1e-12);
+            assert!((t.cross(&b) - n).length() < 1e-12);
+            assert!((to_world(&Vector3::new(0.0, 0.0, 1.0), &n) - n).length() < 1e-12);
+        }
+    }
+}
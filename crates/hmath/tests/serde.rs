@@ -0,0 +1,137 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use hmath::color::linear::LinearRgba;
+    use hmath::fixed::Fixed64;
+    use hmath::geometry::aabb::Aabb;
+    use hmath::geometry::frustum::Containment;
+    use hmath::geometry::plane::Plane;
+    use hmath::matrix::{Matrix4, Matrix4x4};
+    use hmath::noise::fractal::FractalConfig;
+    use hmath::quaternion::{EulerOrder, Quaternion};
+    use hmath::transform::Transform;
+    use hmath::vector::{Vector2i, Vector3, Vector3d, Vector3f, Vector4};
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use std::fmt::Debug;
+
+    fn round_trip<V: Serialize + DeserializeOwned + PartialEq + Debug>(value: &V) {
+        let bytes = bincode::serialize(value).unwrap();
+        assert_eq!(&bincode::deserialize::<V>(&bytes).unwrap(), value);
+        let text = serde_json::to_string(value).unwrap();
+        assert_eq!(
+            &serde_json::from_str::<V>(&text).unwrap(),
+            value,
+            "{}",
+            text
+        );
+    }
+
+    fn sample_transform() -> Transform<f64> {
+        Transform::new(
+            Vector3d::new(1.5, -2.0, 1e9),
+            Quaternion::from_axis_angle(Vector3d::new(0.0, 1.0, 0.0), 0.75),
+            Vector3d::new(1.0, 2.0, 3.0),
+        )
+    }
+
+    #[test]
+    fn test_round_trips() {
+        round_trip(&Vector2i::new(-3, 7));
+        round_trip(&Vector3f::new(0.1, -0.2, 0.3));
+        round_trip(&Vector4::new(1.0, 2.0, 3.0, 4.0));
+        round_trip(&Matrix4::<f64>::from_translation(&Vector3d::new(
+            1.0, 2.0, 3.0,
+        )));
+        round_trip(&Quaternion::new(0.5f32, 0.5, -0.5, 0.5));
+        round_trip(&sample_transform());
+        round_trip(&Aabb::new(
+            Vector3d::new(-1.0, -2.0, -3.0),
+            Vector3d::new(1.0, 2.0, 3.0),
+        ));
+        round_trip(&Plane::new(Vector3f::new(0.0, 1.0, 0.0), -4.0));
+        round_trip(&LinearRgba::new(0.25, 0.5, 1.0, 0.75));
+        round_trip(&FractalConfig::default());
+        round_trip(&EulerOrder::ZXY);
+        round_trip(&Containment::Intersecting);
+    }
+
+    #[test]
+    fn test_binary_encoding_is_compact() {
+        let bytes = bincode::serialize(&Vector3f::new(1.0, 2.0, 3.0)).unwrap();
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(
+            bincode::serialize(&Matrix4x4::identity()).unwrap().len(),
+            64
+        );
+        // Translation, rotation and scale: ten doubles and no framing.
+        assert_eq!(bincode::serialize(&sample_transform()).unwrap().len(), 80);
+        assert_eq!(bincode::serialize(&Fixed64::ONE).unwrap().len(), 8);
+    }
+
+    #[test]
+    fn test_text_encoding_is_readable() {
+        let text = serde_json::to_string(&Vector3d::new(1.0, 2.5, -3.0)).unwrap();
+        assert_eq!(text, r#"{"x":1.0,"y":2.5,"z":-3.0}"#);
+        let text = serde_json::to_string(&Quaternion::new(1.0, 0.0, 0.0, 0.0)).unwrap();
+        assert_eq!(text, r#"{"w":1.0,"x":0.0,"y":0.0,"z":0.0}"#);
+        assert_eq!(serde_json::to_string(&EulerOrder::YXZ).unwrap(), r#""YXZ""#);
+
+        let parsed: Vector3d = serde_json::from_str("[1, 2, 3]").unwrap();
+        assert_eq!(parsed, Vector3d::new(1.0, 2.0, 3.0));
+        let parsed: Vector3d = serde_json::from_str(r#"{"z":3,"x":1,"y":2}"#).unwrap();
+        assert_eq!(parsed, Vector3d::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_text_encoding_errors() {
+        assert!(serde_json::from_str::<Vector3d>(r#"{"x":1,"y":2}"#).is_err());
+        assert!(serde_json::from_str::<Vector3d>(r#"{"x":1,"y":2,"z":3,"w":4}"#).is_err());
+        assert!(serde_json::from_str::<Vector3d>(r#"{"x":1,"x":2,"y":2,"z":3}"#).is_err());
+        let short = serde_json::from_str::<Vector3d>("[1, 2]").unwrap_err();
+        assert!(short.to_string().contains("invalid length 2"), "{}", short);
+    }
+
+    #[test]
+    fn test_fixed_round_trips_exactly() {
+        let values = [
+            Fixed64::ZERO,
+            Fixed64::ONE,
+            Fixed64::from_f64(-1.5),
+            Fixed64::PI,
+            Fixed64::EPSILON,
+            -Fixed64::EPSILON,
+            Fixed64::MAX,
+            Fixed64::MIN,
+            Fixed64::from_bits(0x1234_5678_9abc_def0),
+        ];
+        for value in values {
+            round_trip(&value);
+        }
+        round_trip(&Vector3::new(Fixed64::PI, Fixed64::E, Fixed64::MIN));
+    }
+
+    #[test]
+    fn test_fixed_text_encoding() {
+        // Values an f64 holds exactly are plain numbers...
+        assert_eq!(
+            serde_json::to_string(&Fixed64::from_f64(-2.25)).unwrap(),
+            "-2.25"
+        );
+        assert_eq!(
+            serde_json::to_string(&Fixed64::MIN).unwrap(),
+            "-2147483648.0"
+        );
+        // ...the rest are exact decimal strings.
+        assert_eq!(
+            serde_json::to_string(&Fixed64::MAX).unwrap(),
+            r#""2147483647.99999999976716935634613037109375""#
+        );
+        let parsed: Fixed64 = serde_json::from_str(r#""0.1""#).unwrap();
+        assert_eq!(parsed, Fixed64::from_f64(0.1));
+        let parsed: Fixed64 = serde_json::from_str("3").unwrap();
+        assert_eq!(parsed, Fixed64::from_int(3));
+        assert!(serde_json::from_str::<Fixed64>(r#""2147483648""#).is_err());
+        assert!(serde_json::from_str::<Fixed64>(r#""1.2.3""#).is_err());
+        assert!(serde_json::from_str::<Fixed64>("1e12").is_err());
+    }
+}
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use hmath::matrix::Matrix4x4;
+    use hmath::quaternion::Quaternion;
+    use hmath::sampling::rng::{Pcg32, Rng};
+    use hmath::simd::{self, Backend};
+    use hmath::vector::Vector3f;
+
+    fn supported() -> impl Iterator<Item = Backend> {
+        Backend::ALL
+            .into_iter()
+            .filter(|backend| backend.is_supported())
+    }
+
+    fn random_matrix(rng: &mut Pcg32) -> Matrix4x4 {
+        Matrix4x4 {
+            data: std::array::from_fn(|_| rng.next_f32() * 4.0 - 2.0),
+        }
+    }
+
+    fn random_transform(rng: &mut Pcg32) -> Matrix4x4 {
+        let axis = Vector3f::new(rng.next_f32(), rng.next_f32(), 1.0).normalize();
+        Matrix4x4::from_trs(
+            &Vector3f::new(rng.next_f32() * 10.0, -5.0, rng.next_f32()),
+            &Quaternion::from_axis_angle(axis, rng.next_f32() * 6.0),
+            &Vector3f::new(1.0 + rng.next_f32(), 2.0, 0.5),
+        )
+    }
+
+    fn random_vectors(rng: &mut Pcg32, count: usize) -> Vec<Vector3f> {
+        (0..count)
+            .map(|_| {
+                Vector3f::new(
+                    rng.next_f32() * 100.0,
+                    rng.next_f32() - 0.5,
+                    -rng.next_f32(),
+                )
+            })
+            .collect()
+    }
+
+    fn assert_matrix_close(a: &Matrix4x4, b: &Matrix4x4, tolerance: f32) {
+        for (x, y) in a.data.iter().zip(b.data.iter()) {
+            assert!((x - y).abs() <= tolerance, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_detected_backend_is_supported() {
+        assert!(Backend::detect().is_supported());
+        assert!(Backend::Scalar.is_supported());
+        #[cfg(target_arch = "x86_64")]
+        assert!(Backend::Sse2.is_supported());
+    }
+
+    #[test]
+    fn test_multiply_matches_scalar() {
+        let mut rng = Pcg32::new(7, 0);
+        for _ in 0..100 {
+            let (a, b) = (random_matrix(&mut rng), random_matrix(&mut rng));
+            let expected = a.multiply(&b);
+            assert_eq!(simd::multiply(&a, &b), expected);
+            for backend in supported() {
+                assert_eq!(backend.multiply(&a, &b), expected, "{:?}", backend);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiply_batch() {
+        let mut rng = Pcg32::new(8, 0);
+        let a: Vec<_> = (0..37).map(|_| random_transform(&mut rng)).collect();
+        let b: Vec<_> = (0..37).map(|_| random_transform(&mut rng)).collect();
+        for backend in supported() {
+            let mut out = vec![Matrix4x4::zero(); a.len()];
+            backend.multiply_batch(&a, &b, &mut out);
+            for i in 0..a.len() {
+                assert_eq!(out[i], a[i] * b[i], "{:?}", backend);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "slice lengths differ")]
+    fn test_multiply_batch_length_mismatch() {
+        let mut out = [Matrix4x4::zero(); 2];
+        simd::multiply_batch(
+            &[Matrix4x4::identity(); 2],
+            &[Matrix4x4::identity(); 1],
+            &mut out,
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng = Pcg32::new(9, 0);
+        for _ in 0..100 {
+            let m = random_transform(&mut rng);
+            let inverse = simd::inverse(&m).unwrap();
+            assert_matrix_close(&(m * inverse), &Matrix4x4::identity(), 1e-4);
+            assert_matrix_close(&inverse, &m.inverse().unwrap(), 1e-4);
+
+            let general = random_matrix(&mut rng);
+            let reference = Backend::Scalar.inverse(&general);
+            for backend in supported() {
+                assert_eq!(backend.inverse(&general), reference, "{:?}", backend);
+            }
+        }
+        // A perspective projection, whose last column is not (0, 0, 0, 1).
+        let projection = Matrix4x4::new([
+            1.5, 0.0, 0.0, 0.0, //
+            0.0, 2.0, 0.0, 0.0, //
+            0.0, 0.0, -1.002, -1.0, //
+            0.0, 0.0, -0.2002, 0.0,
+        ]);
+        assert_matrix_close(
+            &(projection * simd::inverse(&projection).unwrap()),
+            &Matrix4x4::identity(),
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let mut singular = Matrix4x4::identity();
+        singular.data[10] = 0.0;
+        for backend in supported() {
+            assert_eq!(backend.inverse(&singular), None);
+            assert_eq!(backend.inverse(&Matrix4x4::zero()), None);
+        }
+    }
+
+    #[test]
+    fn test_transform_batches_match_per_vector() {
+        let mut rng = Pcg32::new(10, 0);
+        let m = random_transform(&mut rng);
+        // An odd length exercises the AVX remainder.
+        let input = random_vectors(&mut rng, 101);
+        for backend in supported() {
+            let mut points = vec![Vector3f::default(); input.len()];
+            let mut vectors = vec![Vector3f::default(); input.len()];
+            backend.transform_points(&m, &input, &mut points);
+            backend.transform_vectors(&m, &input, &mut vectors);
+            for (i, v) in input.iter().enumerate() {
+                assert_eq!(points[i], m.transform_point(v), "{:?}", backend);
+                assert_eq!(vectors[i], m.transform_vector(v), "{:?}", backend);
+            }
+        }
+        simd::transform_points(&m, &[], &mut []);
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use hmath::space_filling::{hilbert, morton};
+
+    #[test]
+    fn test_morton_known_values() {
+        assert_eq!(morton::encode2_32(0b11, 0b00), 0b0101);
+        assert_eq!(morton::encode2_32(0b00, 0b11), 0b1010);
+        assert_eq!(morton::encode3_32(1, 0, 0), 0b001);
+        assert_eq!(morton::encode3_32(0, 1, 0), 0b010);
+        assert_eq!(morton::encode3_32(0, 0, 1), 0b100);
+        assert_eq!(
+            morton::encode3_64(0x1f_ffff, 0x1f_ffff, 0x1f_ffff),
+            (1 << 63) - 1
+        );
+        assert_eq!(morton::encode2_64(u32::MAX, u32::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn test_morton_round_trip() {
+        let samples = [
+            0u32,
+            1,
+            2,
+            3,
+            7,
+            100,
+            511,
+            1023,
+            0x1234,
+            0xffff,
+            0x1f_ffff,
+            0xdead_beef,
+        ];
+        for &x in &samples {
+            for &y in &samples {
+                assert_eq!(
+                    morton::decode2_32(morton::encode2_32(x, y)),
+                    (x & 0xffff, y & 0xffff)
+                );
+                assert_eq!(morton::decode2_64(morton::encode2_64(x, y)), (x, y));
+                for &z in &samples {
+                    assert_eq!(
+                        morton::decode3_32(morton::encode3_32(x, y, z)),
+                        (x & 0x3ff, y & 0x3ff, z & 0x3ff)
+                    );
+                    assert_eq!(
+                        morton::decode3_64(morton::encode3_64(x, y, z)),
+                        (x & 0x1f_ffff, y & 0x1f_ffff, z & 0x1f_ffff)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_morton_signed() {
+        for (x, y, z) in [(0, 0, 0), (-1, 5, -1_048_576), (1_048_575, -7, 3)] {
+            assert_eq!(
+                morton::decode3_64_signed(morton::encode3_64_signed(x, y, z)),
+                (x, y, z)
+            );
+        }
+        // Order along an axis is preserved across zero.
+        assert!(morton::encode3_64_signed(-1, 0, 0) < morton::encode3_64_signed(0, 0, 0));
+    }
+
+    #[test]
+    fn test_morton_stepping() {
+        for (x, y, z) in [(5u32, 9u32, 12u32), (0, 0, 0), (1023, 511, 0)] {
+            let code = morton::encode3_64(x, y, z);
+            assert_eq!(
+                morton::step3_64(code, 0, 1),
+                morton::encode3_64(x + 1, y, z)
+            );
+            assert_eq!(
+                morton::step3_64(code, 1, 7),
+                morton::encode3_64(x, y + 7, z)
+            );
+            assert_eq!(
+                morton::step3_64(code, 2, -1),
+                morton::encode3_64(x, y, z.wrapping_sub(1))
+            );
+
+            let small = morton::encode3_32(x, y, z);
+            assert_eq!(
+                morton::step3_32(small, 2, 3),
+                morton::encode3_32(x, y, z + 3)
+            );
+        }
+
+        let code = morton::encode2_32(40, 17);
+        assert_eq!(morton::step2_32(code, 0, -40), morton::encode2_32(0, 17));
+        assert_eq!(
+            morton::step2_64(morton::encode2_64(40, 17), 1, 5),
+            morton::encode2_64(40, 22)
+        );
+
+        let a = morton::encode3_64(3, 4, 5);
+        let b = morton::encode3_64(10, 20, 30);
+        assert_eq!(morton::add3_64(a, b), morton::encode3_64(13, 24, 35));
+        assert_eq!(morton::sub3_64(b, a), morton::encode3_64(7, 16, 25));
+        assert_eq!(
+            morton::add2_32(morton::encode2_32(1, 2), morton::encode2_32(3, 4)),
+            morton::encode2_32(4, 6)
+        );
+        assert_eq!(
+            morton::sub2_64(morton::encode2_64(9, 9), morton::encode2_64(3, 4)),
+            morton::encode2_64(6, 5)
+        );
+        assert_eq!(
+            morton::add3_32(morton::encode3_32(1, 2, 3), morton::encode3_32(1, 1, 1)),
+            morton::encode3_32(2, 3, 4)
+        );
+        assert_eq!(
+            morton::sub3_32(morton::encode3_32(1, 2, 3), morton::encode3_32(1, 1, 1)),
+            morton::encode3_32(0, 1, 2)
+        );
+    }
+
+    #[test]
+    fn test_morton_octree_addressing() {
+        let code = morton::encode3_64(6, 3, 5);
+        assert_eq!(morton::parent3_64(code), morton::encode3_64(3, 1, 2));
+        assert_eq!(morton::child3_64(morton::parent3_64(code), 0b110), code);
+    }
+
+    fn manhattan(a: (u32, u32, u32), b: (u32, u32, u32)) -> u32 {
+        a.0.abs_diff(b.0) + a.1.abs_diff(b.1) + a.2.abs_diff(b.2)
+    }
+
+    #[test]
+    fn test_hilbert_2d() {
+        let mut seen = vec![false; 256];
+        let mut previous = hilbert::decode2_32(0);
+        assert_eq!(previous, (0, 0));
+        for index in 0..(1u32 << 16) {
+            let (x, y) = hilbert::decode2_32(index);
+            assert_eq!(hilbert::encode2_32(x, y), index);
+            if index > 0 {
+                assert_eq!(manhattan((x, y, 0), (previous.0, previous.1, 0)), 1);
+            }
+            if x < 16 && y < 16 {
+                seen[(y * 16 + x) as usize] = true;
+            }
+            previous = (x, y);
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        for (x, y) in [(0, 0), (123_456, 7), (u32::MAX, 1)] {
+            assert_eq!(hilbert::decode2_64(hilbert::encode2_64(x, y)), (x, y));
+        }
+    }
+
+    #[test]
+    fn test_hilbert_3d() {
+        let mut previous = hilbert::decode3_32(0);
+        for index in 1..(1u32 << 15) {
+            let point = hilbert::decode3_32(index);
+            assert_eq!(hilbert::encode3_32(point.0, point.1, point.2), index);
+            assert_eq!(manhattan(point, previous), 1);
+            previous = point;
+        }
+
+        let mut previous = hilbert::decode3_64(1 << 40);
+        for index in (1u64 << 40) + 1..(1u64 << 40) + 4096 {
+            let point = hilbert::decode3_64(index);
+            assert_eq!(hilbert::encode3_64(point.0, point.1, point.2), index);
+            assert_eq!(manhattan(point, previous), 1);
+            previous = point;
+        }
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use hmath::quaternion::Quaternion;
+    use hmath::transform::{Transform, Transformd, Transformf};
+    use hmath::vector::{Vector3, Vector3d};
+
+    fn assert_vector_eq(a: &Vector3d, b: &Vector3d) {
+        assert!((*a - *b).length() < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    fn parent() -> Transformd {
+        Transform::new(
+            Vector3::new(1.0, -2.0, 3.0),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.8),
+            Vector3::new(2.0, 2.0, 2.0),
+        )
+    }
+
+    fn child() -> Transformd {
+        Transform::new(
+            Vector3::new(0.5, 0.0, -1.0),
+            Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), -0.4),
+            Vector3::new(1.0, 3.0, 0.5),
+        )
+    }
+
+    #[test]
+    fn test_transform_point_matches_matrix() {
+        let t = child();
+        let p = Vector3::new(0.3, 1.1, -2.0);
+        let from_matrix = t.to_matrix().transform_point(&p);
+        assert_vector_eq(&t.transform_point(&p), &from_matrix);
+        assert_vector_eq(&t.inverse_transform_point(&t.transform_point(&p)), &p);
+        assert_vector_eq(&t.transform_vector(&p), &t.to_matrix().transform_vector(&p));
+    }
+
+    #[test]
+    fn test_transform_composition() {
+        let world = child() * parent();
+        let p = Vector3::new(0.3, 1.1, -2.0);
+        let expected = parent().transform_point(&child().transform_point(&p));
+        assert_vector_eq(&world.transform_point(&p), &expected);
+
+        // Uniform parent scale makes the composition exact.
+        let matrix = child().to_matrix() * parent().to_matrix();
+        for (a, b) in world.to_matrix().data.iter().zip(matrix.data.iter()) {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_transform_inverse() {
+        let t = parent();
+        let inverse = t.inverse().unwrap();
+        let p = Vector3::new(4.0, 5.0, -6.0);
+        assert_vector_eq(&inverse.transform_point(&t.transform_point(&p)), &p);
+
+        let identity = t * inverse;
+        assert_vector_eq(&identity.translation, &Vector3::zero());
+        assert_vector_eq(&identity.scale, &Vector3::new(1.0, 1.0, 1.0));
+        assert!(Transformd::from_scale(Vector3::new(1.0, 0.0, 1.0))
+            .inverse()
+            .is_none());
+    }
+
+    #[test]
+    fn test_transform_matrix_round_trip() {
+        let t = child();
+        let round_trip = Transform::from_matrix(&t.to_matrix());
+        assert_vector_eq(&round_trip.translation, &t.translation);
+        assert_vector_eq(&round_trip.scale, &t.scale);
+        assert!((round_trip.rotation.dot(&t.rotation).abs() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transform_lerp() {
+        let a = Transformd::identity();
+        let b = parent();
+        assert_eq!(a.lerp(&b, 0.0), a);
+        let mid = a.lerp(&b, 0.5);
+        assert_vector_eq(&mid.translation, &(b.translation * 0.5));
+        assert_vector_eq(&mid.scale, &Vector3::new(1.5, 1.5, 1.5));
+        assert!((mid.rotation.angle_between(&b.rotation) - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transform_precision_conversion() {
+        let t = parent();
+        let narrow: Transformf = t.cast();
+        assert_eq!(narrow.translation, Vector3::new(1.0f32, -2.0, 3.0));
+        let wide: Transformd = narrow.into();
+        assert_vector_eq(&wide.scale, &t.scale);
+        assert_eq!(Transformd::default(), Transformd::identity());
+    }
+}
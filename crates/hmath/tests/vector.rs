@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use hmath::vector::Vector3;
+    use hmath::vector::{Vector2, Vector3, Vector3d, Vector3i, Vector4};
 
     #[test]
     fn test_vector3_new() {
@@ -79,4 +79,145 @@ mod tests {
         assert_eq!(scaled.y, 2.0);
         assert_eq!(scaled.z, 3.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_vector2_ops() {
+        let v1 = Vector2::new(3.0, 4.0);
+        let v2 = Vector2::new(1.0, 2.0);
+        assert_eq!(v1.length(), 5.0);
+        assert_eq!(v1.dot(&v2), 11.0);
+        assert_eq!(v1.perp_dot(&v2), 2.0);
+        assert_eq!(v1 - v2, Vector2::new(2.0, 2.0));
+        assert_eq!(v2 * 3.0, Vector2::new(3.0, 6.0));
+    }
+
+    #[test]
+    fn test_vector4_ops() {
+        let v = Vector4::new(1.0, 2.0, 2.0, 4.0);
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(v + v, Vector4::new(2.0, 4.0, 4.0, 8.0));
+        let p = v.truncate();
+        assert_eq!((p.x, p.y, p.z), (1.0, 2.0, 2.0));
+        assert_eq!(p.extend(1.0), Vector4::new(1.0, 2.0, 2.0, 1.0));
+    }
+
+    #[test]
+    fn test_vector3i_ops() {
+        let a = Vector3i::new(1, -2, 3);
+        let b = Vector3i::new(4, 5, -6);
+        let sum = a + b;
+        assert_eq!((sum.x, sum.y, sum.z), (5, 3, -3));
+        assert_eq!(a.dot(&b), -24);
+        let scaled = a * 2;
+        assert_eq!((scaled.x, scaled.y, scaled.z), (2, -4, 6));
+    }
+
+    #[test]
+    fn test_vector3_rounding_casts() {
+        let v = Vector3d::new(-1.5, 0.4, 2.5);
+        let floor: Vector3i = v.floor_cast();
+        assert_eq!((floor.x, floor.y, floor.z), (-2, 0, 2));
+        let ceil: Vector3i = v.ceil_cast();
+        assert_eq!((ceil.x, ceil.y, ceil.z), (-1, 1, 3));
+        let round: Vector3i = v.round_cast();
+        assert_eq!((round.x, round.y, round.z), (-2, 0, 3));
+        let truncated: Vector3i = v.cast();
+        assert_eq!((truncated.x, truncated.y, truncated.z), (-1, 0, 2));
+        let back: Vector3d = Vector3i::new(-3, 0, 7).into();
+        assert_eq!((back.x, back.y, back.z), (-3.0, 0.0, 7.0));
+    }
+
+    #[test]
+    fn test_vector3i_euclid() {
+        let block = Vector3i::new(-1, 33, -32);
+        let chunk = block.div_euclid(32);
+        assert_eq!((chunk.x, chunk.y, chunk.z), (-1, 1, -1));
+        let local = block.rem_euclid(32);
+        assert_eq!((local.x, local.y, local.z), (31, 1, 0));
+    }
+
+    #[test]
+    fn test_vector3_eq_and_default() {
+        assert_eq!(Vector3::<f32>::default(), Vector3::zero());
+        assert_ne!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 4.0));
+        assert_eq!(
+            format!("{:?}", Vector3::new(1, 2, 3)),
+            "Vector3 { x: 1, y: 2, z: 3 }"
+        );
+    }
+
+    #[test]
+    fn test_vector3_neg() {
+        assert_eq!(-Vector3::new(1.0, -2.0, 3.0), Vector3::new(-1.0, 2.0, -3.0));
+    }
+
+    #[test]
+    fn test_vector3_assign_ops() {
+        let mut v = Vector3::new(1.0, 2.0, 3.0);
+        v -= Vector3::new(1.0, 1.0, 1.0);
+        assert_eq!(v, Vector3::new(0.0, 1.0, 2.0));
+        v *= 3.0;
+        assert_eq!(v, Vector3::new(0.0, 3.0, 6.0));
+        v /= 3.0;
+        assert_eq!(v, Vector3::new(0.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn test_vector3_component_mul() {
+        let v1 = Vector3::new(1.0, 2.0, 3.0);
+        let v2 = Vector3::new(4.0, 5.0, 6.0);
+        assert_eq!(v1 * v2, Vector3::new(4.0, 10.0, 18.0));
+        assert_eq!(v2 / v1, Vector3::new(4.0, 2.5, 2.0));
+    }
+
+    #[test]
+    fn test_vector3_index() {
+        let mut v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(v[0], 1.0);
+        assert_eq!(v[2], 3.0);
+        v[1] = 5.0;
+        assert_eq!(v.y, 5.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vector3_index_out_of_range() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        let _ = v[3];
+    }
+
+    #[test]
+    fn test_vector3_array_conversion() {
+        let v: Vector3<f32> = [1.0, 2.0, 3.0].into();
+        assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+        let array: [f32; 3] = v.into();
+        assert_eq!(array, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_vector3_min_max_clamp() {
+        let v1 = Vector3::new(1.0, 5.0, -3.0);
+        let v2 = Vector3::new(2.0, 4.0, -4.0);
+        assert_eq!(v1.min(&v2), Vector3::new(1.0, 4.0, -4.0));
+        assert_eq!(v1.max(&v2), Vector3::new(2.0, 5.0, -3.0));
+        let clamped = v1.clamp(&Vector3::zero(), &Vector3::new(2.0, 2.0, 2.0));
+        assert_eq!(clamped, Vector3::new(1.0, 2.0, 0.0));
+        assert_eq!(v1.abs(), Vector3::new(1.0, 5.0, 3.0));
+    }
+
+    #[test]
+    fn test_vector3_lerp() {
+        let v1 = Vector3::new(0.0, 10.0, -2.0);
+        let v2 = Vector3::new(4.0, 20.0, 2.0);
+        assert_eq!(v1.lerp(&v2, 0.0), v1);
+        assert_eq!(v1.lerp(&v2, 1.0), v2);
+        assert_eq!(v1.lerp(&v2, 0.25), Vector3::new(1.0, 12.5, -1.0));
+    }
+
+    #[test]
+    fn test_vector3_pod() {
+        let vertices = [Vector3::new(1.0f32, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&vertices);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+}
@@ -1,5 +1,5 @@
-mod mesh;
+pub mod mesh;
 mod render_context;
 pub mod renderer;
-mod vertex;
+pub mod vertex;
 mod uniform;
\ No newline at end of file
@@ -0,0 +1,223 @@
+use crate::vertex::Vertex;
+use hcore::voxel::block::BlockId;
+use hcore::voxel::chunk::{Chunk, CHUNK_SIZE};
+use hcore::voxel::world::VoxelWorld;
+use hmath::vector::{Vector3, Vector3f, Vector3i};
+
+/// A block face, named by the direction it points in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Face {
+    NegX,
+    PosX,
+    NegY,
+    PosY,
+    NegZ,
+    PosZ,
+}
+
+impl Face {
+    pub const ALL: [Face; 6] = [
+        Face::NegX,
+        Face::PosX,
+        Face::NegY,
+        Face::PosY,
+        Face::NegZ,
+        Face::PosZ,
+    ];
+
+    /// 0, 1 or 2 for x, y or z.
+    pub fn axis(self) -> usize {
+        self as usize / 2
+    }
+
+    pub fn is_positive(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn normal(self) -> Vector3i {
+        let mut normal = Vector3i::zero();
+        normal[self.axis()] = if self.is_positive() { 1 } else { -1 };
+        normal
+    }
+}
+
+/// How a block face is drawn. Adjacent faces are merged only when their
+/// materials are equal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FaceMaterial {
+    /// Passed through to [`Vertex::material`].
+    pub material: u32,
+    pub color: Vector3f,
+}
+
+/// Triangles for one chunk, in the chunk's local space where block
+/// `(x, y, z)` spans `[x, x + 1]` on each axis.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChunkMesh {
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<u32>,
+}
+
+impl ChunkMesh {
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn quad_count(&self) -> usize {
+        self.indices.len() / 6
+    }
+
+    /// The indices as `u16`, or `None` if there are too many vertices.
+    pub fn indices_u16(&self) -> Option<Vec<u16>> {
+        if self.vertices.len() > u16::MAX as usize + 1 {
+            return None;
+        }
+        Some(self.indices.iter().map(|&index| index as u16).collect())
+    }
+
+    fn push_quad(&mut self, corners: [Vector3f; 4], positive: bool, material: FaceMaterial) {
+        let base = self.vertices.len() as u32;
+        self.vertices.extend(corners.map(|position| Vertex {
+            position,
+            color: material.color,
+            material: material.material,
+        }));
+        // Clockwise seen from outside, like the rest of the renderer's
+        // geometry; `corners` runs counterclockwise around the +axis.
+        let order = if positive {
+            [0, 2, 1, 0, 3, 2]
+        } else {
+            [0, 1, 2, 0, 2, 3]
+        };
+        self.indices.extend(order.map(|corner| base + corner));
+    }
+}
+
+/// Builds a mesh of the chunk's visible faces, merging coplanar neighbours
+/// with the same material into larger rectangles.
+///
+/// A face is visible when the block across it is air. `neighbours` holds
+/// the adjacent chunks in [`Face::ALL`] order and is consulted at the
+/// borders; missing ones count as air. The output depends only on the
+/// inputs, so it is stable across runs and platforms.
+pub fn mesh_chunk(
+    chunk: &Chunk,
+    neighbours: [Option<&Chunk>; 6],
+    material: impl Fn(BlockId, Face) -> FaceMaterial,
+) -> ChunkMesh {
+    let mut mesh = ChunkMesh::default();
+    if chunk.is_empty() {
+        return mesh;
+    }
+
+    let size = CHUNK_SIZE as i32;
+    let block_at = |p: Vector3i| -> BlockId {
+        for axis in 0..3 {
+            let outside = if p[axis] < 0 {
+                Some(axis * 2)
+            } else if p[axis] >= size {
+                Some(axis * 2 + 1)
+            } else {
+                None
+            };
+            if let Some(face) = outside {
+                let Some(neighbour) = neighbours[face] else {
+                    return BlockId::AIR;
+                };
+                let mut local = p;
+                local[axis] = p[axis].rem_euclid(size);
+                return neighbour.get(local.x as usize, local.y as usize, local.z as usize);
+            }
+        }
+        chunk.get(p.x as usize, p.y as usize, p.z as usize)
+    };
+
+    let mut mask: Vec<Option<FaceMaterial>> = vec![None; CHUNK_SIZE * CHUNK_SIZE];
+    for face in Face::ALL {
+        let d = face.axis();
+        let (u, v) = ((d + 1) % 3, (d + 2) % 3);
+        let normal = face.normal();
+
+        for slice in 0..size {
+            for j in 0..size {
+                for i in 0..size {
+                    let mut p = Vector3i::zero();
+                    p[d] = slice;
+                    p[u] = i;
+                    p[v] = j;
+                    let block = block_at(p);
+                    let visible = !block.is_air() && block_at(p + normal).is_air();
+                    mask[(j * size + i) as usize] = visible.then(|| material(block, face));
+                }
+            }
+
+            // Grow each rectangle along u first, then along v while whole
+            // rows match.
+            let plane = (slice + face.is_positive() as i32) as f32;
+            for j in 0..CHUNK_SIZE {
+                let mut i = 0;
+                while i < CHUNK_SIZE {
+                    let Some(current) = mask[j * CHUNK_SIZE + i] else {
+                        i += 1;
+                        continue;
+                    };
+                    let mut width = 1;
+                    while i + width < CHUNK_SIZE
+                        && mask[j * CHUNK_SIZE + i + width] == Some(current)
+                    {
+                        width += 1;
+                    }
+                    let mut height = 1;
+                    while j + height < CHUNK_SIZE
+                        && (0..width)
+                            .all(|k| mask[(j + height) * CHUNK_SIZE + i + k] == Some(current))
+                    {
+                        height += 1;
+                    }
+                    for row in j..j + height {
+                        mask[row * CHUNK_SIZE + i..row * CHUNK_SIZE + i + width].fill(None);
+                    }
+
+                    let mut origin = Vector3f::zero();
+                    origin[d] = plane;
+                    origin[u] = i as f32;
+                    origin[v] = j as f32;
+                    let mut along_u = Vector3f::zero();
+                    along_u[u] = width as f32;
+                    let mut along_v = Vector3f::zero();
+                    along_v[v] = height as f32;
+                    let corners = [
+                        origin,
+                        origin + along_u,
+                        origin + along_u + along_v,
+                        origin + along_v,
+                    ];
+                    mesh.push_quad(corners, face.is_positive(), current);
+                    i += width;
+                }
+            }
+        }
+    }
+    mesh
+}
+
+/// [`mesh_chunk`] for a chunk of a world, with its neighbours looked up
+/// there. Returns an empty mesh for missing chunks.
+pub fn mesh_world_chunk(
+    world: &VoxelWorld,
+    position: Vector3i,
+    material: impl Fn(BlockId, Face) -> FaceMaterial,
+) -> ChunkMesh {
+    let Some(chunk) = world.chunk(position) else {
+        return ChunkMesh::default();
+    };
+    let neighbours = Face::ALL.map(|face| world.chunk(position + face.normal()));
+    mesh_chunk(chunk, neighbours, material)
+}
+
+/// A colour per face direction, handy for debugging: each normal component
+/// mapped from `[-1, 1]` to `[0, 1]`.
+pub fn normal_color(face: Face) -> Vector3f {
+    let normal: Vector3f = face.normal().cast();
+    (normal + Vector3::new(1.0, 1.0, 1.0)) * 0.5
+}
@@ -0,0 +1,2 @@
+pub mod greedy;
+pub mod surface;
@@ -0,0 +1,33 @@
+use super::greedy::{ChunkMesh, FaceMaterial};
+use crate::vertex::Vertex;
+use hcore::voxel::surface::SurfaceMesh;
+use hmath::vector::Vector3f;
+
+/// Converts an extracted isosurface into renderer geometry, choosing each
+/// vertex's material from its position and unit normal.
+pub fn surface_to_chunk_mesh(
+    surface: &SurfaceMesh,
+    material: impl Fn(Vector3f, Vector3f) -> FaceMaterial,
+) -> ChunkMesh {
+    let vertices = surface
+        .positions
+        .iter()
+        .zip(&surface.normals)
+        .map(|(&position, &normal)| {
+            let material = material(position, normal);
+            Vertex {
+                position,
+                color: material.color,
+                material: material.material,
+            }
+        })
+        .collect();
+    // Surface triangles are counterclockwise seen from outside; the
+    // renderer's front faces are clockwise.
+    let indices = surface
+        .indices
+        .chunks_exact(3)
+        .flat_map(|triangle| [triangle[0], triangle[2], triangle[1]])
+        .collect();
+    ChunkMesh { vertices, indices }
+}
@@ -1,9 +1,12 @@
+use crate::mesh::greedy::{self, ChunkMesh, FaceMaterial};
 use crate::render_context::RenderContext;
 use crate::uniform::UniformBufferObject;
 use crate::vertex::Vertex;
 use anyhow::Result;
+use hcore::voxel::block::BlockId;
+use hcore::voxel::chunk::Chunk;
 use hmath::matrix::Matrix4x4;
-use hmath::vector::Vector3;
+use hmath::vector::{Vector3d, Vector3f};
 use vulkano::buffer::allocator::{SubbufferAllocator, SubbufferAllocatorCreateInfo};
 use vulkano::descriptor_set::allocator::StandardDescriptorSetAllocator;
 use vulkano::descriptor_set::{DescriptorSet, WriteDescriptorSet};
@@ -55,12 +58,17 @@ pub struct Renderer {
     command_buffer_allocator: Arc<StandardCommandBufferAllocator>,
     descriptor_set_allocator: Arc<StandardDescriptorSetAllocator>,
     memory_allocator: Arc<StandardMemoryAllocator>,
-    vertex_buffer: Subbuffer<[Vertex]>,
-    index_buffer: Subbuffer<[u16]>,
+    /// The vertex and index buffers of the drawn mesh, or `None` while it
+    /// is empty since Vulkan has no zero-sized buffers.
+    mesh_buffers: Option<(Subbuffer<[Vertex]>, Subbuffer<[u32]>)>,
     render_context: Option<RenderContext>,
     uniform_buffer_allocator: Option<SubbufferAllocator>,
     current_view_matrix: Matrix4x4,
     current_projection_matrix: Matrix4x4,
+    camera_position: Vector3d,
+    /// Where the vertex buffer's local origin sits in the engine's
+    /// coordinate frame.
+    mesh_origin: Vector3d,
 }
 
 fn load_shader(device: Arc<Device>, name: &str) -> Arc<ShaderModule> {
@@ -181,62 +189,14 @@ impl Renderer {
             Default::default(),
         ));
 
-        let vertices = [
-            // Front face
-            Vertex { position: Vector3::new(-0.5, -0.5,  0.5), color: Vector3::new(1.0, 0.0, 0.0) }, // 0
-            Vertex { position: Vector3::new( 0.5, -0.5,  0.5), color: Vector3::new(1.0, 0.0, 0.0) }, // 1
-            Vertex { position: Vector3::new( 0.5,  0.5,  0.5), color: Vector3::new(1.0, 0.0, 0.0) }, // 2
-            Vertex { position: Vector3::new(-0.5,  0.5,  0.5), color: Vector3::new(1.0, 0.0, 0.0) }, // 3
-            
-            // Back face
-            Vertex { position: Vector3::new(-0.5, -0.5, -0.5), color: Vector3::new(0.0, 1.0, 0.0) }, // 4
-            Vertex { position: Vector3::new( 0.5, -0.5, -0.5), color: Vector3::new(0.0, 1.0, 0.0) }, // 5
-            Vertex { position: Vector3::new( 0.5,  0.5, -0.5), color: Vector3::new(0.0, 1.0, 0.0) }, // 6
-            Vertex { position: Vector3::new(-0.5,  0.5, -0.5), color: Vector3::new(0.0, 1.0, 0.0) }, // 7
-        ];
-
-        let indices: [u16; 36] = [
-            // Front face
-            0, 2, 1,  0, 3, 2,
-            // Back face
-            5, 7, 4,  5, 6, 7,
-            // Top face
-            3, 6, 2,  3, 7, 6,
-            // Bottom face
-            4, 1, 5,  4, 0, 1,
-            // Right face
-            1, 6, 5,  1, 2, 6,
-            // Left face
-            4, 3, 0,  4, 7, 3
-        ];
-
-        let vertex_buffer = Buffer::from_iter(
-            memory_allocator.clone(),
-            BufferCreateInfo {
-                usage: BufferUsage::VERTEX_BUFFER,
-                ..Default::default()
-            },
-            AllocationCreateInfo {
-                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
-                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
-                ..Default::default()
-            },
-            vertices,
-        )?;
-
-        let index_buffer = Buffer::from_iter(
-            memory_allocator.clone(),
-            BufferCreateInfo {
-                usage: BufferUsage::INDEX_BUFFER,
-                ..Default::default()
-            },
-            AllocationCreateInfo {
-                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
-                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
-                ..Default::default()
-            },
-            indices,
-        )?;
+        // A single block, centred on the origin through `mesh_origin`.
+        let mut chunk = Chunk::default();
+        chunk.set(0, 0, 0, BlockId(1));
+        let mesh = greedy::mesh_chunk(&chunk, [None; 6], |block, face| FaceMaterial {
+            material: block.0 as u32,
+            color: greedy::normal_color(face),
+        });
+        let mesh_buffers = upload_mesh(&memory_allocator, &mesh)?;
 
         Ok(Renderer {
             instance,
@@ -245,12 +205,13 @@ impl Renderer {
             command_buffer_allocator,
             descriptor_set_allocator,
             memory_allocator,
-            vertex_buffer,
-            index_buffer,
+            mesh_buffers,
             render_context: None,
             uniform_buffer_allocator: None,
             current_view_matrix: Matrix4x4::identity(),
             current_projection_matrix: Matrix4x4::identity(),
+            camera_position: Vector3d::zero(),
+            mesh_origin: Vector3d::new(-0.5, -0.5, -0.5),
         })
     }
 
@@ -453,7 +414,11 @@ impl Renderer {
         }
 
         let uniform_buffer = if let Some(allocator) = &self.uniform_buffer_allocator {
-            let uniform_data = UniformBufferObject::new(self.current_view_matrix, self.current_projection_matrix);
+            // The mesh's offset from the camera is taken in f64, so only the
+            // small difference is rounded to f32.
+            let offset: Vector3f = (self.mesh_origin - self.camera_position).cast();
+            let view = Matrix4x4::from_translation(&offset) * self.current_view_matrix;
+            let uniform_data = UniformBufferObject::new(view, self.current_projection_matrix);
             
             let buffer = allocator.allocate_sized().unwrap();
             *buffer.write().unwrap() = uniform_data;
@@ -524,13 +489,16 @@ impl Renderer {
                 0,
                 descriptor_set,
             )
-            .unwrap()
-            .bind_vertex_buffers(0, self.vertex_buffer.clone())
-            .unwrap()
-            .bind_index_buffer(self.index_buffer.clone())
             .unwrap();
 
-        unsafe { builder.draw_indexed(self.index_buffer.len() as u32, 1, 0, 0, 0) }.unwrap();
+        if let Some((vertex_buffer, index_buffer)) = &self.mesh_buffers {
+            builder
+                .bind_vertex_buffers(0, vertex_buffer.clone())
+                .unwrap()
+                .bind_index_buffer(index_buffer.clone())
+                .unwrap();
+            unsafe { builder.draw_indexed(index_buffer.len() as u32, 1, 0, 0, 0) }.unwrap();
+        }
 
         builder.end_rendering().unwrap();
 
@@ -567,6 +535,9 @@ impl Renderer {
         }
     }
 
+    /// The view is relative to the camera position, so for camera-relative
+    /// rendering it only holds the camera's rotation. A full view matrix
+    /// also works while the camera position is left at zero.
     pub fn set_view_matrix(&mut self, view: &Matrix4x4) {
         self.current_view_matrix = *view;
     }
@@ -579,6 +550,61 @@ impl Renderer {
         self.current_view_matrix = *view;
         self.current_projection_matrix = *projection;
     }
+
+    /// Replaces the drawn geometry. `origin` places the mesh's local space
+    /// in the same frame as the camera position. An empty mesh releases the
+    /// buffers and clears the geometry.
+    pub fn set_mesh(&mut self, mesh: &ChunkMesh, origin: Vector3d) -> Result<()> {
+        self.mesh_buffers = upload_mesh(&self.memory_allocator, mesh)?;
+        self.mesh_origin = origin;
+        Ok(())
+    }
+
+    pub fn set_camera_position(&mut self, position: Vector3d) {
+        self.camera_position = position;
+    }
+
+    /// Moves geometry after the engine rebased its origin by `shift`, so it
+    /// stays put in absolute world coordinates.
+    pub fn shift_origin(&mut self, shift: Vector3d) {
+        self.mesh_origin -= shift;
+    }
+}
+
+/// Copies a mesh into new vertex and index buffers, or returns `None` for
+/// an empty mesh, which has nothing to draw.
+fn upload_mesh(
+    memory_allocator: &Arc<StandardMemoryAllocator>,
+    mesh: &ChunkMesh,
+) -> Result<Option<(Subbuffer<[Vertex]>, Subbuffer<[u32]>)>> {
+    if mesh.is_empty() {
+        return Ok(None);
+    }
+
+    let allocation_info = AllocationCreateInfo {
+        memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+            | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+        ..Default::default()
+    };
+    let vertex_buffer = Buffer::from_iter(
+        memory_allocator.clone(),
+        BufferCreateInfo {
+            usage: BufferUsage::VERTEX_BUFFER,
+            ..Default::default()
+        },
+        allocation_info.clone(),
+        mesh.vertices.iter().copied(),
+    )?;
+    let index_buffer = Buffer::from_iter(
+        memory_allocator.clone(),
+        BufferCreateInfo {
+            usage: BufferUsage::INDEX_BUFFER,
+            ..Default::default()
+        },
+        allocation_info,
+        mesh.indices.iter().copied(),
+    )?;
+    Ok(Some((vertex_buffer, index_buffer)))
 }
 
 fn window_size_dependent_setup(images: &[Arc<Image>]) -> Vec<Arc<ImageView>> {
@@ -1,8 +1,6 @@
 use hmath::vector::Vector3;
-use std::alloc::Layout;
 use std::collections::HashMap;
-use std::ptr::NonNull;
-use vulkano::buffer::{BufferContents, BufferContentsLayout};
+use vulkano::buffer::BufferContents;
 use vulkano::format::Format;
 use vulkano::pipeline;
 use vulkano::pipeline::graphics::vertex_input::{
@@ -10,17 +8,15 @@ use vulkano::pipeline::graphics::vertex_input::{
 };
 
 #[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, BufferContents)]
 pub struct Vertex {
     pub position: Vector3<f32>,
+    /// Linear RGB; convert authored colours with
+    /// `hmath::color::linear::LinearRgba::to_vector3`.
     pub color: Vector3<f32>,
-}
-
-unsafe impl BufferContents for Vertex {
-    const LAYOUT: BufferContentsLayout = BufferContentsLayout::from_sized(Layout::new::<Vertex>());
-
-    unsafe fn ptr_from_slice(slice: NonNull<[u8]>) -> *mut Self {
-        <*mut [u8]>::cast::<Vertex>(slice.as_ptr())
-    }
+    /// Application-defined material id of the face, for shaders that look
+    /// up textures or other per-material data.
+    pub material: u32,
 }
 
 unsafe impl VulkanoVertex for Vertex {
@@ -46,6 +42,15 @@ unsafe impl VulkanoVertex for Vertex {
                         stride: 0,
                     },
                 ),
+                (
+                    String::from("material"),
+                    VertexMemberInfo {
+                        offset: 24,
+                        format: Format::R32_UINT,
+                        num_elements: 1,
+                        stride: 0,
+                    },
+                ),
             ]),
             stride: ::std::mem::size_of::<Vertex>() as u32,
             input_rate: pipeline::graphics::vertex_input::VertexInputRate::Vertex,
@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod tests {
+    use hcore::voxel::block::BlockId;
+    use hcore::voxel::chunk::Chunk;
+    use hcore::voxel::world::VoxelWorld;
+    use hmath::vector::{Vector3, Vector3f, Vector3i};
+    use hrenderer::mesh::greedy::{self, ChunkMesh, Face, FaceMaterial};
+
+    const STONE: BlockId = BlockId(1);
+    const DIRT: BlockId = BlockId(2);
+
+    fn by_block(block: BlockId, _: Face) -> FaceMaterial {
+        FaceMaterial {
+            material: block.0 as u32,
+            color: Vector3::new(block.0 as f32, 0.0, 0.0),
+        }
+    }
+
+    fn corners(mesh: &ChunkMesh, quad: usize) -> Vec<(f32, f32, f32)> {
+        mesh.vertices[quad * 4..quad * 4 + 4]
+            .iter()
+            .map(|vertex| (vertex.position.x, vertex.position.y, vertex.position.z))
+            .collect()
+    }
+
+    /// Every triangle's winding normal must point away from the outward
+    /// normal, matching the renderer's clockwise front faces.
+    fn assert_clockwise(mesh: &ChunkMesh, outward: &[Vector3f]) {
+        for (triangle, normal) in mesh
+            .indices
+            .chunks_exact(3)
+            .zip(outward.iter().flat_map(|n| [n, n]))
+        {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].position);
+            assert!((b - a).cross(&(c - a)).dot(normal) < 0.0);
+        }
+    }
+
+    #[test]
+    fn test_single_block_snapshot() {
+        let mut chunk = Chunk::default();
+        chunk.set(2, 3, 4, STONE);
+        let mesh = greedy::mesh_chunk(&chunk, [None; 6], by_block);
+
+        assert_eq!(mesh.quad_count(), 6);
+        assert_eq!(mesh.vertices.len(), 24);
+        assert_eq!(&mesh.indices[..12], [0, 1, 2, 0, 2, 3, 4, 6, 5, 4, 7, 6]);
+        assert_eq!(
+            corners(&mesh, 0),
+            [
+                (2.0, 3.0, 4.0),
+                (2.0, 4.0, 4.0),
+                (2.0, 4.0, 5.0),
+                (2.0, 3.0, 5.0)
+            ]
+        );
+        assert_eq!(
+            corners(&mesh, 1),
+            [
+                (3.0, 3.0, 4.0),
+                (3.0, 4.0, 4.0),
+                (3.0, 4.0, 5.0),
+                (3.0, 3.0, 5.0)
+            ]
+        );
+        assert_eq!(
+            corners(&mesh, 3),
+            [
+                (2.0, 4.0, 4.0),
+                (2.0, 4.0, 5.0),
+                (3.0, 4.0, 5.0),
+                (3.0, 4.0, 4.0)
+            ]
+        );
+        assert_eq!(
+            corners(&mesh, 5),
+            [
+                (2.0, 3.0, 5.0),
+                (3.0, 3.0, 5.0),
+                (3.0, 4.0, 5.0),
+                (2.0, 4.0, 5.0)
+            ]
+        );
+        assert!(mesh.vertices.iter().all(|vertex| vertex.material == 1));
+
+        let outward: Vec<Vector3f> = Face::ALL.iter().map(|face| face.normal().cast()).collect();
+        assert_clockwise(&mesh, &outward);
+    }
+
+    #[test]
+    fn test_box_merges_to_six_quads() {
+        let mut chunk = Chunk::default();
+        for z in 0..3 {
+            for y in 0..2 {
+                for x in 0..5 {
+                    chunk.set(x + 10, y, z, STONE);
+                }
+            }
+        }
+        let mesh = greedy::mesh_chunk(&chunk, [None; 6], by_block);
+        assert_eq!(mesh.quad_count(), 6);
+        // The top face spans the whole 5x3 footprint.
+        assert_eq!(
+            corners(&mesh, 3),
+            [
+                (10.0, 2.0, 0.0),
+                (10.0, 2.0, 3.0),
+                (15.0, 2.0, 3.0),
+                (15.0, 2.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_materials_split_quads() {
+        let mut chunk = Chunk::default();
+        for x in 0..4 {
+            chunk.set(x, 0, 0, if x < 2 { STONE } else { DIRT });
+        }
+        let mesh = greedy::mesh_chunk(&chunk, [None; 6], by_block);
+        // Ends are single quads; the four long sides split in two.
+        assert_eq!(mesh.quad_count(), 2 + 4 * 2);
+        let materials: Vec<u32> = mesh
+            .vertices
+            .iter()
+            .step_by(4)
+            .map(|v| v.material)
+            .collect();
+        assert_eq!(materials, [1, 2, 1, 2, 1, 2, 1, 2, 1, 2]);
+
+        // Per-face materials stop merging across directions too.
+        let top_only = greedy::mesh_chunk(&chunk, [None; 6], |_, face| FaceMaterial {
+            material: (face == Face::PosY) as u32,
+            color: greedy::normal_color(face),
+        });
+        assert_eq!(top_only.quad_count(), 6);
+        assert_eq!(
+            greedy::normal_color(Face::PosY),
+            Vector3::new(0.5, 1.0, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_hidden_faces_are_culled() {
+        let mut chunk = Chunk::default();
+        chunk.fill(STONE);
+        let alone = greedy::mesh_chunk(&chunk, [None; 6], by_block);
+        assert_eq!(alone.quad_count(), 6);
+
+        let solid = Chunk::new(DIRT);
+        let mut neighbours = [Some(&solid); 6];
+        assert!(greedy::mesh_chunk(&chunk, neighbours, by_block).is_empty());
+
+        neighbours[Face::PosY as usize] = None;
+        let mesh = greedy::mesh_chunk(&chunk, neighbours, by_block);
+        assert_eq!(mesh.quad_count(), 1);
+        assert!(mesh.vertices.iter().all(|vertex| vertex.position.y == 32.0));
+
+        assert!(greedy::mesh_chunk(&Chunk::default(), [None; 6], by_block).is_empty());
+    }
+
+    #[test]
+    fn test_world_chunks_use_neighbours() {
+        let mut world = VoxelWorld::new();
+        world.fill(Vector3i::new(-32, 0, 0), Vector3i::new(32, 1, 1), STONE);
+
+        let left = greedy::mesh_world_chunk(&world, Vector3i::new(-1, 0, 0), by_block);
+        let right = greedy::mesh_world_chunk(&world, Vector3i::new(0, 0, 0), by_block);
+        // The shared face at x = 0 is hidden on both sides.
+        assert_eq!(left.quad_count(), 5);
+        assert_eq!(right.quad_count(), 5);
+        assert!(greedy::mesh_world_chunk(&world, Vector3i::new(5, 0, 0), by_block).is_empty());
+    }
+
+    #[test]
+    fn test_output_is_deterministic_and_fits_u16() {
+        let mut chunk = Chunk::default();
+        for index in (0..32 * 32 * 32).step_by(7) {
+            let (x, y, z) = Chunk::position(index);
+            chunk.set(x, y, z, if index % 3 == 0 { STONE } else { DIRT });
+        }
+        let first = greedy::mesh_chunk(&chunk, [None; 6], by_block);
+        let second = greedy::mesh_chunk(&chunk.clone(), [None; 6], by_block);
+        assert_eq!(first, second);
+
+        let indices = first.indices_u16();
+        if first.vertices.len() <= 65536 {
+            assert_eq!(indices.unwrap().len(), first.indices.len());
+        } else {
+            assert!(indices.is_none());
+        }
+
+        let mut single = Chunk::default();
+        single.set(0, 0, 0, STONE);
+        let mesh = greedy::mesh_chunk(&single, [None; 6], by_block);
+        let small: Vec<u32> = mesh
+            .indices_u16()
+            .unwrap()
+            .into_iter()
+            .map(u32::from)
+            .collect();
+        assert_eq!(small, mesh.indices);
+    }
+}
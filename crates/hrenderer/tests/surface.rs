@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod tests {
+    use hcore::voxel::density::DensityChunk;
+    use hcore::voxel::marching_cubes::marching_cubes;
+    use hmath::vector::{Vector3, Vector3d, Vector3i};
+    use hrenderer::mesh::greedy::FaceMaterial;
+    use hrenderer::mesh::surface::surface_to_chunk_mesh;
+
+    #[test]
+    fn test_surface_becomes_clockwise_geometry() {
+        let center = Vector3d::new(4.1, 3.8, 4.3);
+        let chunk = DensityChunk::sample(Vector3i::zero(), 8, 0, 0, |p| {
+            ((p - center).length() - 2.5) as f32
+        });
+        let surface = marching_cubes(&chunk);
+        let mesh = surface_to_chunk_mesh(&surface, |position, normal| FaceMaterial {
+            material: (position.y > 4.0) as u32,
+            color: normal,
+        });
+
+        assert_eq!(mesh.vertices.len(), surface.positions.len());
+        assert_eq!(mesh.indices.len(), surface.indices.len());
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize]);
+            let normal = (b.position - a.position).cross(&(c.position - a.position));
+            assert!(normal.dot(&a.color) < 0.0);
+            assert_eq!(a.material, (a.position.y > 4.0) as u32);
+        }
+        assert!(mesh.vertices.iter().any(|vertex| vertex.material == 0));
+        assert_ne!(mesh.vertices[0].color, Vector3::new(0.0, 0.0, 0.0));
+    }
+}